@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use anyhow::anyhow;
+use unicode_ident::{is_xid_continue, is_xid_start};
+
+pub fn to_identifier(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 1);
+
+    if let Some(first) = name.chars().next() {
+        if !is_xid_start(first) {
+            result.push('_');
+        }
+
+        for ch in name.chars() {
+            result.push(if is_xid_continue(ch) { ch } else { '_' });
+        }
+    }
+
+    result
+}
+
+fn to_literal(name: &str) -> String {
+    format!("r#\"{}\"#", name)
+}
+
+/// How an ID list lays out its columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdFormat {
+    /// `ID Name`, variants built from the name (items, cells)
+    Simple,
+    /// A `ID Tag Name` header with right-aligned ID and Tag columns; names
+    /// repeat freely, so variants are built from the unique tag column
+    Tabular,
+}
+
+/// One row of an ID list: the numeric id, the Rust variant and the display name
+#[derive(Debug)]
+pub struct Entry {
+    pub line: usize,
+    pub id: i32,
+    pub ident: String,
+    pub name: String,
+}
+
+/// Parse an ID list, reporting every malformed line at once
+pub fn parse(file_name: &str, text: &str, format: IdFormat) -> anyhow::Result<Vec<Entry>> {
+    let mut vec: Vec<Entry> = Vec::new();
+    let mut errors: Vec<String> = Vec::new();
+
+    let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+    let columns = match format {
+        IdFormat::Simple => None,
+        IdFormat::Tabular => {
+            let (_, header) = lines.next().ok_or(anyhow!("{file_name}: missing header"))?;
+            Some(TabularColumns::new(header).map_err(|e| anyhow!("{file_name}:1: {e}"))?)
+        }
+    };
+
+    for (line_no, line) in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let parsed = match &columns {
+            None => parse_simple_line(line),
+            Some(columns) => columns.parse_line(line),
+        };
+
+        match parsed {
+            Ok((id, ident, name)) => vec.push(Entry {
+                line: line_no,
+                id,
+                ident,
+                name,
+            }),
+            Err(e) => errors.push(format!("{file_name}:{line_no}: {e}: {:?}", line.trim())),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(anyhow!(errors.join("\n")));
+    }
+
+    check_unique(file_name, &vec)?;
+
+    vec.sort_by_key(|k| k.id);
+    Ok(vec)
+}
+
+fn parse_simple_line(line: &str) -> Result<(i32, String, String), String> {
+    let mut parts = line.trim().splitn(2, ' ');
+
+    let id_str = parts.next().unwrap_or_default();
+    let name = parts
+        .next()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or("missing name")?;
+
+    let id = id_str
+        .parse::<i32>()
+        .map_err(|_| format!("bad id {id_str:?}"))?;
+
+    Ok((id, to_identifier(name), name.into()))
+}
+
+struct TabularColumns {
+    id_end: usize,
+    tag_end: usize,
+    name_start: usize,
+}
+
+impl TabularColumns {
+    fn new(header: &str) -> Result<Self, String> {
+        let column = |title: &str| header.find(title).ok_or(format!("missing {title} column"));
+        Ok(Self {
+            id_end: column("ID")? + "ID".len(),
+            tag_end: column("Tag")? + "Tag".len(),
+            name_start: column("Name")?,
+        })
+    }
+
+    fn parse_line(&self, line: &str) -> Result<(i32, String, String), String> {
+        let field = |range: std::ops::Range<usize>| {
+            line.get(range)
+                .map(str::trim)
+                .ok_or("line is shorter than the header")
+        };
+
+        let id_str = field(0..self.id_end)?;
+        let tag = field(self.id_end..self.tag_end)?;
+        if !field(self.tag_end..self.name_start)?.is_empty() {
+            return Err("text between the Tag and Name columns".into());
+        }
+        let name = line
+            .get(self.name_start..)
+            .map(str::trim)
+            .unwrap_or_default();
+
+        let id = id_str
+            .parse::<i32>()
+            .map_err(|_| format!("bad id {id_str:?}"))?;
+
+        if tag.is_empty() {
+            return Err("missing tag".into());
+        }
+        if name.is_empty() {
+            return Err("missing name".into());
+        }
+
+        Ok((id, to_identifier(tag), name.into()))
+    }
+}
+
+/// Reject duplicate ids and distinct names that collapse into one identifier
+fn check_unique(file_name: &str, entries: &[Entry]) -> anyhow::Result<()> {
+    let mut errors: Vec<String> = Vec::new();
+    let mut ids: HashMap<i32, &Entry> = HashMap::new();
+    let mut idents: HashMap<&str, &Entry> = HashMap::new();
+
+    for entry in entries {
+        if let Some(first) = ids.insert(entry.id, entry) {
+            errors.push(format!(
+                "{file_name}:{}: duplicate id {} (first defined on line {})",
+                entry.line, entry.id, first.line
+            ));
+        }
+        if let Some(first) = idents.insert(&entry.ident, entry) {
+            errors.push(format!(
+                "{file_name}:{}: identifier {} collides with line {}",
+                entry.line, entry.ident, first.line
+            ));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(anyhow!(errors.join("\n")));
+    }
+    Ok(())
+}
+
+pub fn write_enum(target: &mut impl Write, enum_name: &str, entries: &[Entry]) -> std::fmt::Result {
+    let sp = "    ";
+
+    writeln!(target, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]")?;
+    writeln!(
+        target,
+        "#[allow(non_camel_case_types, clippy::upper_case_acronyms)]"
+    )?;
+    writeln!(target, "pub enum {enum_name} {{")?;
+
+    for entry in entries {
+        writeln!(target, "{sp}{},", entry.ident)?;
+    }
+
+    writeln!(target, "}}")?;
+    writeln!(target)?;
+
+    writeln!(target, "impl {enum_name} {{")?;
+
+    writeln!(target, "{sp}pub fn from_id(id: i32) -> Option<Self> {{")?;
+    writeln!(target, "{sp}{sp}match id {{")?;
+    for entry in entries {
+        writeln!(
+            target,
+            "{sp}{sp}{sp}{} => Some(Self::{}),",
+            entry.id, entry.ident
+        )?;
+    }
+    writeln!(target, "{sp}{sp}{sp}_ => None,")?;
+    writeln!(target, "{sp}{sp}}}")?;
+    writeln!(target, "{sp}}}")?;
+    writeln!(target)?;
+
+    writeln!(target, "{sp}pub fn id(&self) -> i32 {{")?;
+    writeln!(target, "{sp}{sp}match self {{")?;
+    for entry in entries {
+        writeln!(target, "{sp}{sp}{sp}Self::{} => {},", entry.ident, entry.id)?;
+    }
+    writeln!(target, "{sp}{sp}}}")?;
+    writeln!(target, "{sp}}}")?;
+    writeln!(target)?;
+
+    writeln!(target, "{sp}pub fn name(&self) -> &'static str {{")?;
+    writeln!(target, "{sp}{sp}match self {{")?;
+    for entry in entries {
+        writeln!(
+            target,
+            "{sp}{sp}{sp}Self::{} => {},",
+            entry.ident,
+            to_literal(&entry.name)
+        )?;
+    }
+    writeln!(target, "{sp}{sp}}}")?;
+    writeln!(target, "{sp}}}")?;
+    writeln!(target, "}}")?;
+    writeln!(target)?;
+
+    writeln!(target, "impl TryFrom<i32> for {enum_name} {{")?;
+    writeln!(target, "{sp}type Error = &'static str;")?;
+    writeln!(
+        target,
+        "{sp}fn try_from(id: i32) -> Result<Self, Self::Error> {{"
+    )?;
+    writeln!(target, "{sp}{sp}Self::from_id(id).ok_or(\"unknown id\")")?;
+    writeln!(target, "{sp}}}")?;
+    writeln!(target, "}}")?;
+    writeln!(target)?;
+
+    writeln!(target, "impl From<{enum_name}> for i32 {{")?;
+    writeln!(target, "{sp}fn from(id: {enum_name}) -> i32 {{")?;
+    writeln!(target, "{sp}{sp}id.id()")?;
+    writeln!(target, "{sp}}}")?;
+    writeln!(target, "}}")?;
+    writeln!(target)?;
+
+    writeln!(target, "impl std::fmt::Display for {enum_name} {{")?;
+    writeln!(
+        target,
+        "{sp}fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+    )?;
+    writeln!(target, "{sp}{sp}f.write_str(self.name())")?;
+    writeln!(target, "{sp}}}")?;
+    writeln!(target, "}}")?;
+
+    Ok(())
+}
+
+/// Parse one ID list and render its enum
+pub fn generate_enum(
+    file_name: &str,
+    text: &str,
+    format: IdFormat,
+    enum_name: &str,
+) -> anyhow::Result<String> {
+    let entries = parse(file_name, text, format)?;
+    let mut output = String::new();
+    write_enum(&mut output, enum_name, &entries)?;
+    Ok(output)
+}
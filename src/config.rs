@@ -0,0 +1,471 @@
+use crate::propulsion::Propulsion;
+use crate::template::Template;
+use crate::types::MapType;
+use anyhow::{anyhow, bail, Context};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Commented example written by `statmind config init`; every value is the default
+pub const EXAMPLE_CONFIG: &str = r#"# statmind configuration
+
+# Seconds between polls of the game (defaults to 2 for `run`, 1 for `watch`).
+# Discord is only sent changes, at most one every 15 seconds.
+# interval = 2
+
+# Log filter, e.g. "info" or "statmind=debug"
+# log_level = "info"
+
+[process]
+# Process names to look for, in order of preference (case-insensitive substring)
+names = ["cogmind.exe", "wine"]
+# Command line argument the game must be started with
+argument = "-luigiAi"
+
+[presence]
+# Discord application that owns the image assets below
+application_id = "914720093701832724"
+
+# details, state, large_text, small_text and every text below are templates
+# filled from the game:
+#   {depth} {map} {map_code} {integrity} {energy} {matter} {heat} {corruption}
+#   {propulsion} {hostiles_in_view}
+# {name|fallback} uses fallback when a value is missing (e.g. no player yet),
+# {#name}...{/name} shows text only when name has a value, {^name}...{/name}
+# only when it doesn't. Write {{ and }} for literal braces.
+details = "Playing b13"
+state = "Current map: {depth}/{map}"
+large_image = "cogmind_logo"
+large_text = "Cogmind b13"
+# The small image and text are only used when the player's propulsion is unknown
+small_image = ""
+small_text = ""
+
+# What the elapsed timer counts from: "run" (the current run) or "map" (entering
+# the current map). In the menus it counts from when the game got there.
+timer = "run"
+
+# Up to two buttons shown under the presence
+[[presence.buttons]]
+label = "Visit Site"
+url = "https://gridsagegames.com/cogmind"
+
+# Small image and hover text for the propulsion the player is moving on. Leave
+# out image or text to use small_image or small_text above instead.
+[presence.propulsion.treads]
+image = "go_treads"
+text = "Treads enjoyer"
+
+[presence.propulsion.legs]
+image = "go_legs"
+text = "Legs enjoyer"
+
+[presence.propulsion.wheels]
+image = "go_wheels"
+text = "Wheels enjoyer"
+
+[presence.propulsion.hover]
+image = "go_hover"
+text = "Hover enjoyer"
+
+[presence.propulsion.flight]
+image = "go_flight"
+text = "Flight enjoyer"
+
+# Per-map large image and hover text, keyed by location code. Either key can be
+# left out to keep the default above. Codes: NONE SAN SCR MAT FAC RES ACC SUR
+# MIN EXI STO REC WAS GAR DSF SUB LOW UPP PRO DEE ZIO DAT ZHI WAR EXT CET ARC
+# HUB ARM LAB QUA TES SEC COM AC0 LAI TOW W00-W08
+#
+# [presence.maps.FAC]
+# image = "map_factory"
+# text = "Factory, depth {depth}"
+#
+# [presence.maps.ZIO]
+# image = "map_zion"
+"#;
+
+/// Settings read from a TOML file; anything left out falls back to the defaults
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Seconds between polls of the game
+    pub interval: Option<f64>,
+    /// `env_logger` filter, e.g. `info` or `statmind=debug`
+    pub log_level: Option<String>,
+    pub process: ProcessConfig,
+    pub presence: PresenceConfig,
+}
+
+/// Which process is Cogmind
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProcessConfig {
+    /// Case-insensitive substrings of the process name, in order of preference
+    pub names: Vec<String>,
+    /// Argument the game must have been started with
+    pub argument: String,
+}
+
+impl Default for ProcessConfig {
+    fn default() -> Self {
+        Self {
+            names: vec!["cogmind.exe".into(), "wine".into()],
+            argument: "-luigiAi".into(),
+        }
+    }
+}
+
+/// What the Discord presence looks like
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PresenceConfig {
+    pub application_id: String,
+    pub details: String,
+    pub state: String,
+    pub large_image: String,
+    pub large_text: String,
+    pub small_image: String,
+    pub small_text: String,
+    pub buttons: Vec<ButtonConfig>,
+    pub timer: TimerConfig,
+    /// Small image overrides for each kind of propulsion
+    pub propulsion: PropulsionConfig,
+    /// Large image overrides keyed by `MapType::code`
+    pub maps: BTreeMap<String, AssetsConfig>,
+}
+
+impl Default for PresenceConfig {
+    fn default() -> Self {
+        Self {
+            application_id: "914720093701832724".into(),
+            details: "Playing b13".into(),
+            state: "Current map: {depth}/{map}".into(),
+            large_image: "cogmind_logo".into(),
+            large_text: "Cogmind b13".into(),
+            small_image: String::new(),
+            small_text: String::new(),
+            buttons: vec![ButtonConfig {
+                label: "Visit Site".into(),
+                url: "https://gridsagegames.com/cogmind".into(),
+            }],
+            timer: TimerConfig::Run,
+            propulsion: PropulsionConfig::default(),
+            maps: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ButtonConfig {
+    pub label: String,
+    pub url: String,
+}
+
+/// What the presence's elapsed timer counts from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimerConfig {
+    Run,
+    Map,
+}
+
+/// Image and hover text override; unset fields keep the default
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AssetsConfig {
+    pub image: Option<String>,
+    pub text: Option<String>,
+}
+
+impl AssetsConfig {
+    fn new(image: &str, text: &str) -> Self {
+        Self {
+            image: Some(image.into()),
+            text: Some(text.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PropulsionConfig {
+    pub treads: AssetsConfig,
+    pub legs: AssetsConfig,
+    pub wheels: AssetsConfig,
+    pub hover: AssetsConfig,
+    pub flight: AssetsConfig,
+}
+
+impl Default for PropulsionConfig {
+    fn default() -> Self {
+        Self {
+            treads: AssetsConfig::new("go_treads", "Treads enjoyer"),
+            legs: AssetsConfig::new("go_legs", "Legs enjoyer"),
+            wheels: AssetsConfig::new("go_wheels", "Wheels enjoyer"),
+            hover: AssetsConfig::new("go_hover", "Hover enjoyer"),
+            flight: AssetsConfig::new("go_flight", "Flight enjoyer"),
+        }
+    }
+}
+
+impl PropulsionConfig {
+    pub fn get(&self, propulsion: Propulsion) -> &AssetsConfig {
+        match propulsion {
+            Propulsion::Treads => &self.treads,
+            Propulsion::Legs => &self.legs,
+            Propulsion::Wheels => &self.wheels,
+            Propulsion::Hover => &self.hover,
+            Propulsion::Flight => &self.flight,
+        }
+    }
+}
+
+impl PresenceConfig {
+    /// Overrides for `map`, if any are configured
+    pub fn map_assets(&self, map: MapType) -> Option<&AssetsConfig> {
+        self.maps
+            .iter()
+            .find(|(code, _)| MapType::from_code(code) == Some(map))
+            .map(|(_, assets)| assets)
+    }
+}
+
+/// Discord rejects activity strings longer than this
+pub const MAX_TEXT_LEN: usize = 128;
+/// Discord shows at most this many buttons
+const MAX_BUTTONS: usize = 2;
+
+impl Config {
+    /// `statmind/config.toml` in the platform config directory (`$XDG_CONFIG_HOME` on Linux)
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("statmind").join("config.toml"))
+    }
+
+    /// Load `path`, or the default file if there is one, or fall back to the defaults
+    pub fn find(path: Option<&Path>) -> anyhow::Result<Self> {
+        match path {
+            Some(path) => Self::load(path),
+            None => match Self::default_path() {
+                Some(path) if path.exists() => Self::load(&path),
+                _ => Ok(Self::default()),
+            },
+        }
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid config {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let config: Self = toml::from_str(text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check the values serde can't, naming the offending key
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.interval()?;
+
+        if self.process.names.is_empty() {
+            bail!("process.names must list at least one process name");
+        }
+        if let Some(index) = self
+            .process
+            .names
+            .iter()
+            .position(|name| name.trim().is_empty())
+        {
+            bail!("process.names[{}] must not be blank", index);
+        }
+        if self.process.argument.trim().is_empty() {
+            bail!("process.argument must not be empty");
+        }
+
+        let presence = &self.presence;
+        if presence.application_id.is_empty()
+            || !presence.application_id.chars().all(|c| c.is_ascii_digit())
+        {
+            bail!(
+                "presence.application_id must be a numeric Discord application ID, got {:?}",
+                presence.application_id
+            );
+        }
+        for (key, value) in [
+            ("large_image", &presence.large_image),
+            ("small_image", &presence.small_image),
+        ] {
+            check_text(&format!("presence.{}", key), value)?;
+        }
+        for (key, value) in [
+            ("details", &presence.details),
+            ("state", &presence.state),
+            ("large_text", &presence.large_text),
+            ("small_text", &presence.small_text),
+        ] {
+            Template::parse(value).with_context(|| format!("presence.{}", key))?;
+        }
+
+        if presence.buttons.len() > MAX_BUTTONS {
+            bail!("presence.buttons can have at most {} entries", MAX_BUTTONS);
+        }
+        for (i, button) in presence.buttons.iter().enumerate() {
+            if button.label.is_empty() {
+                bail!("presence.buttons[{}].label must not be empty", i);
+            }
+            check_text(&format!("presence.buttons[{}].label", i), &button.label)?;
+            if !button.url.starts_with("https://") && !button.url.starts_with("http://") {
+                bail!(
+                    "presence.buttons[{}].url must be an http(s) URL, got {:?}",
+                    i,
+                    button.url
+                );
+            }
+        }
+
+        for propulsion in Propulsion::ALL {
+            let key = format!("presence.propulsion.{}", propulsion.key());
+            check_assets(&key, presence.propulsion.get(propulsion))?;
+        }
+        for (code, assets) in &presence.maps {
+            let key = format!("presence.maps.{}", code);
+            if MapType::from_code(code).is_none() {
+                bail!("{} is not a known location code", key);
+            }
+            check_assets(&key, assets)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn interval(&self) -> anyhow::Result<Option<Duration>> {
+        self.interval
+            .map(parse_interval)
+            .transpose()
+            .map_err(|e| anyhow!("interval: {}", e))
+    }
+
+    /// Write the commented example to `path`, refusing to clobber an existing file unless `force`
+    pub fn write_example(path: &Path, force: bool) -> anyhow::Result<()> {
+        if path.exists() && !force {
+            bail!(
+                "{} already exists (use --force to overwrite)",
+                path.display()
+            );
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, EXAMPLE_CONFIG)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+fn check_text(key: &str, value: &str) -> anyhow::Result<()> {
+    if value.chars().count() > MAX_TEXT_LEN {
+        bail!("{} is longer than {} characters", key, MAX_TEXT_LEN);
+    }
+    Ok(())
+}
+
+fn check_assets(key: &str, assets: &AssetsConfig) -> anyhow::Result<()> {
+    if let Some(image) = &assets.image {
+        check_text(&format!("{}.image", key), image)?;
+    }
+    if let Some(text) = &assets.text {
+        Template::parse(text).with_context(|| format!("{}.text", key))?;
+    }
+    Ok(())
+}
+
+/// Turn a number of seconds into a poll interval
+pub fn parse_interval(secs: f64) -> anyhow::Result<Duration> {
+    match Duration::try_from_secs_f64(secs) {
+        Ok(interval) if !interval.is_zero() => Ok(interval),
+        _ => bail!("must be a positive number of seconds, got {}", secs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_matches_defaults() {
+        assert_eq!(Config::parse(EXAMPLE_CONFIG).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn empty_file_is_default() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn map_assets_match_code_case_insensitively() {
+        let config = Config::parse("[presence.maps.fac]\nimage = \"map_factory\"\n").unwrap();
+        let assets = config.presence.map_assets(MapType::MapFac).unwrap();
+        assert_eq!(assets.image.as_deref(), Some("map_factory"));
+        assert_eq!(assets.text, None);
+        assert!(config.presence.map_assets(MapType::MapMat).is_none());
+    }
+
+    #[test]
+    fn propulsion_overrides_keep_other_defaults() {
+        let config = Config::parse("[presence.propulsion.legs]\ntext = \"Walking\"\n").unwrap();
+        let propulsion = &config.presence.propulsion;
+        assert_eq!(propulsion.legs.image, None);
+        assert_eq!(propulsion.legs.text.as_deref(), Some("Walking"));
+        assert_eq!(propulsion.treads, PropulsionConfig::default().treads);
+    }
+
+    #[test]
+    fn timer_modes() {
+        let config = Config::parse("[presence]\ntimer = \"map\"\n").unwrap();
+        assert_eq!(config.presence.timer, TimerConfig::Map);
+        let err = Config::parse("[presence]\ntimer = \"floor\"\n").unwrap_err();
+        assert!(err.to_string().contains("unknown variant"), "{}", err);
+    }
+
+    #[test]
+    fn unknown_key_is_named() {
+        let err = Config::parse("[presence]\nlarge_imag = \"x\"\n").unwrap_err();
+        assert!(err.to_string().contains("large_imag"), "{}", err);
+    }
+
+    #[test]
+    fn invalid_value_is_named() {
+        let err = Config::parse("[presence]\napplication_id = \"cogmind\"\n").unwrap_err();
+        assert!(
+            err.to_string().starts_with("presence.application_id"),
+            "{}",
+            err
+        );
+
+        let err =
+            Config::parse("[[presence.buttons]]\nlabel = \"a\"\nurl = \"ftp://x\"\n").unwrap_err();
+        assert!(
+            err.to_string().starts_with("presence.buttons[0].url"),
+            "{}",
+            err
+        );
+
+        let err = Config::parse("[presence]\nstate = \"{depht}\"\n").unwrap_err();
+        assert!(err.to_string().starts_with("presence.state"), "{}", err);
+
+        let err = Config::parse("[presence.maps.XYZ]\nimage = \"x\"\n").unwrap_err();
+        assert!(err.to_string().starts_with("presence.maps.XYZ"), "{}", err);
+
+        let err = Config::parse("interval = -1\n").unwrap_err();
+        assert!(err.to_string().starts_with("interval"), "{}", err);
+
+        let err = Config::parse("[process]\nnames = [\"cogmind.exe\", \" \"]\n").unwrap_err();
+        assert!(err.to_string().starts_with("process.names[1]"), "{}", err);
+        let err = Config::parse("[process]\nnames = []\n").unwrap_err();
+        assert!(err.to_string().starts_with("process.names"), "{}", err);
+    }
+}
@@ -1,43 +1,355 @@
-use anyhow::anyhow;
+use crate::config::{AssetsConfig, PresenceConfig, TimerConfig, MAX_TEXT_LEN};
+use crate::propulsion::Propulsion;
+use crate::state::GameState;
+use crate::template::{Placeholders, Template};
+use crate::timeline::Timeline;
+use crate::types::MapType;
+use anyhow::{anyhow, bail, Context};
 use discord_rich_presence::activity::Activity;
 use discord_rich_presence::{activity, DiscordIpc, DiscordIpcClient};
-use std::time::{SystemTime, UNIX_EPOCH};
-pub struct PresenceProvider {
-    pub client: DiscordIpcClient,
-    pub activity: Activity<'static>,
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Activity fields rendered from the config for one poll
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderedActivity {
+    pub details: String,
+    pub state: String,
+    pub large_image: String,
+    pub large_text: String,
+    pub small_image: String,
+    pub small_text: String,
+    /// Unix time the elapsed timer counts from
+    pub start: i64,
 }
 
-impl PresenceProvider {
-    pub fn try_init() -> anyhow::Result<Self> {
-        let mut client = DiscordIpcClient::new("914720093701832724").map_err(|e| {
-            error!("{}", e);
-            anyhow!("Failed to init client!")
-        })?;
-        client.connect().map_err(|e| {
-            error!("{}", e);
-            anyhow!("Failed to connect to RPC endpoint!")
-        })?;
-        let assets = activity::Assets::new()
-            .large_image("cogmind_logo")
-            .small_image("go_treads")
-            .large_text("Cogmind b13")
-            .small_text("Treads enjoyer");
-        let mut buttons = Vec::new();
-        let start = SystemTime::now();
-        let start_time = start.duration_since(UNIX_EPOCH)?;
-        let timestamp = activity::Timestamps::new().start(start_time.as_secs() as i64);
-        buttons.push(activity::Button::new(
-            "Visit Site",
-            "https://gridsagegames.com/cogmind",
-        ));
-        let payload = Activity::new()
-            .assets(assets)
-            .details("Playing b13")
-            .buttons(buttons)
-            .timestamps(timestamp);
+struct Templates {
+    details: Template,
+    state: Template,
+    large_text: Template,
+    small_text: Template,
+    propulsion: HashMap<Propulsion, Assets>,
+    maps: HashMap<MapType, Assets>,
+}
+
+/// Parsed `AssetsConfig`
+struct Assets {
+    image: Option<String>,
+    text: Option<Template>,
+}
+
+impl Assets {
+    fn parse(config: &AssetsConfig, key: &str) -> anyhow::Result<Self> {
+        let text = config
+            .text
+            .as_deref()
+            .map(Template::parse)
+            .transpose()
+            .with_context(|| format!("{}.text", key))?;
         Ok(Self {
-            client,
-            activity: payload,
+            image: config.image.clone(),
+            text,
         })
     }
 }
+
+/// First delay before reconnecting to Discord
+const MIN_RECONNECT_DELAY: Duration = Duration::from_secs(2);
+/// Longest delay between attempts to reach Discord
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+/// Discord throttles clients that update more often than this
+const MIN_UPDATE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Discord IPC connection that comes back after Discord restarts
+struct Connection {
+    application_id: String,
+    client: Option<DiscordIpcClient>,
+    delay: Duration,
+    next_attempt: Instant,
+}
+
+impl Connection {
+    fn new(application_id: &str) -> Self {
+        Self {
+            application_id: application_id.to_owned(),
+            client: None,
+            delay: MIN_RECONNECT_DELAY,
+            next_attempt: Instant::now(),
+        }
+    }
+
+    /// Connect if we aren't and the backoff allows it; `Some(true)` means the
+    /// connection is new and has no activity yet
+    fn ensure(&mut self) -> Option<bool> {
+        if self.client.is_some() {
+            return Some(false);
+        }
+        let now = Instant::now();
+        if now < self.next_attempt {
+            return None;
+        }
+
+        match self.connect() {
+            Ok(client) => {
+                info!("Connected to Discord");
+                self.client = Some(client);
+                self.delay = MIN_RECONNECT_DELAY;
+                Some(true)
+            }
+            Err(e) => {
+                // Only the first failure in a row is worth more than a debug line
+                if self.delay == MIN_RECONNECT_DELAY {
+                    info!("Discord isn't reachable, will keep trying: {}", e);
+                } else {
+                    debug!("Couldn't connect to Discord: {}", e);
+                }
+                self.next_attempt = now + self.delay;
+                self.delay = (self.delay * 2).min(MAX_RECONNECT_DELAY);
+                None
+            }
+        }
+    }
+
+    fn connect(&self) -> anyhow::Result<DiscordIpcClient> {
+        let mut client =
+            DiscordIpcClient::new(&self.application_id).map_err(|e| anyhow!("{}", e))?;
+        client.connect().map_err(|e| anyhow!("{}", e))?;
+        Ok(client)
+    }
+
+    /// Say goodbye to Discord; the next `ensure` connects again straight away
+    fn close(&mut self) -> anyhow::Result<()> {
+        self.delay = MIN_RECONNECT_DELAY;
+        self.next_attempt = Instant::now();
+        match self.client.take() {
+            Some(mut client) => client
+                .close()
+                .map_err(|e| anyhow!("Failed to close Discord connection: {}", e)),
+            None => Ok(()),
+        }
+    }
+
+    /// Forget a client whose socket failed and schedule a reconnect
+    fn lost(&mut self, error: &str) {
+        warn!("Lost connection to Discord: {}", error);
+        self.client = None;
+        self.delay = MIN_RECONNECT_DELAY;
+        self.next_attempt = Instant::now() + MIN_RECONNECT_DELAY;
+    }
+}
+
+pub struct PresenceProvider {
+    connection: Connection,
+    config: PresenceConfig,
+    templates: Templates,
+    timeline: Timeline,
+    /// What Discord should be showing
+    current: Option<RenderedActivity>,
+    /// What Discord was last sent over this connection
+    sent: Option<RenderedActivity>,
+    last_update: Option<Instant>,
+}
+
+impl PresenceProvider {
+    /// Parse the templates; Discord doesn't have to be running yet
+    pub fn new(config: &PresenceConfig) -> anyhow::Result<Self> {
+        let templates = Templates {
+            details: Template::parse(&config.details)?,
+            state: Template::parse(&config.state)?,
+            large_text: Template::parse(&config.large_text)?,
+            small_text: Template::parse(&config.small_text)?,
+            propulsion: Propulsion::ALL
+                .into_iter()
+                .map(|kind| {
+                    let key = format!("presence.propulsion.{}", kind.key());
+                    Ok((kind, Assets::parse(config.propulsion.get(kind), &key)?))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            maps: MapType::ALL
+                .into_iter()
+                .filter_map(|map| Some((map, config.map_assets(map)?)))
+                .map(|(map, assets)| {
+                    let key = format!("presence.maps.{}", map.code());
+                    Ok((map, Assets::parse(assets, &key)?))
+                })
+                .collect::<anyhow::Result<_>>()?,
+        };
+        let mut provider = Self {
+            connection: Connection::new(&config.application_id),
+            config: config.clone(),
+            templates,
+            timeline: Timeline::default(),
+            current: None,
+            sent: None,
+            last_update: None,
+        };
+        // Connect early so a missing Discord shows up in the log at startup
+        provider.connection.ensure();
+        Ok(provider)
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connection.client.is_some()
+    }
+
+    /// Send the current activity if Discord isn't showing it yet, reconnecting
+    /// first if needed. Returns whether an update went out.
+    ///
+    /// Call this regularly: changes made within `MIN_UPDATE_INTERVAL` of the
+    /// last update are held back and only the latest one is sent.
+    pub fn poll(&mut self) -> anyhow::Result<bool> {
+        if self.connection.ensure() == Some(true) {
+            // A new connection shows nothing, so catch up straight away
+            self.sent = None;
+            self.last_update = None;
+        }
+        if !self.is_connected() || self.current == self.sent {
+            return Ok(false);
+        }
+        if let Some(last) = self.last_update {
+            if last.elapsed() < MIN_UPDATE_INTERVAL {
+                return Ok(false);
+            }
+        }
+        self.send()?;
+        Ok(true)
+    }
+
+    /// Fill the configured templates from `state`, preferring the overrides for
+    /// the current map and the player's propulsion
+    pub fn render(&mut self, state: &GameState) -> RenderedActivity {
+        let now = unix_now();
+        self.timeline
+            .observe(state.ai.location_depth, state.ai.location_map, now);
+        // In the menus both timers count from when the game got there
+        let start = self.timeline.menu_start().or(match self.config.timer {
+            TimerConfig::Run => self.timeline.run_start(),
+            TimerConfig::Map => self.timeline.map_start(),
+        });
+
+        let values = Placeholders::from_state(state);
+        let render = |template: &Template| truncate(template.render(&values));
+        let map = MapType::try_from(state.ai.location_map)
+            .ok()
+            .and_then(|map| self.templates.maps.get(&map));
+        let propulsion = state
+            .propulsion()
+            .and_then(|kind| self.templates.propulsion.get(&kind));
+
+        let image = |assets: Option<&Assets>, default: &String| {
+            assets
+                .and_then(|assets| assets.image.clone())
+                .unwrap_or_else(|| default.clone())
+        };
+        let text = |assets: Option<&Assets>, default| {
+            render(
+                assets
+                    .and_then(|assets| assets.text.as_ref())
+                    .unwrap_or(default),
+            )
+        };
+        RenderedActivity {
+            details: render(&self.templates.details),
+            state: render(&self.templates.state),
+            large_image: image(map, &self.config.large_image),
+            large_text: text(map, &self.templates.large_text),
+            small_image: image(propulsion, &self.config.small_image),
+            small_text: text(propulsion, &self.templates.small_text),
+            start: start.unwrap_or(now),
+        }
+    }
+
+    /// Make `activity` the one to show; see `poll` for when it is sent
+    pub fn set_activity(&mut self, activity: &RenderedActivity) -> anyhow::Result<bool> {
+        self.current = Some(activity.clone());
+        self.poll()
+    }
+
+    /// Remove the activity right away, ignoring the update interval
+    pub fn clear(&mut self) -> anyhow::Result<()> {
+        self.current = None;
+        if self.sent.is_none() || !self.is_connected() {
+            return Ok(());
+        }
+        self.send()
+    }
+
+    /// Clear the activity and disconnect; a later `set_activity` reconnects.
+    /// The next run starts timing from scratch.
+    pub fn close(&mut self) -> anyhow::Result<()> {
+        self.timeline.reset();
+        let cleared = self.clear();
+        self.sent = None;
+        cleared.and(self.connection.close())
+    }
+
+    /// Push `current` over the connected client, dropping the client if the socket failed
+    fn send(&mut self) -> anyhow::Result<()> {
+        let Some(client) = self.connection.client.as_mut() else {
+            bail!("Not connected to Discord");
+        };
+        let result = match &self.current {
+            Some(text) => client.set_activity(payload(&self.config, text)),
+            None => client.clear_activity(),
+        };
+        match result {
+            Ok(()) => {
+                self.sent = self.current.clone();
+                self.last_update = Some(Instant::now());
+                Ok(())
+            }
+            Err(e) => {
+                let e = e.to_string();
+                self.connection.lost(&e);
+                Err(anyhow!("Failed to update activity: {}", e))
+            }
+        }
+    }
+}
+
+fn payload<'a>(config: &'a PresenceConfig, text: &'a RenderedActivity) -> Activity<'a> {
+    let mut assets = activity::Assets::new();
+    if !text.large_image.is_empty() {
+        assets = assets.large_image(&text.large_image);
+    }
+    if !text.large_text.is_empty() {
+        assets = assets.large_text(&text.large_text);
+    }
+    if !text.small_image.is_empty() {
+        assets = assets.small_image(&text.small_image);
+    }
+    if !text.small_text.is_empty() {
+        assets = assets.small_text(&text.small_text);
+    }
+    let buttons = config
+        .buttons
+        .iter()
+        .map(|button| activity::Button::new(&button.label, &button.url))
+        .collect();
+    let timestamp = activity::Timestamps::new().start(text.start);
+    let mut payload = Activity::new().assets(assets).timestamps(timestamp);
+    if !text.details.is_empty() {
+        payload = payload.details(&text.details);
+    }
+    if !text.state.is_empty() {
+        payload = payload.state(&text.state);
+    }
+    if !config.buttons.is_empty() {
+        payload = payload.buttons(buttons);
+    }
+    payload
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs() as i64)
+}
+
+/// Cut rendered text down to what Discord accepts
+fn truncate(mut text: String) -> String {
+    if let Some((index, _)) = text.char_indices().nth(MAX_TEXT_LEN) {
+        text.truncate(index);
+    }
+    text
+}
@@ -1,4457 +1 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(non_camel_case_types)]
-pub enum ItemId {
-    Matter,
-    Data_Core,
-    Derelict_Log,
-    Schematic_Archive,
-    Imprinter_Data_Core,
-    A2_Data_Core,
-    A7_Data_Core,
-    MAIN_C_Data_Core,
-    Architect_Data_Core,
-    Scrap,
-    Protomatter,
-    Ion_Engine,
-    Lgt__Ion_Engine,
-    Backup_Power_I,
-    Imp__Ion_Engine,
-    Rnf__Ion_Engine,
-    Hvy__Ion_Engine,
-    Cld__Ion_Engine,
-    Sub__Power_Source,
-    Deuterium_Engine,
-    Lgt__Deuterium_Engine,
-    Backup_Power_III,
-    Nuclear_Core,
-    Lgt__Nuclear_Core,
-    Imp__Deuterium_Engine,
-    Rnf__Deuterium_Engine,
-    Hyb__Deuterium_Engine,
-    Hvy__Deuterium_Engine,
-    Cld__Deuterium_Engine,
-    Enh__Nuclear_Core,
-    Mic__Nuclear_Core,
-    Mak__Power_Source,
-    Angular_Momentum_Engine,
-    Lgt__Angular_Momentum_Engine,
-    F_cell_Engine,
-    Fission_Core,
-    Lgt__Fission_Core,
-    Hyb__Fission_Core,
-    Fusion_Compressor,
-    Cold_Fusion_Reactor,
-    Neutrino_Core,
-    Lgt__Neutrino_Core,
-    Rnf__Fission_Core,
-    Enh__Fission_Core,
-    Cld__Fission_Core,
-    Mic__Fission_Core,
-    Fusion_Reactor,
-    Antimatter_Reactor,
-    Lgt__Antimatter_Reactor,
-    Rnf__Antimatter_Reactor,
-    Hyb__Antimatter_Reactor,
-    Backup_Power_VII,
-    Hvy__Antimatter_Reactor,
-    Mni__Fusion_Reactor,
-    Imp__Fusion_Compressor,
-    Mic__Neutrino_Core,
-    Zio__Light_DM_Reactor,
-    Zio__Heavy_DM_Reactor,
-    Particle_Reactor,
-    Lgt__Particle_Reactor,
-    Mic__Antimatter_Reactor,
-    Com__Particle_Reactor,
-    Cld__Antimatter_Reactor,
-    Graviton_Reactor,
-    Asb__Flux_Generator,
-    Quantum_Reactor,
-    Lgt__Quantum_Reactor,
-    Rnf__Quantum_Reactor,
-    Imp__Quantum_Reactor,
-    Zero_point_Reactor,
-    Vortex_Chain_Reactor,
-    Singularity_Reactor,
-    Matter_Drive,
-    Meta_Core,
-    SE_PO1,
-    Lgt__Treads,
-    Imp__Treads,
-    Spk__Treads,
-    Arm__Treads,
-    Adv__Treads,
-    Med__Treads,
-    Imp__Medium_Treads,
-    Spk__Medium_Treads,
-    Arm__Medium_Treads,
-    Hvy__Treads,
-    Adv__Medium_Treads,
-    Enh__Armored_Medium_Treads,
-    Imp__Heavy_Treads,
-    Hvy__Siege_Treads,
-    Spk__Heavy_Treads,
-    Biometal_Medium_Treads,
-    Arm__Heavy_Treads,
-    Adv__Heavy_Treads,
-    Imp__Heavy_Siege_Treads,
-    Centrium_Heavy_Treads,
-    Megatreads,
-    Biometal_Heavy_Treads,
-    Exp__Biometal_Heavy_Treads,
-    Enh__Armored_Heavy_Treads,
-    Adv__Heavy_Siege_Treads,
-    Hdn__Centrium_Heavy_Treads,
-    Exp__Biometal_Medium_Treads,
-    Aluminum_Leg,
-    Imp__Aluminum_Leg,
-    Carbon_fiber_Leg,
-    Titanium_Leg,
-    VSS_Leg,
-    Imp__Carbon_fiber_Leg,
-    Imp__Titanium_Leg,
-    Lgt__Armored_Exoskeleton,
-    Mak__Leg,
-    Flexi_carbon_Leg,
-    Arm__Leg,
-    Imp__VSS_Leg,
-    Zio__Composite_Leg_I,
-    Med__Armored_Exoskeleton,
-    Enh__Flexi_carbon_Leg,
-    Enh__Armored_Leg,
-    Arachnoskeleton,
-    Myomer_Leg,
-    Adv__VSS_Leg,
-    Adv__Myomer_Leg,
-    Exp__Flexi_carbon_Leg,
-    Zio__Composite_Leg_II,
-    Cargo_Legs,
-    Etherial_Tendrils,
-    Biometal_Leg,
-    Hvy__Armored_Exoskeleton,
-    Enh__Biometal_Leg,
-    Exp__Myomer_Leg,
-    Tripod_Weapons_Platform,
-    T_R_O_L_L__Exoskeleton,
-    Asb__Alloy_Leg,
-    Centrium_Leg,
-    Hdn__Centrium_Leg,
-    Exp__Biometal_Leg,
-    Zio__Supercomposite_Leg,
-    Potential_Leg,
-    Myomer_Exoskeleton,
-    Sigix_Exoskeleton,
-    SE_PR1,
-    Wheel,
-    Com__Wheel,
-    Arm__Wheel,
-    Sub__Monowheel,
-    Arm__Huge_Wheel,
-    Centrium_Wheel,
-    Hdn__Centrium_Wheel,
-    Hover_Unit,
-    Backup_Propulsion_I,
-    Imp__Hover_Unit,
-    Airjet,
-    Aerolev_Unit,
-    Asb__Hover_Unit,
-    Backup_Propulsion_III,
-    Gravmag_System,
-    Asb__Hover_System,
-    Imp__Airjet,
-    Cld__Airjet,
-    Imp__Gravmag_System,
-    Cld__Aerolev_Unit,
-    Cmb__Airjet,
-    Gravmag_Array,
-    Mak__Hover_Unit,
-    Zio__Glidesys_I,
-    Antigrav_System,
-    Gyrokinetic_Inverter,
-    Linear_Gravjet,
-    Cld__Gravmag_System,
-    Cmb__Gravmag_System,
-    Imp__Antigrav_System,
-    Imp__Gyrokinetic_Inverter,
-    Imp__Linear_Gravjet,
-    Backup_Propulsion_VII,
-    Zio__Glidesys_II,
-    Cld__Antigrav_System,
-    Cmb__Antigrav_System,
-    Antigrav_Array,
-    Exp__Gyrokinetic_Inverter,
-    Cld__Linear_Gravjet,
-    Cmb__Linear_Gravjet,
-    Flight_Unit,
-    VTOL_Module,
-    Imp__Flight_Unit,
-    Cesium_ion_Thruster,
-    Surge_Thruster,
-    Imp__VTOL_Module,
-    Xenon_Bombardment_Thruster,
-    Imp__Cesium_ion_Thruster,
-    Cld__VTOL_Module,
-    Mak__Flight_Unit,
-    Nuclear_Pulse_Thruster,
-    Imp__Surge_Thruster,
-    Mni__Nuclear_Pulse_Thruster,
-    Cld__Cesium_ion_Thruster,
-    Biomechanical_Wings,
-    Field_Propulsion_Drive,
-    Cld__Nuclear_Pulse_Thruster,
-    Exp__Cesium_ion_Thruster,
-    Nuclear_Pulse_Array,
-    Mak__Microthruster,
-    Impulse_Thruster,
-    Adv__Surge_Thruster,
-    Cld__Field_Propulsion_Drive,
-    Field_Propulsion_Array,
-    Diametric_Drive,
-    Flying_Fortress_v7,
-    T_thruster,
-    Integrated_Tracker_Drive,
-    Etherial_Propulsion,
-    Q_thruster,
-    Electron_Diverter,
-    Imp__Diametric_Drive,
-    Cld__Impulse_Thruster,
-    Impulse_Thruster_Array,
-    Flightbrick,
-    Imp__Q_thruster,
-    Cld__Q_thruster,
-    Exp__Q_thruster,
-    Scrap_Engine,
-    Arm__Scrap_Engine,
-    Desublimator,
-    Power_Amplifier,
-    Adv__Power_Amplifier,
-    Exp__Power_Amplifier,
-    Thermal_Generator,
-    Imp__Thermal_Generator,
-    Adv__Thermal_Generator,
-    Exp__Thermal_Generator,
-    Cryofiber_Web,
-    Imp__Cryofiber_Web,
-    Adv__Cryofiber_Web,
-    Exp__Cryofiber_Web,
-    Heat_Sink,
-    Imp__Heat_Sink,
-    Adv__Heat_Sink,
-    Exp__Heat_Sink,
-    Cooling_System,
-    Imp__Cooling_System,
-    Adv__Cooling_System,
-    Exp__Cooling_System,
-    Coolant_Network,
-    Asb__Nanovents,
-    _2N_1CE_s_Frost_Array,
-    Disposable_Heat_Sink,
-    Coolant_Injector,
-    Imp__Coolant_Injector,
-    Adv__Coolant_Injector,
-    Exp__Coolant_Injector,
-    Sml__Storage_Unit,
-    Med__Storage_Unit,
-    Lrg__Storage_Unit,
-    Hcp__Storage_Unit,
-    Huge_Storage_Unit,
-    Cargo_Storage_Unit,
-    Humpback,
-    Lightpack_2_0,
-    Sml__Battery,
-    Med__Battery,
-    Lrg__Battery,
-    Hcp__Battery,
-    Com__Battery,
-    Energy_Well,
-    Imp__Energy_Well,
-    Adv__Energy_Well,
-    Exp__Energy_Well,
-    Zio__Biocell,
-    Asb__Biocell_Array,
-    Zio__Biocell_Array,
-    Superbattery,
-    Sml__Matter_Pod,
-    Med__Matter_Pod,
-    Lrg__Matter_Pod,
-    Hcp__Matter_Pod,
-    Com__Matter_Pod,
-    Matter_Compressor,
-    Imp__Matter_Compressor,
-    Adv__Matter_Compressor,
-    Exp__Matter_Compressor,
-    YI_UF0_s_Bottomless_Matter_Pit,
-    Weight_Redist__System,
-    Adv__Weight_Redist__System,
-    Gravity_Neutralizer,
-    Adv__Gravity_Neutralizer,
-    Quantum_Shading_Machine,
-    Asb__Suspension_Frame,
-    Adv__Quantum_Shading_Machine,
-    Asb__Metafiber_Network,
-    Zio__Metafield_Generator,
-    ST_Field_Compressor,
-    Structural_Scanner,
-    Trap_Scanner,
-    Imp__Trap_Scanner,
-    Mak__Trap_Scanner,
-    Adv__Trap_Scanner,
-    Machine_Analyzer,
-    Triangulator,
-    Visual_Processing_Unit,
-    Sub__Optics,
-    Adv__Visual_Processing_Unit,
-    Enh__Optical_Array,
-    Exp__Optical_Array,
-    Spectral_Analyzer,
-    Sensor_Array,
-    Imp__Sensor_Array,
-    Adv__Sensor_Array,
-    Lrn__Sensor_Array,
-    Mak__Sensor_Array,
-    Exp__Sensor_Array,
-    Signal_Interpreter,
-    Imp__Signal_Interpreter,
-    Adv__Signal_Interpreter,
-    Exp__Signal_Interpreter,
-    Active_Sensor_Suite,
-    Terrain_Scanner,
-    Imp__Terrain_Scanner,
-    Adv__Terrain_Scanner,
-    Mak__Terrain_Scanner,
-    Lrn__Terrain_Scanner,
-    Exp__Terrain_Scanner,
-    Surveybot_24_Scanner,
-    Terrain_Scan_Processor,
-    Imp__Terrain_Scan_Processor,
-    Mak__Terrain_Scan_Processor,
-    Adv__Terrain_Scan_Processor,
-    Exp__Terrain_Scan_Processor,
-    Surveybot_24_Chip,
-    Seismic_Detector,
-    Transport_Network_Coupler,
-    Modified_TNC,
-    Encrypted_Comm_Array,
-    _0b10_Decoder_Chip__Generic_,
-    _0b10_Decoder_Chip__Looter_,
-    _0b10_Decoder_Chip__Scout_,
-    _0b10_Decoder_Chip__Skirmisher_,
-    _0b10_Alert_Chip,
-    Salvage_Targeting_Computer,
-    Imp__Salvage_Targeting_Computer,
-    Mak__Salvage_Targeting_Computer,
-    Adv__Salvage_Targeting_Computer,
-    Asb__Combat_Suite,
-    Targeting_Computer,
-    Imp__Targeting_Computer,
-    Adv__Targeting_Computer,
-    Exp__Targeting_Computer,
-    Multitargeting_Array,
-    Tactical_Coordination_Suite,
-    Melee_Analysis_Suite,
-    Imp__Melee_Analysis_Suite,
-    Adv__Melee_Analysis_Suite,
-    Exp__Melee_Analysis_Suite,
-    Launcher_Guidance_Computer,
-    Imp__Launcher_Guidance_Computer,
-    Adv__Launcher_Guidance_Computer,
-    Weapon_Mount,
-    Target_Analyzer,
-    Imp__Target_Analyzer,
-    Adv__Target_Analyzer,
-    Exp__Target_Analyzer,
-    Core_Analyzer,
-    Exp__Core_Analyzer,
-    Armor_Integrity_Analyzer,
-    Imp__Armor_Integrity_Analyzer,
-    Exp__Armor_Integrity_Analyzer,
-    Recoil_Stabilizer,
-    Recoil_Nullifier,
-    Matter_Filter,
-    Prc__Matter_Filter,
-    Energy_Filter,
-    Prc__Energy_Filter,
-    Particle_Charger,
-    Imp__Particle_Charger,
-    Adv__Particle_Charger,
-    Particle_Accelerator,
-    Imp__Particle_Accelerator,
-    Adv__Particle_Accelerator,
-    Kinecellerator,
-    Imp__Kinecellerator,
-    Adv__Kinecellerator,
-    Heavy_Servo_Lattice,
-    VL_GR5_s_Timing_Chip,
-    Launcher_Loader,
-    Quantum_Capacitor,
-    Weapon_Cycler,
-    Imp__Weapon_Cycler,
-    Adv__Weapon_Cycler,
-    Exp__Weapon_Cycler,
-    Microactuators,
-    Nanoactuators,
-    Femtoactuators,
-    Actuator_Array,
-    Imp__Actuator_Array,
-    Adv__Actuator_Array,
-    Exp__Actuator_Array,
-    Force_Booster,
-    Imp__Force_Booster,
-    Adv__Force_Booster,
-    Stasis_Canceller,
-    Transmission_Jammer,
-    Mak__Transmission_Jammer,
-    Imp__Transmission_Jammer,
-    Adv__Transmission_Jammer,
-    Exp__Transmission_Jammer,
-    ECM_Suite,
-    Mak__ECM_Suite,
-    Adv__ECM_Suite,
-    Exp__ECM_Suite,
-    Active_Sensor_Spoofer,
-    Reaction_Control_System,
-    Imp__Reaction_Control_System,
-    Adv__Reaction_Control_System,
-    Exp__Reaction_Control_System,
-    Phase_Shifter,
-    Imp__Phase_Shifter,
-    Adv__Phase_Shifter,
-    Exp__Phase_Shifter,
-    Cloaking_Device,
-    Mak__Cloaking_Device,
-    Imp__Cloaking_Device,
-    Adv__Cloaking_Device,
-    Exp__Cloaking_Device,
-    Cloak_of_Protection,
-    _5H_AD0_s_Cloak,
-    Core_Shielding,
-    Imp__Core_Shielding,
-    Exp__Core_Shielding,
-    Power_Shielding,
-    Imp__Power_Shielding,
-    Exp__Power_Shielding,
-    Propulsion_Shielding,
-    Imp__Propulsion_Shielding,
-    Exp__Propulsion_Shielding,
-    Utility_Shielding,
-    Imp__Utility_Shielding,
-    Exp__Utility_Shielding,
-    Weapon_Shielding,
-    Imp__Weapon_Shielding,
-    Exp__Weapon_Shielding,
-    Zio__Weapon_Casing,
-    Backup_Plating_I,
-    Backup_Plating_III,
-    Backup_Plating_VII,
-    Lgt__Armor_Plating,
-    Med__Armor_Plating,
-    Hvy__Armor_Plating,
-    Zio__Light_Carapace,
-    Zio__Heavy_Carapace,
-    Hrd__Light_Armor_Plating,
-    Hrd__Medium_Armor_Plating,
-    Hrd__Heavy_Armor_Plating,
-    Imp__Light_Armor_Plating,
-    Imp__Medium_Armor_Plating,
-    Imp__Heavy_Armor_Plating,
-    Mak__Light_Armor_Plating,
-    Lyr__Light_Armor_Plating,
-    Lyr__Medium_Armor_Plating,
-    Lyr__Heavy_Armor_Plating,
-    Gun_Armor,
-    Centrium_Light_Armor_Plating,
-    Centrium_Medium_Armor_Plating,
-    Centrium_Heavy_Armor_Plating,
-    Centrium_Linked_Plating,
-    Reactive_Plating,
-    Med__Reactive_Plating,
-    Mak__Kinetic_Plating,
-    Hvy__Reactive_Plating,
-    Reflective_Plating,
-    Med__Reflective_Plating,
-    Mak__Thermal_Plating,
-    Hvy__Reflective_Plating,
-    _8R_AWN_s_Armor_TH,
-    _8R_AWN_s_Armor_EX,
-    Insulated_Plating,
-    Med__Insulated_Plating,
-    Hvy__Insulated_Plating,
-    Damper_Plating,
-    Zio__Shade_Carapace,
-    Zio__Shade_Armor,
-    Asb__Alloy_Armor,
-    Mak__Ablative_Armor,
-    Active_Cooling_Armor,
-    Scrap_Shield,
-    Powered_Armor,
-    Imp__Powered_Armor,
-    Adv__Powered_Armor,
-    Exp__Powered_Armor,
-    _1C_UTU_s_Buckler,
-    Lgt__Regenerative_Plating,
-    Med__Regenerative_Plating,
-    Hvy__Regenerative_Plating,
-    SHELL_Armor,
-    Phase_Armor,
-    Graphene_Brace,
-    Focal_Shield,
-    Imp__Focal_Shield,
-    Adv__Focal_Shield,
-    Exp__Focal_Shield,
-    Thermal_Shield,
-    Imp__Thermal_Shield,
-    Adv__Thermal_Shield,
-    Exp__Thermal_Shield,
-    Thermal_Barrier,
-    Beam_Splitter,
-    Thermal_Defense_Suite,
-    Imp__Thermal_Defense_Suite,
-    Adv__Thermal_Defense_Suite,
-    Exp__Thermal_Defense_Suite,
-    Shock_Absorption_System,
-    Imp__Shock_Absorption_System,
-    Exp__Shock_Absorption_System,
-    EM_Disruption_Field,
-    Adv__EM_Disruption_Field,
-    Exp__EM_Disruption_Field,
-    ME_RLN_s_Chromatic_Screen,
-    Hardlight_Generator,
-    Imp__Hardlight_Generator,
-    Adv__Hardlight_Generator,
-    Exp__Hardlight_Generator,
-    Shield_Generator,
-    Imp__Shield_Generator,
-    Adv__Shield_Generator,
-    Exp__Shield_Generator,
-    Force_Field,
-    Imp__Force_Field,
-    Adv__Force_Field,
-    Exp__Force_Field,
-    _7V_RTL_s_Ultimate_Field,
-    Vortex_Field_Projector,
-    Remote_Shield,
-    Imp__Remote_Shield,
-    Adv__Remote_Shield,
-    Remote_Force_Field,
-    Imp__Remote_Force_Field,
-    Energy_Mantle,
-    Imp__Energy_Mantle,
-    AEGIS_Remote_Shield,
-    Phase_Redirector,
-    Point_Defense_System,
-    Point_Defense_Array,
-    Antimissile_System,
-    EX_Chip_1,
-    Hacking_Suite,
-    Mak__Hacking_Suite,
-    Imp__Hacking_Suite,
-    Adv__Hacking_Suite,
-    Exp__Hacking_Suite,
-    System_Mapper,
-    Deep_Network_Scanner,
-    Architect_God_Chip_A,
-    EX_Chip_2,
-    System_Shield,
-    Mak__System_Shield,
-    Imp__System_Shield,
-    Adv__System_Shield,
-    Exp__System_Shield,
-    Ghost_Barrier,
-    Quantum_Router,
-    Architect_God_Chip_D,
-    Authchip__R_NC_,
-    Authchip__R_Combat_,
-    Authchip__Power_,
-    Authchip__Propulsion_,
-    Authchip__Device_,
-    Authchip__Storage_,
-    Authchip__Processor_,
-    Authchip__Armor_,
-    Authchip__Weapon_,
-    Relay_Coupler__NC_,
-    Relay_Coupler__C_,
-    Relay_Coupler__Swarmer_,
-    Relay_Coupler__Cutter_,
-    Relay_Coupler__Grunt_,
-    Relay_Coupler__Brawler_,
-    Relay_Coupler__Duelist_,
-    Relay_Coupler__Sentry_,
-    Relay_Coupler__Demolisher_,
-    Relay_Coupler__Specialist_,
-    Relay_Coupler__Hunter_,
-    Relay_Coupler__Heavy_,
-    Relay_Coupler__Behemoth_,
-    Relay_Coupler__Programmer_,
-    Relay_Coupler__Proto_,
-    Skeleton_Box,
-    _01_MTF_s_Autohacker,
-    Component_Analysis_Suite,
-    Imp__Component_Analysis_Suite,
-    Adv__Component_Analysis_Suite,
-    Dynamic_Insulation_System,
-    Imp__Dynamic_Insulation_System,
-    Adv__Dynamic_Insulation_System,
-    System_Guard,
-    Imp__System_Guard,
-    Exp__System_Guard,
-    Corruption_Screen,
-    Imp__Corruption_Screen,
-    Adv__Corruption_Screen,
-    System_Restoration_Module,
-    Mak__System_Restoration_Module,
-    Imp__System_Restoration_Module,
-    Adv__System_Restoration_Module,
-    System_Purifier,
-    Exp__System_Purifier,
-    Tractor_Beam,
-    Hpw__Tractor_Beam,
-    Lrn__Tractor_Beam,
-    Mobile_Refinery_Mk__CCLXVII,
-    Trap_Extractor,
-    Imp__Trap_Extractor,
-    Adv__Trap_Extractor,
-    Trap_Reconfigurator,
-    Recalibrator,
-    Imp__Recalibrator,
-    Adv__Recalibrator,
-    Field_Recycling_Unit,
-    Imp__Field_Recycling_Unit,
-    Adv__Field_Recycling_Unit,
-    Exp__Field_Recycling_Unit,
-    Drone_Bay,
-    Z_Drone_Bay,
-    Mni__Drone_Bay,
-    Mapping_Drone_Bay,
-    Adv__Drone_Bay,
-    Bomb_Drone_Bay,
-    Thief_Drone_Bay,
-    Decoy_Drone_Bay,
-    Splice_Drone_Bay,
-    Combat_Drone_Bay,
-    Assault_Drone_Bay,
-    Stealth_Drone_Bay,
-    Swarm_Drone_Bay,
-    Supersonic_Drone_Bay,
-    Sensor_Drone_Bay,
-    Hacking_Drone_Bay,
-    Minesniffer_Drone_Bay,
-    Army_in_a_Box,
-    Wardrone_Bay,
-    Cobbler_Unit,
-    Bomb_Factory_No__2,
-    Mobile_Assembly_Unit,
-    Heart_of_Steel,
-    GOLEM_Unit,
-    PSU_Rigger,
-    Phasing_AFG,
-    AS_neutralizer_No__17b,
-    V3_11A_s_Pendant,
-    Chronowheel,
-    Power_Bank,
-    Rainbow_Chip,
-    VL_GR5_s_Exoskeleton__Deathgrip_,
-    Dimensional_Node_Initializer,
-    Transdimensional_Reconstructor,
-    Hpw__Transdimensional_Reconstructor,
-    Supercharged_TR,
-    Core_Expander,
-    Core_Regenerator,
-    Integrity_Redistributor,
-    Integrated_Dissipator,
-    Supercharged_Integrated_Dissipator,
-    Integrated_Thermoelectric_Network,
-    Integrated_Reactor,
-    Supercharged_Integrated_Reactor,
-    Integrated_Mediator,
-    Core_Membrane,
-    Navigation_Efficiency_Matrix,
-    Core_Reset_Matrix,
-    Subatomic_Replicator,
-    Terrabomb,
-    Core_Physics_Calibrator,
-    Spacefold_Activator,
-    Microwarp_Drive,
-    LC_Capacitor,
-    LRC_Armor,
-    LRC_Storage,
-    LRC_Energy_Well,
-    LRC_Matter_Compressor,
-    LRC_Inertial_Stabilizer,
-    LRC_Insulator,
-    EM_Pulse_Gun,
-    Hvy__EM_Pulse_Gun,
-    EM_Shotgun,
-    Imp__EM_Shotgun,
-    Shock_Rifle,
-    Riot_Gun,
-    Hvy__EM_Shotgun,
-    Lightning_Gun,
-    Hvy__Riot_Gun,
-    Arc_Projector,
-    Prc__Shock_Rifle,
-    Hpw__Shock_Rifle,
-    Arc_Thrower,
-    Imp__Lightning_Gun,
-    Tesla_Rifle,
-    Imp__Arc_Projector,
-    Hvy__Tesla_Rifle,
-    Hyp__EM_Gauss_Rifle,
-    Imp__Arc_Thrower,
-    _01_MTF_s_Shockpuncher,
-    Gamma_Rifle,
-    Tachyon_Dispersion_Ray,
-    Immobilizer,
-    Modified_EM_Gauss_Rifle,
-    AWS_EX_DEC,
-    Enh__Gamma_Rifle,
-    Med__Laser,
-    Sml__Laser,
-    Backup_Laser_I,
-    Beam_Rifle,
-    Particle_Gun,
-    Spread_Laser,
-    Imp__Medium_Laser,
-    Sub__Laser,
-    Autobeam,
-    Field_Laser,
-    Pulse_Rifle,
-    Backup_Laser_III,
-    Hvy__Particle_Gun,
-    Prc__Beam_Rifle,
-    Adv__Beam_Rifle,
-    Hvy__Laser,
-    Gatling_Laser,
-    Cld__Pulse_Rifle,
-    Hpw__Field_Laser,
-    Imp__Spread_Laser,
-    Mak__Laser,
-    Plasma_Rifle,
-    Thermic_Laser,
-    Beamcaster,
-    Force_Rifle,
-    Imp__Heavy_Laser,
-    Storm_Laser,
-    Zio__Laser_S,
-    Wave_Gun,
-    Variable_Charge_Gun,
-    Hvy__Beamcaster,
-    Cld__Plasma_Rifle,
-    Enh__Force_Rifle,
-    Hvy__Gatling_Laser,
-    Zio__Phaser_S,
-    Phase_Gun,
-    Dispersion_Rifle,
-    Backup_Laser_VII,
-    Plasma_Storm,
-    Adv__Plasma_Rifle,
-    Hvy__Wave_Gun,
-    Adv__Variable_Charge_Gun,
-    Cld__Phase_Gun,
-    Lrn__Gatling_Laser,
-    Warp_Gun,
-    Vortex_Rifle,
-    Zio__Laser_M,
-    AWS_Thermal,
-    PD_Laser,
-    Quantum_Rifle,
-    Prc__Phase_Gun,
-    Hvy__Dispersion_Rifle,
-    Vortex_Shotgun,
-    Vortex_Rail,
-    Zio__Phaser_M,
-    Enh__Quantum_Rifle,
-    Zio__Laser_H,
-    Hvy__Quantum_Rifle,
-    Zio__Phaser_H,
-    Gatling_Beam,
-    Sigix_Sheargun,
-    Modified_Sigix_Sheargun,
-    Lgt__EM_Pulse_Cannon,
-    EM_Pulse_Cannon,
-    Imp__EM_Pulse_Cannon,
-    Proton_Cannon,
-    Lrn__Proton_Cannon,
-    Disruptor_Cannon,
-    Hvy__Proton_Cannon,
-    HERF_Cannon,
-    Hvy__Disruptor_Cannon,
-    Cld__Proton_Cannon,
-    Proton_Burst_Cannon,
-    Com__HERF_Cannon,
-    Latent_Energy_Streamer,
-    NK_0LA_s_Tesla_Doomcannon,
-    EMDS,
-    Hpw__Disruptor_Cannon,
-    Beam_Cannon,
-    Particle_Cannon,
-    Adv__Beam_Cannon,
-    Sub__Beam_Cannon,
-    Hvy__Particle_Cannon,
-    Ion_Cannon,
-    Plasma_Cannon,
-    Hvy__Ion_Cannon,
-    Cld__Particle_Cannon,
-    Phase_Cannon,
-    Hvy__Plasma_Cannon,
-    Cld__Plasma_Cannon,
-    Com__Ion_Cannon,
-    Ion_Burst_Cannon,
-    Adv__Plasma_Cannon,
-    Hvy__Phase_Cannon,
-    Neutron_Cannon,
-    Powered_Cannon,
-    Matter_Neutralizer,
-    Hvy__Neutron_Cannon,
-    Lrn__Phase_Cannon,
-    Cld__Phase_Cannon,
-    Vortex_Cannon,
-    Gui__Plasma_Cannon,
-    Nova_Cannon,
-    Imp__Matter_Neutralizer,
-    Exp__Thermic_Cannon,
-    Vortex_Driver,
-    Zio__Alpha_Cannon,
-    Asb__Focal_Cannon,
-    Enh__Nova_Cannon,
-    Firepult,
-    YOLO_Cannon,
-    Potential_Cannon,
-    Warp_Cannon,
-    Cld__Nova_Cannon,
-    Null_Cannon,
-    Imp__Potential_Cannon,
-    Multinova_Projection_Cannon,
-    Disintegrator,
-    Core_Cannon,
-    Sigix_Shearcannon,
-    Drained_L_Cannon,
-    L_Cannon,
-    Zio__Alpha_Cannon_Mk__II,
-    BFG_9k_Vortex_Edition,
-    Assault_Rifle,
-    Asb__Rifle,
-    Lgt__Assault_Rifle,
-    Hvy__Assault_Rifle,
-    Autogun,
-    Shotgun,
-    Battle_Rifle,
-    Asb__Heavy_Rifle,
-    Sub__Machine_Gun,
-    Imp__Assault_Rifle,
-    Minigun,
-    Hpw__Shotgun,
-    Asb__Shotgun,
-    Barrage_Gun,
-    Enh__Autogun,
-    Gauss_Rifle,
-    Asb__Gauss_Rifle,
-    Flak_Gun,
-    Prc__Assault_Rifle,
-    Hvy__Battle_Rifle,
-    Mak__Shrapnel_Gun,
-    Coil_Gun,
-    Hyp__Gauss_Rifle,
-    Hvy__Machine_Gun,
-    Imp__Heavy_Machine_Gun,
-    Com__Gauss_Rifle,
-    XL_Autogun_Subcomponent,
-    KE_Penetrator,
-    Hyp__Coil_Gun,
-    Imp__KE_Penetrator,
-    Enh__Coil_Gun,
-    Com__Coil_Gun,
-    Railgun,
-    Adv__KE_Penetrator,
-    Linked_Autogun,
-    AWS_Gauss,
-    Hyp__Railgun,
-    Helical_Railgun,
-    Com__Railgun,
-    XL_Autogun_Array,
-    _12_ASH_s_Boomstick,
-    _8R_AWN_s_Boregun,
-    Multirail,
-    Hyp__Multirail,
-    _5H_AD0_s_Sniper_Rifle,
-    A3_s_Sniper_Rifle,
-    Lgt__Cannon,
-    Imp__Light_Cannon,
-    Battle_Cannon,
-    Assault_Cannon,
-    Hvy__Assault_Cannon,
-    Flak_Cannon,
-    Adv__Assault_Cannon,
-    Gauss_Cannon,
-    Slug_Cannon,
-    Mni__Assault_Cannon,
-    Magnetic_Acceleration_Cannon,
-    Hvy__Flak_Cannon,
-    Bore_Cannon,
-    Antimatter_Cannon,
-    Mass_Driver,
-    Hvy__Mass_Driver,
-    Enh__Gauss_Cannon,
-    Hyp__Gauss_Cannon,
-    Tri_rail_Accelerator,
-    Hardcell_Cannon,
-    Hvy__Gauss_Cannon,
-    Hyp__Mass_Driver,
-    Hvy__Autocannon,
-    DAS_Cannon,
-    Lgt__Antimatter_Cannon,
-    Linear_Accelerator,
-    Hvy__Linear_Accelerator,
-    Com__Mass_Driver,
-    Hvy__Hardcell_Cannon,
-    Com__Linear_Accelerator,
-    Hyp__Linear_Accelerator,
-    Hcl__Linear_Accelerator,
-    Graviton_Cannon,
-    Blast_Cannon,
-    P1_3CE_s_Gatling_Flakker,
-    AWS_Autocannon,
-    Perforator,
-    Omega_Cannon,
-    EMP_Blaster,
-    Shock_Bomb_Launcher,
-    Adv__EMP_Blaster,
-    Proton_Missile_Launcher,
-    Imp__Proton_Missile_Launcher,
-    Gui__EMP_Blaster,
-    Hvy__Shock_Bomb_Launcher,
-    Tesla_Bomb_Launcher,
-    Hvy__Proton_Missile_Launcher,
-    Com__Tesla_Bomb_Launcher,
-    Gamma_Bomb_Array,
-    Enh__Gamma_Bomb_Array,
-    Grenade_Launcher,
-    Rocket_Launcher,
-    Mni__Grenade_Launcher,
-    Sub__Shrapnel_Launcher,
-    Imp__Grenade_Launcher,
-    Missile_Launcher,
-    Concussive_RPG,
-    YI_UF0_s_Doublenader,
-    Hvy__Rocket_Launcher,
-    Hvy__Missile_Launcher,
-    Gui__Missile_Launcher,
-    Prc__Grenade_Launcher,
-    Rocket_Array,
-    Lrn__Missile_Launcher,
-    Imp__Concussive_RPG,
-    Prc__Rocket_Launcher,
-    Scatter_Rocket_Array,
-    Smartbomb_Launcher,
-    Mni__Smartbomb_Launcher,
-    Tiamat_Missile_Launcher,
-    Thermoblaster,
-    Micro_nuke_Launcher,
-    Adv__Concussive_RPG,
-    Neutron_Missile_Launcher,
-    Fusion_Bomb_Launcher,
-    Hellfire_Missile_Launcher,
-    Adv__Micro_nuke_Launcher,
-    Tactical_Quantum_Warhead,
-    Vortex_Catalyst_Activator,
-    Chain_Missile_Launcher,
-    Ragnarok_Missile_Launcher,
-    Point_Singularity_Launcher,
-    Gui__Micro_nuke_Launcher,
-    _99_TNT_s_Nukerbomber,
-    Z_bomb_Delivery_System,
-    Sigix_Terminator,
-    Supercharged_Sigix_Terminator,
-    Containment_Facilitator,
-    Detonator,
-    Dirty_Datajack,
-    Splice_Injector,
-    Master_Link,
-    Datajack,
-    Imp__Datajack,
-    Adv__Datajack,
-    Remote_Datajack,
-    Imp__Remote_Datajack,
-    Adv__Remote_Datajack,
-    Exp__Remote_Datajack,
-    Gui__Remote_Datajack,
-    ME_RLN_s_Wand,
-    Field_Lobotomy_Kit,
-    Mining_Laser,
-    Welding_Torch,
-    Plasma_Cutter,
-    Imp__Plasma_Cutter,
-    Exp__Plasma_Cutter,
-    CPS_Tube,
-    Multitool,
-    Flamer,
-    Asb__F_torch,
-    Enh__Flamer,
-    Plasma_Flamer,
-    Asb__P_torch,
-    RU_N14_s_Throwing_Claymores,
-    RU_N14_s_Throwing_Claymores_v3,
-    AD_0RF_s_Magmablaster,
-    Stasis_Beam,
-    Stasis_Projector,
-    Exp__Stasis_Projector,
-    Molecular_Deconstructor,
-    SE_WE1,
-    SE_WE2,
-    SE_WE3,
-    SE_WE4,
-    SE_WE5,
-    Hammer,
-    Mace,
-    Flail,
-    Maul,
-    Asb__Maul,
-    Hvy__Hammer,
-    Hvy__Mace,
-    Hvy__Flail,
-    Great_Maul,
-    Powered_Hammer,
-    Mak__Hammer,
-    Power_Maul,
-    Asb__P_maul,
-    Impact_Mace,
-    Shock_Maul,
-    Thunder_Hammer,
-    Compactor,
-    Gravity_Flail,
-    CL_ANK_s_Mallet,
-    Core_Stripper,
-    Mining_Claw,
-    EC_1,
-    Katana,
-    Asb__Blade,
-    Scythe,
-    Axe,
-    Blade_Saw,
-    EC_3,
-    Chainsword,
-    Ripper,
-    Mak__Axe,
-    Mak__Sword,
-    Great_Axe,
-    Greatsword,
-    Power_Sword,
-    Asb__P_sword,
-    Falx,
-    Carbide_Saw,
-    Segregator,
-    Tearclaws,
-    EC_5,
-    Phasing_Sabre,
-    Plasma_Sword,
-    Ionic_Axe,
-    Mni__Tearclaws,
-    Dual_blade_Saw,
-    Vibroblade,
-    Molecular_Scythe,
-    Centrium_Greatsword,
-    Master_Tearclaws,
-    Longsword__1,
-    EC_7,
-    Nanosword,
-    Particle_Cleaver,
-    Centrium_Claws,
-    _1C_UTU_s_Sword__Lootmaker_,
-    Vortex_Shredder,
-    EC_9,
-    _1C_UTU_s_Sword__Choppy_,
-    Quantum_Blade,
-    Sigix_Broadsword,
-    Spear,
-    Mak__Spear,
-    CR_A16_s_Pointy_Stick,
-    Hvy__Pick,
-    Lance,
-    Kinetic_Spear,
-    Force_Lance,
-    Plasma_Lance,
-    Enh__Force_Lance,
-    Vortex_Lance,
-    CR_A16_s_Behemoth_Slayer,
-    Blade_Trap,
-    Segregator_Trap,
-    Explosive_Trap,
-    Heavy_Explosive_Trap,
-    Tiamat_Bomb_Trap,
-    Fusion_Bomb_Trap,
-    Hellfire_Trap,
-    Armageddon_Trap,
-    Dirty_Bomb_Trap,
-    EMP_Trap,
-    Proton_Bomb_Trap,
-    Tesla_Bomb_Trap,
-    Gamma_Bomb_Trap,
-    Shrapnel_Trap,
-    Piercing_Trap,
-    Shock_Trap,
-    EM_Surge_Trap,
-    Fire_Trap,
-    Stasis_Trap,
-    Scrap_Shield_Fragment,
-    Signal_Generator,
-    Self_destruct_Interrupter,
-    Sapper_Charge,
-    Zhirov_Special,
-    Deploy_a_Sentry,
-    GAL_Module,
-    Portable_AFG,
-    SGEMP_Prototype,
-    Terrabomb_Derivative,
-    Sigix_Containment_Pod,
-    Sigix_Corpse,
-    Meganuke,
-    Gamma_Refractor,
-}
-
-impl ItemId {
-    pub fn from_id(id: i32) -> Option<Self> {
-        match id {
-            0 => Some(Self::Matter),
-            1 => Some(Self::Data_Core),
-            2 => Some(Self::Derelict_Log),
-            3 => Some(Self::Schematic_Archive),
-            4 => Some(Self::Imprinter_Data_Core),
-            5 => Some(Self::A2_Data_Core),
-            6 => Some(Self::A7_Data_Core),
-            7 => Some(Self::MAIN_C_Data_Core),
-            8 => Some(Self::Architect_Data_Core),
-            9 => Some(Self::Scrap),
-            10 => Some(Self::Protomatter),
-            11 => Some(Self::Ion_Engine),
-            12 => Some(Self::Lgt__Ion_Engine),
-            13 => Some(Self::Backup_Power_I),
-            14 => Some(Self::Imp__Ion_Engine),
-            15 => Some(Self::Rnf__Ion_Engine),
-            16 => Some(Self::Hvy__Ion_Engine),
-            17 => Some(Self::Cld__Ion_Engine),
-            18 => Some(Self::Sub__Power_Source),
-            19 => Some(Self::Deuterium_Engine),
-            20 => Some(Self::Lgt__Deuterium_Engine),
-            21 => Some(Self::Backup_Power_III),
-            22 => Some(Self::Nuclear_Core),
-            23 => Some(Self::Lgt__Nuclear_Core),
-            24 => Some(Self::Imp__Deuterium_Engine),
-            25 => Some(Self::Rnf__Deuterium_Engine),
-            26 => Some(Self::Hyb__Deuterium_Engine),
-            27 => Some(Self::Hvy__Deuterium_Engine),
-            28 => Some(Self::Cld__Deuterium_Engine),
-            29 => Some(Self::Enh__Nuclear_Core),
-            30 => Some(Self::Mic__Nuclear_Core),
-            31 => Some(Self::Mak__Power_Source),
-            32 => Some(Self::Angular_Momentum_Engine),
-            33 => Some(Self::Lgt__Angular_Momentum_Engine),
-            34 => Some(Self::F_cell_Engine),
-            35 => Some(Self::Fission_Core),
-            36 => Some(Self::Lgt__Fission_Core),
-            37 => Some(Self::Hyb__Fission_Core),
-            38 => Some(Self::Fusion_Compressor),
-            39 => Some(Self::Cold_Fusion_Reactor),
-            40 => Some(Self::Neutrino_Core),
-            41 => Some(Self::Lgt__Neutrino_Core),
-            42 => Some(Self::Rnf__Fission_Core),
-            43 => Some(Self::Enh__Fission_Core),
-            44 => Some(Self::Cld__Fission_Core),
-            45 => Some(Self::Mic__Fission_Core),
-            46 => Some(Self::Fusion_Reactor),
-            47 => Some(Self::Antimatter_Reactor),
-            48 => Some(Self::Lgt__Antimatter_Reactor),
-            49 => Some(Self::Rnf__Antimatter_Reactor),
-            50 => Some(Self::Hyb__Antimatter_Reactor),
-            51 => Some(Self::Backup_Power_VII),
-            52 => Some(Self::Hvy__Antimatter_Reactor),
-            53 => Some(Self::Mni__Fusion_Reactor),
-            54 => Some(Self::Imp__Fusion_Compressor),
-            55 => Some(Self::Mic__Neutrino_Core),
-            56 => Some(Self::Zio__Light_DM_Reactor),
-            57 => Some(Self::Zio__Heavy_DM_Reactor),
-            58 => Some(Self::Particle_Reactor),
-            59 => Some(Self::Lgt__Particle_Reactor),
-            60 => Some(Self::Mic__Antimatter_Reactor),
-            61 => Some(Self::Com__Particle_Reactor),
-            62 => Some(Self::Cld__Antimatter_Reactor),
-            63 => Some(Self::Graviton_Reactor),
-            64 => Some(Self::Asb__Flux_Generator),
-            65 => Some(Self::Quantum_Reactor),
-            66 => Some(Self::Lgt__Quantum_Reactor),
-            67 => Some(Self::Rnf__Quantum_Reactor),
-            68 => Some(Self::Imp__Quantum_Reactor),
-            69 => Some(Self::Zero_point_Reactor),
-            70 => Some(Self::Vortex_Chain_Reactor),
-            71 => Some(Self::Singularity_Reactor),
-            72 => Some(Self::Matter_Drive),
-            73 => Some(Self::Meta_Core),
-            74 => Some(Self::SE_PO1),
-            75 => Some(Self::Lgt__Treads),
-            76 => Some(Self::Imp__Treads),
-            77 => Some(Self::Spk__Treads),
-            78 => Some(Self::Arm__Treads),
-            79 => Some(Self::Adv__Treads),
-            80 => Some(Self::Med__Treads),
-            81 => Some(Self::Imp__Medium_Treads),
-            82 => Some(Self::Spk__Medium_Treads),
-            83 => Some(Self::Arm__Medium_Treads),
-            84 => Some(Self::Hvy__Treads),
-            85 => Some(Self::Adv__Medium_Treads),
-            86 => Some(Self::Enh__Armored_Medium_Treads),
-            87 => Some(Self::Imp__Heavy_Treads),
-            88 => Some(Self::Hvy__Siege_Treads),
-            89 => Some(Self::Spk__Heavy_Treads),
-            90 => Some(Self::Biometal_Medium_Treads),
-            91 => Some(Self::Arm__Heavy_Treads),
-            92 => Some(Self::Adv__Heavy_Treads),
-            93 => Some(Self::Imp__Heavy_Siege_Treads),
-            94 => Some(Self::Centrium_Heavy_Treads),
-            95 => Some(Self::Megatreads),
-            96 => Some(Self::Biometal_Heavy_Treads),
-            97 => Some(Self::Exp__Biometal_Heavy_Treads),
-            98 => Some(Self::Enh__Armored_Heavy_Treads),
-            99 => Some(Self::Adv__Heavy_Siege_Treads),
-            100 => Some(Self::Hdn__Centrium_Heavy_Treads),
-            101 => Some(Self::Exp__Biometal_Medium_Treads),
-            102 => Some(Self::Aluminum_Leg),
-            103 => Some(Self::Imp__Aluminum_Leg),
-            104 => Some(Self::Carbon_fiber_Leg),
-            105 => Some(Self::Titanium_Leg),
-            106 => Some(Self::VSS_Leg),
-            107 => Some(Self::Imp__Carbon_fiber_Leg),
-            108 => Some(Self::Imp__Titanium_Leg),
-            109 => Some(Self::Lgt__Armored_Exoskeleton),
-            110 => Some(Self::Mak__Leg),
-            111 => Some(Self::Flexi_carbon_Leg),
-            112 => Some(Self::Arm__Leg),
-            113 => Some(Self::Imp__VSS_Leg),
-            114 => Some(Self::Zio__Composite_Leg_I),
-            115 => Some(Self::Med__Armored_Exoskeleton),
-            116 => Some(Self::Enh__Flexi_carbon_Leg),
-            117 => Some(Self::Enh__Armored_Leg),
-            118 => Some(Self::Arachnoskeleton),
-            119 => Some(Self::Myomer_Leg),
-            120 => Some(Self::Adv__VSS_Leg),
-            121 => Some(Self::Adv__Myomer_Leg),
-            122 => Some(Self::Exp__Flexi_carbon_Leg),
-            123 => Some(Self::Zio__Composite_Leg_II),
-            124 => Some(Self::Cargo_Legs),
-            125 => Some(Self::Etherial_Tendrils),
-            126 => Some(Self::Biometal_Leg),
-            127 => Some(Self::Hvy__Armored_Exoskeleton),
-            128 => Some(Self::Enh__Biometal_Leg),
-            129 => Some(Self::Exp__Myomer_Leg),
-            130 => Some(Self::Tripod_Weapons_Platform),
-            131 => Some(Self::T_R_O_L_L__Exoskeleton),
-            132 => Some(Self::Asb__Alloy_Leg),
-            133 => Some(Self::Centrium_Leg),
-            134 => Some(Self::Hdn__Centrium_Leg),
-            135 => Some(Self::Exp__Biometal_Leg),
-            136 => Some(Self::Zio__Supercomposite_Leg),
-            137 => Some(Self::Potential_Leg),
-            138 => Some(Self::Myomer_Exoskeleton),
-            139 => Some(Self::Sigix_Exoskeleton),
-            140 => Some(Self::SE_PR1),
-            141 => Some(Self::Wheel),
-            142 => Some(Self::Com__Wheel),
-            143 => Some(Self::Arm__Wheel),
-            144 => Some(Self::Sub__Monowheel),
-            145 => Some(Self::Arm__Huge_Wheel),
-            146 => Some(Self::Centrium_Wheel),
-            147 => Some(Self::Hdn__Centrium_Wheel),
-            148 => Some(Self::Hover_Unit),
-            149 => Some(Self::Backup_Propulsion_I),
-            150 => Some(Self::Imp__Hover_Unit),
-            151 => Some(Self::Airjet),
-            152 => Some(Self::Aerolev_Unit),
-            153 => Some(Self::Asb__Hover_Unit),
-            154 => Some(Self::Backup_Propulsion_III),
-            155 => Some(Self::Gravmag_System),
-            156 => Some(Self::Asb__Hover_System),
-            157 => Some(Self::Imp__Airjet),
-            158 => Some(Self::Cld__Airjet),
-            159 => Some(Self::Imp__Gravmag_System),
-            160 => Some(Self::Cld__Aerolev_Unit),
-            161 => Some(Self::Cmb__Airjet),
-            162 => Some(Self::Gravmag_Array),
-            163 => Some(Self::Mak__Hover_Unit),
-            164 => Some(Self::Zio__Glidesys_I),
-            165 => Some(Self::Antigrav_System),
-            166 => Some(Self::Gyrokinetic_Inverter),
-            167 => Some(Self::Linear_Gravjet),
-            168 => Some(Self::Cld__Gravmag_System),
-            169 => Some(Self::Cmb__Gravmag_System),
-            170 => Some(Self::Imp__Antigrav_System),
-            171 => Some(Self::Imp__Gyrokinetic_Inverter),
-            172 => Some(Self::Imp__Linear_Gravjet),
-            173 => Some(Self::Backup_Propulsion_VII),
-            174 => Some(Self::Zio__Glidesys_II),
-            175 => Some(Self::Cld__Antigrav_System),
-            176 => Some(Self::Cmb__Antigrav_System),
-            177 => Some(Self::Antigrav_Array),
-            178 => Some(Self::Exp__Gyrokinetic_Inverter),
-            179 => Some(Self::Cld__Linear_Gravjet),
-            180 => Some(Self::Cmb__Linear_Gravjet),
-            181 => Some(Self::Flight_Unit),
-            182 => Some(Self::VTOL_Module),
-            183 => Some(Self::Imp__Flight_Unit),
-            184 => Some(Self::Cesium_ion_Thruster),
-            185 => Some(Self::Surge_Thruster),
-            186 => Some(Self::Imp__VTOL_Module),
-            187 => Some(Self::Xenon_Bombardment_Thruster),
-            188 => Some(Self::Imp__Cesium_ion_Thruster),
-            189 => Some(Self::Cld__VTOL_Module),
-            190 => Some(Self::Mak__Flight_Unit),
-            191 => Some(Self::Nuclear_Pulse_Thruster),
-            192 => Some(Self::Imp__Surge_Thruster),
-            193 => Some(Self::Mni__Nuclear_Pulse_Thruster),
-            194 => Some(Self::Cld__Cesium_ion_Thruster),
-            195 => Some(Self::Biomechanical_Wings),
-            196 => Some(Self::Field_Propulsion_Drive),
-            197 => Some(Self::Cld__Nuclear_Pulse_Thruster),
-            198 => Some(Self::Exp__Cesium_ion_Thruster),
-            199 => Some(Self::Nuclear_Pulse_Array),
-            200 => Some(Self::Mak__Microthruster),
-            201 => Some(Self::Impulse_Thruster),
-            202 => Some(Self::Adv__Surge_Thruster),
-            203 => Some(Self::Cld__Field_Propulsion_Drive),
-            204 => Some(Self::Field_Propulsion_Array),
-            205 => Some(Self::Diametric_Drive),
-            206 => Some(Self::Flying_Fortress_v7),
-            207 => Some(Self::T_thruster),
-            208 => Some(Self::Integrated_Tracker_Drive),
-            209 => Some(Self::Etherial_Propulsion),
-            210 => Some(Self::Q_thruster),
-            211 => Some(Self::Electron_Diverter),
-            212 => Some(Self::Imp__Diametric_Drive),
-            213 => Some(Self::Cld__Impulse_Thruster),
-            214 => Some(Self::Impulse_Thruster_Array),
-            215 => Some(Self::Flightbrick),
-            216 => Some(Self::Imp__Q_thruster),
-            217 => Some(Self::Cld__Q_thruster),
-            218 => Some(Self::Exp__Q_thruster),
-            219 => Some(Self::Scrap_Engine),
-            220 => Some(Self::Arm__Scrap_Engine),
-            221 => Some(Self::Desublimator),
-            222 => Some(Self::Power_Amplifier),
-            223 => Some(Self::Adv__Power_Amplifier),
-            224 => Some(Self::Exp__Power_Amplifier),
-            225 => Some(Self::Thermal_Generator),
-            226 => Some(Self::Imp__Thermal_Generator),
-            227 => Some(Self::Adv__Thermal_Generator),
-            228 => Some(Self::Exp__Thermal_Generator),
-            229 => Some(Self::Cryofiber_Web),
-            230 => Some(Self::Imp__Cryofiber_Web),
-            231 => Some(Self::Adv__Cryofiber_Web),
-            232 => Some(Self::Exp__Cryofiber_Web),
-            233 => Some(Self::Heat_Sink),
-            234 => Some(Self::Imp__Heat_Sink),
-            235 => Some(Self::Adv__Heat_Sink),
-            236 => Some(Self::Exp__Heat_Sink),
-            237 => Some(Self::Cooling_System),
-            238 => Some(Self::Imp__Cooling_System),
-            239 => Some(Self::Adv__Cooling_System),
-            240 => Some(Self::Exp__Cooling_System),
-            241 => Some(Self::Coolant_Network),
-            242 => Some(Self::Asb__Nanovents),
-            243 => Some(Self::_2N_1CE_s_Frost_Array),
-            244 => Some(Self::Disposable_Heat_Sink),
-            245 => Some(Self::Coolant_Injector),
-            246 => Some(Self::Imp__Coolant_Injector),
-            247 => Some(Self::Adv__Coolant_Injector),
-            248 => Some(Self::Exp__Coolant_Injector),
-            249 => Some(Self::Sml__Storage_Unit),
-            250 => Some(Self::Med__Storage_Unit),
-            251 => Some(Self::Lrg__Storage_Unit),
-            252 => Some(Self::Hcp__Storage_Unit),
-            253 => Some(Self::Huge_Storage_Unit),
-            254 => Some(Self::Cargo_Storage_Unit),
-            255 => Some(Self::Humpback),
-            256 => Some(Self::Lightpack_2_0),
-            257 => Some(Self::Sml__Battery),
-            258 => Some(Self::Med__Battery),
-            259 => Some(Self::Lrg__Battery),
-            260 => Some(Self::Hcp__Battery),
-            261 => Some(Self::Com__Battery),
-            262 => Some(Self::Energy_Well),
-            263 => Some(Self::Imp__Energy_Well),
-            264 => Some(Self::Adv__Energy_Well),
-            265 => Some(Self::Exp__Energy_Well),
-            266 => Some(Self::Zio__Biocell),
-            267 => Some(Self::Asb__Biocell_Array),
-            268 => Some(Self::Zio__Biocell_Array),
-            269 => Some(Self::Superbattery),
-            270 => Some(Self::Sml__Matter_Pod),
-            271 => Some(Self::Med__Matter_Pod),
-            272 => Some(Self::Lrg__Matter_Pod),
-            273 => Some(Self::Hcp__Matter_Pod),
-            274 => Some(Self::Com__Matter_Pod),
-            275 => Some(Self::Matter_Compressor),
-            276 => Some(Self::Imp__Matter_Compressor),
-            277 => Some(Self::Adv__Matter_Compressor),
-            278 => Some(Self::Exp__Matter_Compressor),
-            279 => Some(Self::YI_UF0_s_Bottomless_Matter_Pit),
-            280 => Some(Self::Weight_Redist__System),
-            281 => Some(Self::Adv__Weight_Redist__System),
-            282 => Some(Self::Gravity_Neutralizer),
-            283 => Some(Self::Adv__Gravity_Neutralizer),
-            284 => Some(Self::Quantum_Shading_Machine),
-            285 => Some(Self::Asb__Suspension_Frame),
-            286 => Some(Self::Adv__Quantum_Shading_Machine),
-            287 => Some(Self::Asb__Metafiber_Network),
-            288 => Some(Self::Zio__Metafield_Generator),
-            289 => Some(Self::ST_Field_Compressor),
-            290 => Some(Self::Structural_Scanner),
-            291 => Some(Self::Trap_Scanner),
-            292 => Some(Self::Imp__Trap_Scanner),
-            293 => Some(Self::Mak__Trap_Scanner),
-            294 => Some(Self::Adv__Trap_Scanner),
-            295 => Some(Self::Machine_Analyzer),
-            296 => Some(Self::Triangulator),
-            297 => Some(Self::Visual_Processing_Unit),
-            298 => Some(Self::Sub__Optics),
-            299 => Some(Self::Adv__Visual_Processing_Unit),
-            300 => Some(Self::Enh__Optical_Array),
-            301 => Some(Self::Exp__Optical_Array),
-            302 => Some(Self::Spectral_Analyzer),
-            303 => Some(Self::Sensor_Array),
-            304 => Some(Self::Imp__Sensor_Array),
-            305 => Some(Self::Adv__Sensor_Array),
-            306 => Some(Self::Lrn__Sensor_Array),
-            307 => Some(Self::Mak__Sensor_Array),
-            308 => Some(Self::Exp__Sensor_Array),
-            309 => Some(Self::Signal_Interpreter),
-            310 => Some(Self::Imp__Signal_Interpreter),
-            311 => Some(Self::Adv__Signal_Interpreter),
-            312 => Some(Self::Exp__Signal_Interpreter),
-            313 => Some(Self::Active_Sensor_Suite),
-            314 => Some(Self::Terrain_Scanner),
-            315 => Some(Self::Imp__Terrain_Scanner),
-            316 => Some(Self::Adv__Terrain_Scanner),
-            317 => Some(Self::Mak__Terrain_Scanner),
-            318 => Some(Self::Lrn__Terrain_Scanner),
-            319 => Some(Self::Exp__Terrain_Scanner),
-            320 => Some(Self::Surveybot_24_Scanner),
-            321 => Some(Self::Terrain_Scan_Processor),
-            322 => Some(Self::Imp__Terrain_Scan_Processor),
-            323 => Some(Self::Mak__Terrain_Scan_Processor),
-            324 => Some(Self::Adv__Terrain_Scan_Processor),
-            325 => Some(Self::Exp__Terrain_Scan_Processor),
-            326 => Some(Self::Surveybot_24_Chip),
-            327 => Some(Self::Seismic_Detector),
-            328 => Some(Self::Transport_Network_Coupler),
-            329 => Some(Self::Modified_TNC),
-            330 => Some(Self::Encrypted_Comm_Array),
-            331 => Some(Self::_0b10_Decoder_Chip__Generic_),
-            332 => Some(Self::_0b10_Decoder_Chip__Looter_),
-            333 => Some(Self::_0b10_Decoder_Chip__Scout_),
-            334 => Some(Self::_0b10_Decoder_Chip__Skirmisher_),
-            335 => Some(Self::_0b10_Alert_Chip),
-            336 => Some(Self::Salvage_Targeting_Computer),
-            337 => Some(Self::Imp__Salvage_Targeting_Computer),
-            338 => Some(Self::Mak__Salvage_Targeting_Computer),
-            339 => Some(Self::Adv__Salvage_Targeting_Computer),
-            340 => Some(Self::Asb__Combat_Suite),
-            341 => Some(Self::Targeting_Computer),
-            342 => Some(Self::Imp__Targeting_Computer),
-            343 => Some(Self::Adv__Targeting_Computer),
-            344 => Some(Self::Exp__Targeting_Computer),
-            345 => Some(Self::Multitargeting_Array),
-            346 => Some(Self::Tactical_Coordination_Suite),
-            347 => Some(Self::Melee_Analysis_Suite),
-            348 => Some(Self::Imp__Melee_Analysis_Suite),
-            349 => Some(Self::Adv__Melee_Analysis_Suite),
-            350 => Some(Self::Exp__Melee_Analysis_Suite),
-            351 => Some(Self::Launcher_Guidance_Computer),
-            352 => Some(Self::Imp__Launcher_Guidance_Computer),
-            353 => Some(Self::Adv__Launcher_Guidance_Computer),
-            354 => Some(Self::Weapon_Mount),
-            355 => Some(Self::Target_Analyzer),
-            356 => Some(Self::Imp__Target_Analyzer),
-            357 => Some(Self::Adv__Target_Analyzer),
-            358 => Some(Self::Exp__Target_Analyzer),
-            359 => Some(Self::Core_Analyzer),
-            360 => Some(Self::Exp__Core_Analyzer),
-            361 => Some(Self::Armor_Integrity_Analyzer),
-            362 => Some(Self::Imp__Armor_Integrity_Analyzer),
-            363 => Some(Self::Exp__Armor_Integrity_Analyzer),
-            364 => Some(Self::Recoil_Stabilizer),
-            365 => Some(Self::Recoil_Nullifier),
-            366 => Some(Self::Matter_Filter),
-            367 => Some(Self::Prc__Matter_Filter),
-            368 => Some(Self::Energy_Filter),
-            369 => Some(Self::Prc__Energy_Filter),
-            370 => Some(Self::Particle_Charger),
-            371 => Some(Self::Imp__Particle_Charger),
-            372 => Some(Self::Adv__Particle_Charger),
-            373 => Some(Self::Particle_Accelerator),
-            374 => Some(Self::Imp__Particle_Accelerator),
-            375 => Some(Self::Adv__Particle_Accelerator),
-            376 => Some(Self::Kinecellerator),
-            377 => Some(Self::Imp__Kinecellerator),
-            378 => Some(Self::Adv__Kinecellerator),
-            379 => Some(Self::Heavy_Servo_Lattice),
-            380 => Some(Self::VL_GR5_s_Timing_Chip),
-            381 => Some(Self::Launcher_Loader),
-            382 => Some(Self::Quantum_Capacitor),
-            383 => Some(Self::Weapon_Cycler),
-            384 => Some(Self::Imp__Weapon_Cycler),
-            385 => Some(Self::Adv__Weapon_Cycler),
-            386 => Some(Self::Exp__Weapon_Cycler),
-            387 => Some(Self::Microactuators),
-            388 => Some(Self::Nanoactuators),
-            389 => Some(Self::Femtoactuators),
-            390 => Some(Self::Actuator_Array),
-            391 => Some(Self::Imp__Actuator_Array),
-            392 => Some(Self::Adv__Actuator_Array),
-            393 => Some(Self::Exp__Actuator_Array),
-            394 => Some(Self::Force_Booster),
-            395 => Some(Self::Imp__Force_Booster),
-            396 => Some(Self::Adv__Force_Booster),
-            397 => Some(Self::Stasis_Canceller),
-            398 => Some(Self::Transmission_Jammer),
-            399 => Some(Self::Mak__Transmission_Jammer),
-            400 => Some(Self::Imp__Transmission_Jammer),
-            401 => Some(Self::Adv__Transmission_Jammer),
-            402 => Some(Self::Exp__Transmission_Jammer),
-            403 => Some(Self::ECM_Suite),
-            404 => Some(Self::Mak__ECM_Suite),
-            405 => Some(Self::Adv__ECM_Suite),
-            406 => Some(Self::Exp__ECM_Suite),
-            407 => Some(Self::Active_Sensor_Spoofer),
-            408 => Some(Self::Reaction_Control_System),
-            409 => Some(Self::Imp__Reaction_Control_System),
-            410 => Some(Self::Adv__Reaction_Control_System),
-            411 => Some(Self::Exp__Reaction_Control_System),
-            412 => Some(Self::Phase_Shifter),
-            413 => Some(Self::Imp__Phase_Shifter),
-            414 => Some(Self::Adv__Phase_Shifter),
-            415 => Some(Self::Exp__Phase_Shifter),
-            416 => Some(Self::Cloaking_Device),
-            417 => Some(Self::Mak__Cloaking_Device),
-            418 => Some(Self::Imp__Cloaking_Device),
-            419 => Some(Self::Adv__Cloaking_Device),
-            420 => Some(Self::Exp__Cloaking_Device),
-            421 => Some(Self::Cloak_of_Protection),
-            422 => Some(Self::_5H_AD0_s_Cloak),
-            423 => Some(Self::Core_Shielding),
-            424 => Some(Self::Imp__Core_Shielding),
-            425 => Some(Self::Exp__Core_Shielding),
-            426 => Some(Self::Power_Shielding),
-            427 => Some(Self::Imp__Power_Shielding),
-            428 => Some(Self::Exp__Power_Shielding),
-            429 => Some(Self::Propulsion_Shielding),
-            430 => Some(Self::Imp__Propulsion_Shielding),
-            431 => Some(Self::Exp__Propulsion_Shielding),
-            432 => Some(Self::Utility_Shielding),
-            433 => Some(Self::Imp__Utility_Shielding),
-            434 => Some(Self::Exp__Utility_Shielding),
-            435 => Some(Self::Weapon_Shielding),
-            436 => Some(Self::Imp__Weapon_Shielding),
-            437 => Some(Self::Exp__Weapon_Shielding),
-            438 => Some(Self::Zio__Weapon_Casing),
-            439 => Some(Self::Backup_Plating_I),
-            440 => Some(Self::Backup_Plating_III),
-            441 => Some(Self::Backup_Plating_VII),
-            442 => Some(Self::Lgt__Armor_Plating),
-            443 => Some(Self::Med__Armor_Plating),
-            444 => Some(Self::Hvy__Armor_Plating),
-            445 => Some(Self::Zio__Light_Carapace),
-            446 => Some(Self::Zio__Heavy_Carapace),
-            447 => Some(Self::Hrd__Light_Armor_Plating),
-            448 => Some(Self::Hrd__Medium_Armor_Plating),
-            449 => Some(Self::Hrd__Heavy_Armor_Plating),
-            450 => Some(Self::Imp__Light_Armor_Plating),
-            451 => Some(Self::Imp__Medium_Armor_Plating),
-            452 => Some(Self::Imp__Heavy_Armor_Plating),
-            453 => Some(Self::Mak__Light_Armor_Plating),
-            454 => Some(Self::Lyr__Light_Armor_Plating),
-            455 => Some(Self::Lyr__Medium_Armor_Plating),
-            456 => Some(Self::Lyr__Heavy_Armor_Plating),
-            457 => Some(Self::Gun_Armor),
-            458 => Some(Self::Centrium_Light_Armor_Plating),
-            459 => Some(Self::Centrium_Medium_Armor_Plating),
-            460 => Some(Self::Centrium_Heavy_Armor_Plating),
-            461 => Some(Self::Centrium_Linked_Plating),
-            462 => Some(Self::Reactive_Plating),
-            463 => Some(Self::Med__Reactive_Plating),
-            464 => Some(Self::Mak__Kinetic_Plating),
-            465 => Some(Self::Hvy__Reactive_Plating),
-            466 => Some(Self::Reflective_Plating),
-            467 => Some(Self::Med__Reflective_Plating),
-            468 => Some(Self::Mak__Thermal_Plating),
-            469 => Some(Self::Hvy__Reflective_Plating),
-            470 => Some(Self::_8R_AWN_s_Armor_TH),
-            471 => Some(Self::_8R_AWN_s_Armor_EX),
-            472 => Some(Self::Insulated_Plating),
-            473 => Some(Self::Med__Insulated_Plating),
-            474 => Some(Self::Hvy__Insulated_Plating),
-            475 => Some(Self::Damper_Plating),
-            476 => Some(Self::Zio__Shade_Carapace),
-            477 => Some(Self::Zio__Shade_Armor),
-            478 => Some(Self::Asb__Alloy_Armor),
-            479 => Some(Self::Mak__Ablative_Armor),
-            480 => Some(Self::Active_Cooling_Armor),
-            481 => Some(Self::Scrap_Shield),
-            482 => Some(Self::Powered_Armor),
-            483 => Some(Self::Imp__Powered_Armor),
-            484 => Some(Self::Adv__Powered_Armor),
-            485 => Some(Self::Exp__Powered_Armor),
-            486 => Some(Self::_1C_UTU_s_Buckler),
-            487 => Some(Self::Lgt__Regenerative_Plating),
-            488 => Some(Self::Med__Regenerative_Plating),
-            489 => Some(Self::Hvy__Regenerative_Plating),
-            490 => Some(Self::SHELL_Armor),
-            491 => Some(Self::Phase_Armor),
-            492 => Some(Self::Graphene_Brace),
-            493 => Some(Self::Focal_Shield),
-            494 => Some(Self::Imp__Focal_Shield),
-            495 => Some(Self::Adv__Focal_Shield),
-            496 => Some(Self::Exp__Focal_Shield),
-            497 => Some(Self::Thermal_Shield),
-            498 => Some(Self::Imp__Thermal_Shield),
-            499 => Some(Self::Adv__Thermal_Shield),
-            500 => Some(Self::Exp__Thermal_Shield),
-            501 => Some(Self::Thermal_Barrier),
-            502 => Some(Self::Beam_Splitter),
-            503 => Some(Self::Thermal_Defense_Suite),
-            504 => Some(Self::Imp__Thermal_Defense_Suite),
-            505 => Some(Self::Adv__Thermal_Defense_Suite),
-            506 => Some(Self::Exp__Thermal_Defense_Suite),
-            507 => Some(Self::Shock_Absorption_System),
-            508 => Some(Self::Imp__Shock_Absorption_System),
-            509 => Some(Self::Exp__Shock_Absorption_System),
-            510 => Some(Self::EM_Disruption_Field),
-            511 => Some(Self::Adv__EM_Disruption_Field),
-            512 => Some(Self::Exp__EM_Disruption_Field),
-            513 => Some(Self::ME_RLN_s_Chromatic_Screen),
-            514 => Some(Self::Hardlight_Generator),
-            515 => Some(Self::Imp__Hardlight_Generator),
-            516 => Some(Self::Adv__Hardlight_Generator),
-            517 => Some(Self::Exp__Hardlight_Generator),
-            518 => Some(Self::Shield_Generator),
-            519 => Some(Self::Imp__Shield_Generator),
-            520 => Some(Self::Adv__Shield_Generator),
-            521 => Some(Self::Exp__Shield_Generator),
-            522 => Some(Self::Force_Field),
-            523 => Some(Self::Imp__Force_Field),
-            524 => Some(Self::Adv__Force_Field),
-            525 => Some(Self::Exp__Force_Field),
-            526 => Some(Self::_7V_RTL_s_Ultimate_Field),
-            527 => Some(Self::Vortex_Field_Projector),
-            528 => Some(Self::Remote_Shield),
-            529 => Some(Self::Imp__Remote_Shield),
-            530 => Some(Self::Adv__Remote_Shield),
-            531 => Some(Self::Remote_Force_Field),
-            532 => Some(Self::Imp__Remote_Force_Field),
-            533 => Some(Self::Energy_Mantle),
-            534 => Some(Self::Imp__Energy_Mantle),
-            535 => Some(Self::AEGIS_Remote_Shield),
-            536 => Some(Self::Phase_Redirector),
-            537 => Some(Self::Point_Defense_System),
-            538 => Some(Self::Point_Defense_Array),
-            539 => Some(Self::Antimissile_System),
-            540 => Some(Self::EX_Chip_1),
-            541 => Some(Self::Hacking_Suite),
-            542 => Some(Self::Mak__Hacking_Suite),
-            543 => Some(Self::Imp__Hacking_Suite),
-            544 => Some(Self::Adv__Hacking_Suite),
-            545 => Some(Self::Exp__Hacking_Suite),
-            546 => Some(Self::System_Mapper),
-            547 => Some(Self::Deep_Network_Scanner),
-            548 => Some(Self::Architect_God_Chip_A),
-            549 => Some(Self::EX_Chip_2),
-            550 => Some(Self::System_Shield),
-            551 => Some(Self::Mak__System_Shield),
-            552 => Some(Self::Imp__System_Shield),
-            553 => Some(Self::Adv__System_Shield),
-            554 => Some(Self::Exp__System_Shield),
-            555 => Some(Self::Ghost_Barrier),
-            556 => Some(Self::Quantum_Router),
-            557 => Some(Self::Architect_God_Chip_D),
-            558 => Some(Self::Authchip__R_NC_),
-            559 => Some(Self::Authchip__R_Combat_),
-            560 => Some(Self::Authchip__Power_),
-            561 => Some(Self::Authchip__Propulsion_),
-            562 => Some(Self::Authchip__Device_),
-            563 => Some(Self::Authchip__Storage_),
-            564 => Some(Self::Authchip__Processor_),
-            565 => Some(Self::Authchip__Armor_),
-            566 => Some(Self::Authchip__Weapon_),
-            567 => Some(Self::Relay_Coupler__NC_),
-            568 => Some(Self::Relay_Coupler__C_),
-            569 => Some(Self::Relay_Coupler__Swarmer_),
-            570 => Some(Self::Relay_Coupler__Cutter_),
-            571 => Some(Self::Relay_Coupler__Grunt_),
-            572 => Some(Self::Relay_Coupler__Brawler_),
-            573 => Some(Self::Relay_Coupler__Duelist_),
-            574 => Some(Self::Relay_Coupler__Sentry_),
-            575 => Some(Self::Relay_Coupler__Demolisher_),
-            576 => Some(Self::Relay_Coupler__Specialist_),
-            577 => Some(Self::Relay_Coupler__Hunter_),
-            578 => Some(Self::Relay_Coupler__Heavy_),
-            579 => Some(Self::Relay_Coupler__Behemoth_),
-            580 => Some(Self::Relay_Coupler__Programmer_),
-            581 => Some(Self::Relay_Coupler__Proto_),
-            582 => Some(Self::Skeleton_Box),
-            583 => Some(Self::_01_MTF_s_Autohacker),
-            584 => Some(Self::Component_Analysis_Suite),
-            585 => Some(Self::Imp__Component_Analysis_Suite),
-            586 => Some(Self::Adv__Component_Analysis_Suite),
-            587 => Some(Self::Dynamic_Insulation_System),
-            588 => Some(Self::Imp__Dynamic_Insulation_System),
-            589 => Some(Self::Adv__Dynamic_Insulation_System),
-            590 => Some(Self::System_Guard),
-            591 => Some(Self::Imp__System_Guard),
-            592 => Some(Self::Exp__System_Guard),
-            593 => Some(Self::Corruption_Screen),
-            594 => Some(Self::Imp__Corruption_Screen),
-            595 => Some(Self::Adv__Corruption_Screen),
-            596 => Some(Self::System_Restoration_Module),
-            597 => Some(Self::Mak__System_Restoration_Module),
-            598 => Some(Self::Imp__System_Restoration_Module),
-            599 => Some(Self::Adv__System_Restoration_Module),
-            600 => Some(Self::System_Purifier),
-            601 => Some(Self::Exp__System_Purifier),
-            602 => Some(Self::Tractor_Beam),
-            603 => Some(Self::Hpw__Tractor_Beam),
-            604 => Some(Self::Lrn__Tractor_Beam),
-            605 => Some(Self::Mobile_Refinery_Mk__CCLXVII),
-            606 => Some(Self::Trap_Extractor),
-            607 => Some(Self::Imp__Trap_Extractor),
-            608 => Some(Self::Adv__Trap_Extractor),
-            609 => Some(Self::Trap_Reconfigurator),
-            610 => Some(Self::Recalibrator),
-            611 => Some(Self::Imp__Recalibrator),
-            612 => Some(Self::Adv__Recalibrator),
-            613 => Some(Self::Field_Recycling_Unit),
-            614 => Some(Self::Imp__Field_Recycling_Unit),
-            615 => Some(Self::Adv__Field_Recycling_Unit),
-            616 => Some(Self::Exp__Field_Recycling_Unit),
-            617 => Some(Self::Drone_Bay),
-            618 => Some(Self::Z_Drone_Bay),
-            619 => Some(Self::Mni__Drone_Bay),
-            620 => Some(Self::Mapping_Drone_Bay),
-            621 => Some(Self::Adv__Drone_Bay),
-            622 => Some(Self::Bomb_Drone_Bay),
-            623 => Some(Self::Thief_Drone_Bay),
-            624 => Some(Self::Decoy_Drone_Bay),
-            625 => Some(Self::Splice_Drone_Bay),
-            626 => Some(Self::Combat_Drone_Bay),
-            627 => Some(Self::Assault_Drone_Bay),
-            628 => Some(Self::Stealth_Drone_Bay),
-            629 => Some(Self::Swarm_Drone_Bay),
-            630 => Some(Self::Supersonic_Drone_Bay),
-            631 => Some(Self::Sensor_Drone_Bay),
-            632 => Some(Self::Hacking_Drone_Bay),
-            633 => Some(Self::Minesniffer_Drone_Bay),
-            634 => Some(Self::Army_in_a_Box),
-            635 => Some(Self::Wardrone_Bay),
-            636 => Some(Self::Cobbler_Unit),
-            637 => Some(Self::Bomb_Factory_No__2),
-            638 => Some(Self::Mobile_Assembly_Unit),
-            639 => Some(Self::Heart_of_Steel),
-            640 => Some(Self::GOLEM_Unit),
-            641 => Some(Self::PSU_Rigger),
-            642 => Some(Self::Phasing_AFG),
-            643 => Some(Self::AS_neutralizer_No__17b),
-            644 => Some(Self::V3_11A_s_Pendant),
-            645 => Some(Self::Chronowheel),
-            646 => Some(Self::Power_Bank),
-            647 => Some(Self::Rainbow_Chip),
-            648 => Some(Self::VL_GR5_s_Exoskeleton__Deathgrip_),
-            649 => Some(Self::Dimensional_Node_Initializer),
-            650 => Some(Self::Transdimensional_Reconstructor),
-            651 => Some(Self::Hpw__Transdimensional_Reconstructor),
-            652 => Some(Self::Supercharged_TR),
-            653 => Some(Self::Core_Expander),
-            654 => Some(Self::Core_Regenerator),
-            655 => Some(Self::Integrity_Redistributor),
-            656 => Some(Self::Integrated_Dissipator),
-            657 => Some(Self::Supercharged_Integrated_Dissipator),
-            658 => Some(Self::Integrated_Thermoelectric_Network),
-            659 => Some(Self::Integrated_Reactor),
-            660 => Some(Self::Supercharged_Integrated_Reactor),
-            661 => Some(Self::Integrated_Mediator),
-            662 => Some(Self::Core_Membrane),
-            663 => Some(Self::Navigation_Efficiency_Matrix),
-            664 => Some(Self::Core_Reset_Matrix),
-            665 => Some(Self::Subatomic_Replicator),
-            666 => Some(Self::Terrabomb),
-            667 => Some(Self::Core_Physics_Calibrator),
-            668 => Some(Self::Spacefold_Activator),
-            669 => Some(Self::Microwarp_Drive),
-            670 => Some(Self::LC_Capacitor),
-            671 => Some(Self::LRC_Armor),
-            672 => Some(Self::LRC_Storage),
-            673 => Some(Self::LRC_Energy_Well),
-            674 => Some(Self::LRC_Matter_Compressor),
-            675 => Some(Self::LRC_Inertial_Stabilizer),
-            676 => Some(Self::LRC_Insulator),
-            677 => Some(Self::EM_Pulse_Gun),
-            678 => Some(Self::Hvy__EM_Pulse_Gun),
-            679 => Some(Self::EM_Shotgun),
-            680 => Some(Self::Imp__EM_Shotgun),
-            681 => Some(Self::Shock_Rifle),
-            682 => Some(Self::Riot_Gun),
-            683 => Some(Self::Hvy__EM_Shotgun),
-            684 => Some(Self::Lightning_Gun),
-            685 => Some(Self::Hvy__Riot_Gun),
-            686 => Some(Self::Arc_Projector),
-            687 => Some(Self::Prc__Shock_Rifle),
-            688 => Some(Self::Hpw__Shock_Rifle),
-            689 => Some(Self::Arc_Thrower),
-            690 => Some(Self::Imp__Lightning_Gun),
-            691 => Some(Self::Tesla_Rifle),
-            692 => Some(Self::Imp__Arc_Projector),
-            693 => Some(Self::Hvy__Tesla_Rifle),
-            694 => Some(Self::Hyp__EM_Gauss_Rifle),
-            695 => Some(Self::Imp__Arc_Thrower),
-            696 => Some(Self::_01_MTF_s_Shockpuncher),
-            697 => Some(Self::Gamma_Rifle),
-            698 => Some(Self::Tachyon_Dispersion_Ray),
-            699 => Some(Self::Immobilizer),
-            700 => Some(Self::Modified_EM_Gauss_Rifle),
-            701 => Some(Self::AWS_EX_DEC),
-            702 => Some(Self::Enh__Gamma_Rifle),
-            703 => Some(Self::Med__Laser),
-            704 => Some(Self::Sml__Laser),
-            705 => Some(Self::Backup_Laser_I),
-            706 => Some(Self::Beam_Rifle),
-            707 => Some(Self::Particle_Gun),
-            708 => Some(Self::Spread_Laser),
-            709 => Some(Self::Imp__Medium_Laser),
-            710 => Some(Self::Sub__Laser),
-            711 => Some(Self::Autobeam),
-            712 => Some(Self::Field_Laser),
-            713 => Some(Self::Pulse_Rifle),
-            714 => Some(Self::Backup_Laser_III),
-            715 => Some(Self::Hvy__Particle_Gun),
-            716 => Some(Self::Prc__Beam_Rifle),
-            717 => Some(Self::Adv__Beam_Rifle),
-            718 => Some(Self::Hvy__Laser),
-            719 => Some(Self::Gatling_Laser),
-            720 => Some(Self::Cld__Pulse_Rifle),
-            721 => Some(Self::Hpw__Field_Laser),
-            722 => Some(Self::Imp__Spread_Laser),
-            723 => Some(Self::Mak__Laser),
-            724 => Some(Self::Plasma_Rifle),
-            725 => Some(Self::Thermic_Laser),
-            726 => Some(Self::Beamcaster),
-            727 => Some(Self::Force_Rifle),
-            728 => Some(Self::Imp__Heavy_Laser),
-            729 => Some(Self::Storm_Laser),
-            730 => Some(Self::Zio__Laser_S),
-            731 => Some(Self::Wave_Gun),
-            732 => Some(Self::Variable_Charge_Gun),
-            733 => Some(Self::Hvy__Beamcaster),
-            734 => Some(Self::Cld__Plasma_Rifle),
-            735 => Some(Self::Enh__Force_Rifle),
-            736 => Some(Self::Hvy__Gatling_Laser),
-            737 => Some(Self::Zio__Phaser_S),
-            738 => Some(Self::Phase_Gun),
-            739 => Some(Self::Dispersion_Rifle),
-            740 => Some(Self::Backup_Laser_VII),
-            741 => Some(Self::Plasma_Storm),
-            742 => Some(Self::Adv__Plasma_Rifle),
-            743 => Some(Self::Hvy__Wave_Gun),
-            744 => Some(Self::Adv__Variable_Charge_Gun),
-            745 => Some(Self::Cld__Phase_Gun),
-            746 => Some(Self::Lrn__Gatling_Laser),
-            747 => Some(Self::Warp_Gun),
-            748 => Some(Self::Vortex_Rifle),
-            749 => Some(Self::Zio__Laser_M),
-            750 => Some(Self::AWS_Thermal),
-            751 => Some(Self::PD_Laser),
-            752 => Some(Self::Quantum_Rifle),
-            753 => Some(Self::Prc__Phase_Gun),
-            754 => Some(Self::Hvy__Dispersion_Rifle),
-            755 => Some(Self::Vortex_Shotgun),
-            756 => Some(Self::Vortex_Rail),
-            757 => Some(Self::Zio__Phaser_M),
-            758 => Some(Self::Enh__Quantum_Rifle),
-            759 => Some(Self::Zio__Laser_H),
-            760 => Some(Self::Hvy__Quantum_Rifle),
-            761 => Some(Self::Zio__Phaser_H),
-            762 => Some(Self::Gatling_Beam),
-            763 => Some(Self::Sigix_Sheargun),
-            764 => Some(Self::Modified_Sigix_Sheargun),
-            765 => Some(Self::Lgt__EM_Pulse_Cannon),
-            766 => Some(Self::EM_Pulse_Cannon),
-            767 => Some(Self::Imp__EM_Pulse_Cannon),
-            768 => Some(Self::Proton_Cannon),
-            769 => Some(Self::Lrn__Proton_Cannon),
-            770 => Some(Self::Disruptor_Cannon),
-            771 => Some(Self::Hvy__Proton_Cannon),
-            772 => Some(Self::HERF_Cannon),
-            773 => Some(Self::Hvy__Disruptor_Cannon),
-            774 => Some(Self::Cld__Proton_Cannon),
-            775 => Some(Self::Proton_Burst_Cannon),
-            776 => Some(Self::Com__HERF_Cannon),
-            777 => Some(Self::Latent_Energy_Streamer),
-            778 => Some(Self::NK_0LA_s_Tesla_Doomcannon),
-            779 => Some(Self::EMDS),
-            780 => Some(Self::Hpw__Disruptor_Cannon),
-            781 => Some(Self::Beam_Cannon),
-            782 => Some(Self::Particle_Cannon),
-            783 => Some(Self::Adv__Beam_Cannon),
-            784 => Some(Self::Sub__Beam_Cannon),
-            785 => Some(Self::Hvy__Particle_Cannon),
-            786 => Some(Self::Ion_Cannon),
-            787 => Some(Self::Plasma_Cannon),
-            788 => Some(Self::Hvy__Ion_Cannon),
-            789 => Some(Self::Cld__Particle_Cannon),
-            790 => Some(Self::Phase_Cannon),
-            791 => Some(Self::Hvy__Plasma_Cannon),
-            792 => Some(Self::Cld__Plasma_Cannon),
-            793 => Some(Self::Com__Ion_Cannon),
-            794 => Some(Self::Ion_Burst_Cannon),
-            795 => Some(Self::Adv__Plasma_Cannon),
-            796 => Some(Self::Hvy__Phase_Cannon),
-            797 => Some(Self::Neutron_Cannon),
-            798 => Some(Self::Powered_Cannon),
-            799 => Some(Self::Matter_Neutralizer),
-            800 => Some(Self::Hvy__Neutron_Cannon),
-            801 => Some(Self::Lrn__Phase_Cannon),
-            802 => Some(Self::Cld__Phase_Cannon),
-            803 => Some(Self::Vortex_Cannon),
-            804 => Some(Self::Gui__Plasma_Cannon),
-            805 => Some(Self::Nova_Cannon),
-            806 => Some(Self::Imp__Matter_Neutralizer),
-            807 => Some(Self::Exp__Thermic_Cannon),
-            808 => Some(Self::Vortex_Driver),
-            809 => Some(Self::Zio__Alpha_Cannon),
-            810 => Some(Self::Asb__Focal_Cannon),
-            811 => Some(Self::Enh__Nova_Cannon),
-            812 => Some(Self::Firepult),
-            813 => Some(Self::YOLO_Cannon),
-            814 => Some(Self::Potential_Cannon),
-            815 => Some(Self::Warp_Cannon),
-            816 => Some(Self::Cld__Nova_Cannon),
-            817 => Some(Self::Null_Cannon),
-            818 => Some(Self::Imp__Potential_Cannon),
-            819 => Some(Self::Multinova_Projection_Cannon),
-            820 => Some(Self::Disintegrator),
-            821 => Some(Self::Core_Cannon),
-            822 => Some(Self::Sigix_Shearcannon),
-            823 => Some(Self::Drained_L_Cannon),
-            824 => Some(Self::L_Cannon),
-            825 => Some(Self::Zio__Alpha_Cannon_Mk__II),
-            826 => Some(Self::BFG_9k_Vortex_Edition),
-            827 => Some(Self::Assault_Rifle),
-            828 => Some(Self::Asb__Rifle),
-            829 => Some(Self::Lgt__Assault_Rifle),
-            830 => Some(Self::Hvy__Assault_Rifle),
-            831 => Some(Self::Autogun),
-            832 => Some(Self::Shotgun),
-            833 => Some(Self::Battle_Rifle),
-            834 => Some(Self::Asb__Heavy_Rifle),
-            835 => Some(Self::Sub__Machine_Gun),
-            836 => Some(Self::Imp__Assault_Rifle),
-            837 => Some(Self::Minigun),
-            838 => Some(Self::Hpw__Shotgun),
-            839 => Some(Self::Asb__Shotgun),
-            840 => Some(Self::Barrage_Gun),
-            841 => Some(Self::Enh__Autogun),
-            842 => Some(Self::Gauss_Rifle),
-            843 => Some(Self::Asb__Gauss_Rifle),
-            844 => Some(Self::Flak_Gun),
-            845 => Some(Self::Prc__Assault_Rifle),
-            846 => Some(Self::Hvy__Battle_Rifle),
-            847 => Some(Self::Mak__Shrapnel_Gun),
-            848 => Some(Self::Coil_Gun),
-            849 => Some(Self::Hyp__Gauss_Rifle),
-            850 => Some(Self::Hvy__Machine_Gun),
-            851 => Some(Self::Imp__Heavy_Machine_Gun),
-            852 => Some(Self::Com__Gauss_Rifle),
-            853 => Some(Self::XL_Autogun_Subcomponent),
-            854 => Some(Self::KE_Penetrator),
-            855 => Some(Self::Hyp__Coil_Gun),
-            856 => Some(Self::Imp__KE_Penetrator),
-            857 => Some(Self::Enh__Coil_Gun),
-            858 => Some(Self::Com__Coil_Gun),
-            859 => Some(Self::Railgun),
-            860 => Some(Self::Adv__KE_Penetrator),
-            861 => Some(Self::Linked_Autogun),
-            862 => Some(Self::AWS_Gauss),
-            863 => Some(Self::Hyp__Railgun),
-            864 => Some(Self::Helical_Railgun),
-            865 => Some(Self::Com__Railgun),
-            866 => Some(Self::XL_Autogun_Array),
-            867 => Some(Self::_12_ASH_s_Boomstick),
-            868 => Some(Self::_8R_AWN_s_Boregun),
-            869 => Some(Self::Multirail),
-            870 => Some(Self::Hyp__Multirail),
-            871 => Some(Self::_5H_AD0_s_Sniper_Rifle),
-            872 => Some(Self::A3_s_Sniper_Rifle),
-            873 => Some(Self::Lgt__Cannon),
-            874 => Some(Self::Imp__Light_Cannon),
-            875 => Some(Self::Battle_Cannon),
-            876 => Some(Self::Assault_Cannon),
-            877 => Some(Self::Hvy__Assault_Cannon),
-            878 => Some(Self::Flak_Cannon),
-            879 => Some(Self::Adv__Assault_Cannon),
-            880 => Some(Self::Gauss_Cannon),
-            881 => Some(Self::Slug_Cannon),
-            882 => Some(Self::Mni__Assault_Cannon),
-            883 => Some(Self::Magnetic_Acceleration_Cannon),
-            884 => Some(Self::Hvy__Flak_Cannon),
-            885 => Some(Self::Bore_Cannon),
-            886 => Some(Self::Antimatter_Cannon),
-            887 => Some(Self::Mass_Driver),
-            888 => Some(Self::Hvy__Mass_Driver),
-            889 => Some(Self::Enh__Gauss_Cannon),
-            890 => Some(Self::Hyp__Gauss_Cannon),
-            891 => Some(Self::Tri_rail_Accelerator),
-            892 => Some(Self::Hardcell_Cannon),
-            893 => Some(Self::Hvy__Gauss_Cannon),
-            894 => Some(Self::Hyp__Mass_Driver),
-            895 => Some(Self::Hvy__Autocannon),
-            896 => Some(Self::DAS_Cannon),
-            897 => Some(Self::Lgt__Antimatter_Cannon),
-            898 => Some(Self::Linear_Accelerator),
-            899 => Some(Self::Hvy__Linear_Accelerator),
-            900 => Some(Self::Com__Mass_Driver),
-            901 => Some(Self::Hvy__Hardcell_Cannon),
-            902 => Some(Self::Com__Linear_Accelerator),
-            903 => Some(Self::Hyp__Linear_Accelerator),
-            904 => Some(Self::Hcl__Linear_Accelerator),
-            905 => Some(Self::Graviton_Cannon),
-            906 => Some(Self::Blast_Cannon),
-            907 => Some(Self::P1_3CE_s_Gatling_Flakker),
-            908 => Some(Self::AWS_Autocannon),
-            909 => Some(Self::Perforator),
-            910 => Some(Self::Omega_Cannon),
-            911 => Some(Self::EMP_Blaster),
-            912 => Some(Self::Shock_Bomb_Launcher),
-            913 => Some(Self::Adv__EMP_Blaster),
-            914 => Some(Self::Proton_Missile_Launcher),
-            915 => Some(Self::Imp__Proton_Missile_Launcher),
-            916 => Some(Self::Gui__EMP_Blaster),
-            917 => Some(Self::Hvy__Shock_Bomb_Launcher),
-            918 => Some(Self::Tesla_Bomb_Launcher),
-            919 => Some(Self::Hvy__Proton_Missile_Launcher),
-            920 => Some(Self::Com__Tesla_Bomb_Launcher),
-            921 => Some(Self::Gamma_Bomb_Array),
-            922 => Some(Self::Enh__Gamma_Bomb_Array),
-            923 => Some(Self::Grenade_Launcher),
-            924 => Some(Self::Rocket_Launcher),
-            925 => Some(Self::Mni__Grenade_Launcher),
-            926 => Some(Self::Sub__Shrapnel_Launcher),
-            927 => Some(Self::Imp__Grenade_Launcher),
-            928 => Some(Self::Missile_Launcher),
-            929 => Some(Self::Concussive_RPG),
-            930 => Some(Self::YI_UF0_s_Doublenader),
-            931 => Some(Self::Hvy__Rocket_Launcher),
-            932 => Some(Self::Hvy__Missile_Launcher),
-            933 => Some(Self::Gui__Missile_Launcher),
-            934 => Some(Self::Prc__Grenade_Launcher),
-            935 => Some(Self::Rocket_Array),
-            936 => Some(Self::Lrn__Missile_Launcher),
-            937 => Some(Self::Imp__Concussive_RPG),
-            938 => Some(Self::Prc__Rocket_Launcher),
-            939 => Some(Self::Scatter_Rocket_Array),
-            940 => Some(Self::Smartbomb_Launcher),
-            941 => Some(Self::Mni__Smartbomb_Launcher),
-            942 => Some(Self::Tiamat_Missile_Launcher),
-            943 => Some(Self::Thermoblaster),
-            944 => Some(Self::Micro_nuke_Launcher),
-            945 => Some(Self::Adv__Concussive_RPG),
-            946 => Some(Self::Neutron_Missile_Launcher),
-            947 => Some(Self::Fusion_Bomb_Launcher),
-            948 => Some(Self::Hellfire_Missile_Launcher),
-            949 => Some(Self::Adv__Micro_nuke_Launcher),
-            950 => Some(Self::Tactical_Quantum_Warhead),
-            951 => Some(Self::Vortex_Catalyst_Activator),
-            952 => Some(Self::Chain_Missile_Launcher),
-            953 => Some(Self::Ragnarok_Missile_Launcher),
-            954 => Some(Self::Point_Singularity_Launcher),
-            955 => Some(Self::Gui__Micro_nuke_Launcher),
-            956 => Some(Self::_99_TNT_s_Nukerbomber),
-            957 => Some(Self::Z_bomb_Delivery_System),
-            958 => Some(Self::Sigix_Terminator),
-            959 => Some(Self::Supercharged_Sigix_Terminator),
-            960 => Some(Self::Containment_Facilitator),
-            961 => Some(Self::Detonator),
-            962 => Some(Self::Dirty_Datajack),
-            963 => Some(Self::Splice_Injector),
-            964 => Some(Self::Master_Link),
-            965 => Some(Self::Datajack),
-            966 => Some(Self::Imp__Datajack),
-            967 => Some(Self::Adv__Datajack),
-            968 => Some(Self::Remote_Datajack),
-            969 => Some(Self::Imp__Remote_Datajack),
-            970 => Some(Self::Adv__Remote_Datajack),
-            971 => Some(Self::Exp__Remote_Datajack),
-            972 => Some(Self::Gui__Remote_Datajack),
-            973 => Some(Self::ME_RLN_s_Wand),
-            974 => Some(Self::Field_Lobotomy_Kit),
-            975 => Some(Self::Mining_Laser),
-            976 => Some(Self::Welding_Torch),
-            977 => Some(Self::Plasma_Cutter),
-            978 => Some(Self::Imp__Plasma_Cutter),
-            979 => Some(Self::Exp__Plasma_Cutter),
-            980 => Some(Self::CPS_Tube),
-            981 => Some(Self::Multitool),
-            982 => Some(Self::Flamer),
-            983 => Some(Self::Asb__F_torch),
-            984 => Some(Self::Enh__Flamer),
-            985 => Some(Self::Plasma_Flamer),
-            986 => Some(Self::Asb__P_torch),
-            987 => Some(Self::RU_N14_s_Throwing_Claymores),
-            988 => Some(Self::RU_N14_s_Throwing_Claymores_v3),
-            989 => Some(Self::AD_0RF_s_Magmablaster),
-            990 => Some(Self::Stasis_Beam),
-            991 => Some(Self::Stasis_Projector),
-            992 => Some(Self::Exp__Stasis_Projector),
-            993 => Some(Self::Molecular_Deconstructor),
-            994 => Some(Self::SE_WE1),
-            995 => Some(Self::SE_WE2),
-            996 => Some(Self::SE_WE3),
-            997 => Some(Self::SE_WE4),
-            998 => Some(Self::SE_WE5),
-            999 => Some(Self::Hammer),
-            1000 => Some(Self::Mace),
-            1001 => Some(Self::Flail),
-            1002 => Some(Self::Maul),
-            1003 => Some(Self::Asb__Maul),
-            1004 => Some(Self::Hvy__Hammer),
-            1005 => Some(Self::Hvy__Mace),
-            1006 => Some(Self::Hvy__Flail),
-            1007 => Some(Self::Great_Maul),
-            1008 => Some(Self::Powered_Hammer),
-            1009 => Some(Self::Mak__Hammer),
-            1010 => Some(Self::Power_Maul),
-            1011 => Some(Self::Asb__P_maul),
-            1012 => Some(Self::Impact_Mace),
-            1013 => Some(Self::Shock_Maul),
-            1014 => Some(Self::Thunder_Hammer),
-            1015 => Some(Self::Compactor),
-            1016 => Some(Self::Gravity_Flail),
-            1017 => Some(Self::CL_ANK_s_Mallet),
-            1018 => Some(Self::Core_Stripper),
-            1019 => Some(Self::Mining_Claw),
-            1020 => Some(Self::EC_1),
-            1021 => Some(Self::Katana),
-            1022 => Some(Self::Asb__Blade),
-            1023 => Some(Self::Scythe),
-            1024 => Some(Self::Axe),
-            1025 => Some(Self::Blade_Saw),
-            1026 => Some(Self::EC_3),
-            1027 => Some(Self::Chainsword),
-            1028 => Some(Self::Ripper),
-            1029 => Some(Self::Mak__Axe),
-            1030 => Some(Self::Mak__Sword),
-            1031 => Some(Self::Great_Axe),
-            1032 => Some(Self::Greatsword),
-            1033 => Some(Self::Power_Sword),
-            1034 => Some(Self::Asb__P_sword),
-            1035 => Some(Self::Falx),
-            1036 => Some(Self::Carbide_Saw),
-            1037 => Some(Self::Segregator),
-            1038 => Some(Self::Tearclaws),
-            1039 => Some(Self::EC_5),
-            1040 => Some(Self::Phasing_Sabre),
-            1041 => Some(Self::Plasma_Sword),
-            1042 => Some(Self::Ionic_Axe),
-            1043 => Some(Self::Mni__Tearclaws),
-            1044 => Some(Self::Dual_blade_Saw),
-            1045 => Some(Self::Vibroblade),
-            1046 => Some(Self::Molecular_Scythe),
-            1047 => Some(Self::Centrium_Greatsword),
-            1048 => Some(Self::Master_Tearclaws),
-            1049 => Some(Self::Longsword__1),
-            1050 => Some(Self::EC_7),
-            1051 => Some(Self::Nanosword),
-            1052 => Some(Self::Particle_Cleaver),
-            1053 => Some(Self::Centrium_Claws),
-            1054 => Some(Self::_1C_UTU_s_Sword__Lootmaker_),
-            1055 => Some(Self::Vortex_Shredder),
-            1056 => Some(Self::EC_9),
-            1057 => Some(Self::_1C_UTU_s_Sword__Choppy_),
-            1058 => Some(Self::Quantum_Blade),
-            1059 => Some(Self::Sigix_Broadsword),
-            1060 => Some(Self::Spear),
-            1061 => Some(Self::Mak__Spear),
-            1062 => Some(Self::CR_A16_s_Pointy_Stick),
-            1063 => Some(Self::Hvy__Pick),
-            1064 => Some(Self::Lance),
-            1065 => Some(Self::Kinetic_Spear),
-            1066 => Some(Self::Force_Lance),
-            1067 => Some(Self::Plasma_Lance),
-            1068 => Some(Self::Enh__Force_Lance),
-            1069 => Some(Self::Vortex_Lance),
-            1070 => Some(Self::CR_A16_s_Behemoth_Slayer),
-            1071 => Some(Self::Blade_Trap),
-            1072 => Some(Self::Segregator_Trap),
-            1073 => Some(Self::Explosive_Trap),
-            1074 => Some(Self::Heavy_Explosive_Trap),
-            1075 => Some(Self::Tiamat_Bomb_Trap),
-            1076 => Some(Self::Fusion_Bomb_Trap),
-            1077 => Some(Self::Hellfire_Trap),
-            1078 => Some(Self::Armageddon_Trap),
-            1079 => Some(Self::Dirty_Bomb_Trap),
-            1080 => Some(Self::EMP_Trap),
-            1081 => Some(Self::Proton_Bomb_Trap),
-            1082 => Some(Self::Tesla_Bomb_Trap),
-            1083 => Some(Self::Gamma_Bomb_Trap),
-            1084 => Some(Self::Shrapnel_Trap),
-            1085 => Some(Self::Piercing_Trap),
-            1086 => Some(Self::Shock_Trap),
-            1087 => Some(Self::EM_Surge_Trap),
-            1088 => Some(Self::Fire_Trap),
-            1089 => Some(Self::Stasis_Trap),
-            1090 => Some(Self::Scrap_Shield_Fragment),
-            1091 => Some(Self::Signal_Generator),
-            1092 => Some(Self::Self_destruct_Interrupter),
-            1093 => Some(Self::Sapper_Charge),
-            1094 => Some(Self::Zhirov_Special),
-            1095 => Some(Self::Deploy_a_Sentry),
-            1096 => Some(Self::GAL_Module),
-            1097 => Some(Self::Portable_AFG),
-            1098 => Some(Self::SGEMP_Prototype),
-            1099 => Some(Self::Terrabomb_Derivative),
-            1100 => Some(Self::Sigix_Containment_Pod),
-            1101 => Some(Self::Sigix_Corpse),
-            1102 => Some(Self::Meganuke),
-            1103 => Some(Self::Gamma_Refractor),
-            _ => None,
-        }
-    }
-
-    pub fn id(&self) -> i32 {
-        match self {
-            Self::Matter => 0,
-            Self::Data_Core => 1,
-            Self::Derelict_Log => 2,
-            Self::Schematic_Archive => 3,
-            Self::Imprinter_Data_Core => 4,
-            Self::A2_Data_Core => 5,
-            Self::A7_Data_Core => 6,
-            Self::MAIN_C_Data_Core => 7,
-            Self::Architect_Data_Core => 8,
-            Self::Scrap => 9,
-            Self::Protomatter => 10,
-            Self::Ion_Engine => 11,
-            Self::Lgt__Ion_Engine => 12,
-            Self::Backup_Power_I => 13,
-            Self::Imp__Ion_Engine => 14,
-            Self::Rnf__Ion_Engine => 15,
-            Self::Hvy__Ion_Engine => 16,
-            Self::Cld__Ion_Engine => 17,
-            Self::Sub__Power_Source => 18,
-            Self::Deuterium_Engine => 19,
-            Self::Lgt__Deuterium_Engine => 20,
-            Self::Backup_Power_III => 21,
-            Self::Nuclear_Core => 22,
-            Self::Lgt__Nuclear_Core => 23,
-            Self::Imp__Deuterium_Engine => 24,
-            Self::Rnf__Deuterium_Engine => 25,
-            Self::Hyb__Deuterium_Engine => 26,
-            Self::Hvy__Deuterium_Engine => 27,
-            Self::Cld__Deuterium_Engine => 28,
-            Self::Enh__Nuclear_Core => 29,
-            Self::Mic__Nuclear_Core => 30,
-            Self::Mak__Power_Source => 31,
-            Self::Angular_Momentum_Engine => 32,
-            Self::Lgt__Angular_Momentum_Engine => 33,
-            Self::F_cell_Engine => 34,
-            Self::Fission_Core => 35,
-            Self::Lgt__Fission_Core => 36,
-            Self::Hyb__Fission_Core => 37,
-            Self::Fusion_Compressor => 38,
-            Self::Cold_Fusion_Reactor => 39,
-            Self::Neutrino_Core => 40,
-            Self::Lgt__Neutrino_Core => 41,
-            Self::Rnf__Fission_Core => 42,
-            Self::Enh__Fission_Core => 43,
-            Self::Cld__Fission_Core => 44,
-            Self::Mic__Fission_Core => 45,
-            Self::Fusion_Reactor => 46,
-            Self::Antimatter_Reactor => 47,
-            Self::Lgt__Antimatter_Reactor => 48,
-            Self::Rnf__Antimatter_Reactor => 49,
-            Self::Hyb__Antimatter_Reactor => 50,
-            Self::Backup_Power_VII => 51,
-            Self::Hvy__Antimatter_Reactor => 52,
-            Self::Mni__Fusion_Reactor => 53,
-            Self::Imp__Fusion_Compressor => 54,
-            Self::Mic__Neutrino_Core => 55,
-            Self::Zio__Light_DM_Reactor => 56,
-            Self::Zio__Heavy_DM_Reactor => 57,
-            Self::Particle_Reactor => 58,
-            Self::Lgt__Particle_Reactor => 59,
-            Self::Mic__Antimatter_Reactor => 60,
-            Self::Com__Particle_Reactor => 61,
-            Self::Cld__Antimatter_Reactor => 62,
-            Self::Graviton_Reactor => 63,
-            Self::Asb__Flux_Generator => 64,
-            Self::Quantum_Reactor => 65,
-            Self::Lgt__Quantum_Reactor => 66,
-            Self::Rnf__Quantum_Reactor => 67,
-            Self::Imp__Quantum_Reactor => 68,
-            Self::Zero_point_Reactor => 69,
-            Self::Vortex_Chain_Reactor => 70,
-            Self::Singularity_Reactor => 71,
-            Self::Matter_Drive => 72,
-            Self::Meta_Core => 73,
-            Self::SE_PO1 => 74,
-            Self::Lgt__Treads => 75,
-            Self::Imp__Treads => 76,
-            Self::Spk__Treads => 77,
-            Self::Arm__Treads => 78,
-            Self::Adv__Treads => 79,
-            Self::Med__Treads => 80,
-            Self::Imp__Medium_Treads => 81,
-            Self::Spk__Medium_Treads => 82,
-            Self::Arm__Medium_Treads => 83,
-            Self::Hvy__Treads => 84,
-            Self::Adv__Medium_Treads => 85,
-            Self::Enh__Armored_Medium_Treads => 86,
-            Self::Imp__Heavy_Treads => 87,
-            Self::Hvy__Siege_Treads => 88,
-            Self::Spk__Heavy_Treads => 89,
-            Self::Biometal_Medium_Treads => 90,
-            Self::Arm__Heavy_Treads => 91,
-            Self::Adv__Heavy_Treads => 92,
-            Self::Imp__Heavy_Siege_Treads => 93,
-            Self::Centrium_Heavy_Treads => 94,
-            Self::Megatreads => 95,
-            Self::Biometal_Heavy_Treads => 96,
-            Self::Exp__Biometal_Heavy_Treads => 97,
-            Self::Enh__Armored_Heavy_Treads => 98,
-            Self::Adv__Heavy_Siege_Treads => 99,
-            Self::Hdn__Centrium_Heavy_Treads => 100,
-            Self::Exp__Biometal_Medium_Treads => 101,
-            Self::Aluminum_Leg => 102,
-            Self::Imp__Aluminum_Leg => 103,
-            Self::Carbon_fiber_Leg => 104,
-            Self::Titanium_Leg => 105,
-            Self::VSS_Leg => 106,
-            Self::Imp__Carbon_fiber_Leg => 107,
-            Self::Imp__Titanium_Leg => 108,
-            Self::Lgt__Armored_Exoskeleton => 109,
-            Self::Mak__Leg => 110,
-            Self::Flexi_carbon_Leg => 111,
-            Self::Arm__Leg => 112,
-            Self::Imp__VSS_Leg => 113,
-            Self::Zio__Composite_Leg_I => 114,
-            Self::Med__Armored_Exoskeleton => 115,
-            Self::Enh__Flexi_carbon_Leg => 116,
-            Self::Enh__Armored_Leg => 117,
-            Self::Arachnoskeleton => 118,
-            Self::Myomer_Leg => 119,
-            Self::Adv__VSS_Leg => 120,
-            Self::Adv__Myomer_Leg => 121,
-            Self::Exp__Flexi_carbon_Leg => 122,
-            Self::Zio__Composite_Leg_II => 123,
-            Self::Cargo_Legs => 124,
-            Self::Etherial_Tendrils => 125,
-            Self::Biometal_Leg => 126,
-            Self::Hvy__Armored_Exoskeleton => 127,
-            Self::Enh__Biometal_Leg => 128,
-            Self::Exp__Myomer_Leg => 129,
-            Self::Tripod_Weapons_Platform => 130,
-            Self::T_R_O_L_L__Exoskeleton => 131,
-            Self::Asb__Alloy_Leg => 132,
-            Self::Centrium_Leg => 133,
-            Self::Hdn__Centrium_Leg => 134,
-            Self::Exp__Biometal_Leg => 135,
-            Self::Zio__Supercomposite_Leg => 136,
-            Self::Potential_Leg => 137,
-            Self::Myomer_Exoskeleton => 138,
-            Self::Sigix_Exoskeleton => 139,
-            Self::SE_PR1 => 140,
-            Self::Wheel => 141,
-            Self::Com__Wheel => 142,
-            Self::Arm__Wheel => 143,
-            Self::Sub__Monowheel => 144,
-            Self::Arm__Huge_Wheel => 145,
-            Self::Centrium_Wheel => 146,
-            Self::Hdn__Centrium_Wheel => 147,
-            Self::Hover_Unit => 148,
-            Self::Backup_Propulsion_I => 149,
-            Self::Imp__Hover_Unit => 150,
-            Self::Airjet => 151,
-            Self::Aerolev_Unit => 152,
-            Self::Asb__Hover_Unit => 153,
-            Self::Backup_Propulsion_III => 154,
-            Self::Gravmag_System => 155,
-            Self::Asb__Hover_System => 156,
-            Self::Imp__Airjet => 157,
-            Self::Cld__Airjet => 158,
-            Self::Imp__Gravmag_System => 159,
-            Self::Cld__Aerolev_Unit => 160,
-            Self::Cmb__Airjet => 161,
-            Self::Gravmag_Array => 162,
-            Self::Mak__Hover_Unit => 163,
-            Self::Zio__Glidesys_I => 164,
-            Self::Antigrav_System => 165,
-            Self::Gyrokinetic_Inverter => 166,
-            Self::Linear_Gravjet => 167,
-            Self::Cld__Gravmag_System => 168,
-            Self::Cmb__Gravmag_System => 169,
-            Self::Imp__Antigrav_System => 170,
-            Self::Imp__Gyrokinetic_Inverter => 171,
-            Self::Imp__Linear_Gravjet => 172,
-            Self::Backup_Propulsion_VII => 173,
-            Self::Zio__Glidesys_II => 174,
-            Self::Cld__Antigrav_System => 175,
-            Self::Cmb__Antigrav_System => 176,
-            Self::Antigrav_Array => 177,
-            Self::Exp__Gyrokinetic_Inverter => 178,
-            Self::Cld__Linear_Gravjet => 179,
-            Self::Cmb__Linear_Gravjet => 180,
-            Self::Flight_Unit => 181,
-            Self::VTOL_Module => 182,
-            Self::Imp__Flight_Unit => 183,
-            Self::Cesium_ion_Thruster => 184,
-            Self::Surge_Thruster => 185,
-            Self::Imp__VTOL_Module => 186,
-            Self::Xenon_Bombardment_Thruster => 187,
-            Self::Imp__Cesium_ion_Thruster => 188,
-            Self::Cld__VTOL_Module => 189,
-            Self::Mak__Flight_Unit => 190,
-            Self::Nuclear_Pulse_Thruster => 191,
-            Self::Imp__Surge_Thruster => 192,
-            Self::Mni__Nuclear_Pulse_Thruster => 193,
-            Self::Cld__Cesium_ion_Thruster => 194,
-            Self::Biomechanical_Wings => 195,
-            Self::Field_Propulsion_Drive => 196,
-            Self::Cld__Nuclear_Pulse_Thruster => 197,
-            Self::Exp__Cesium_ion_Thruster => 198,
-            Self::Nuclear_Pulse_Array => 199,
-            Self::Mak__Microthruster => 200,
-            Self::Impulse_Thruster => 201,
-            Self::Adv__Surge_Thruster => 202,
-            Self::Cld__Field_Propulsion_Drive => 203,
-            Self::Field_Propulsion_Array => 204,
-            Self::Diametric_Drive => 205,
-            Self::Flying_Fortress_v7 => 206,
-            Self::T_thruster => 207,
-            Self::Integrated_Tracker_Drive => 208,
-            Self::Etherial_Propulsion => 209,
-            Self::Q_thruster => 210,
-            Self::Electron_Diverter => 211,
-            Self::Imp__Diametric_Drive => 212,
-            Self::Cld__Impulse_Thruster => 213,
-            Self::Impulse_Thruster_Array => 214,
-            Self::Flightbrick => 215,
-            Self::Imp__Q_thruster => 216,
-            Self::Cld__Q_thruster => 217,
-            Self::Exp__Q_thruster => 218,
-            Self::Scrap_Engine => 219,
-            Self::Arm__Scrap_Engine => 220,
-            Self::Desublimator => 221,
-            Self::Power_Amplifier => 222,
-            Self::Adv__Power_Amplifier => 223,
-            Self::Exp__Power_Amplifier => 224,
-            Self::Thermal_Generator => 225,
-            Self::Imp__Thermal_Generator => 226,
-            Self::Adv__Thermal_Generator => 227,
-            Self::Exp__Thermal_Generator => 228,
-            Self::Cryofiber_Web => 229,
-            Self::Imp__Cryofiber_Web => 230,
-            Self::Adv__Cryofiber_Web => 231,
-            Self::Exp__Cryofiber_Web => 232,
-            Self::Heat_Sink => 233,
-            Self::Imp__Heat_Sink => 234,
-            Self::Adv__Heat_Sink => 235,
-            Self::Exp__Heat_Sink => 236,
-            Self::Cooling_System => 237,
-            Self::Imp__Cooling_System => 238,
-            Self::Adv__Cooling_System => 239,
-            Self::Exp__Cooling_System => 240,
-            Self::Coolant_Network => 241,
-            Self::Asb__Nanovents => 242,
-            Self::_2N_1CE_s_Frost_Array => 243,
-            Self::Disposable_Heat_Sink => 244,
-            Self::Coolant_Injector => 245,
-            Self::Imp__Coolant_Injector => 246,
-            Self::Adv__Coolant_Injector => 247,
-            Self::Exp__Coolant_Injector => 248,
-            Self::Sml__Storage_Unit => 249,
-            Self::Med__Storage_Unit => 250,
-            Self::Lrg__Storage_Unit => 251,
-            Self::Hcp__Storage_Unit => 252,
-            Self::Huge_Storage_Unit => 253,
-            Self::Cargo_Storage_Unit => 254,
-            Self::Humpback => 255,
-            Self::Lightpack_2_0 => 256,
-            Self::Sml__Battery => 257,
-            Self::Med__Battery => 258,
-            Self::Lrg__Battery => 259,
-            Self::Hcp__Battery => 260,
-            Self::Com__Battery => 261,
-            Self::Energy_Well => 262,
-            Self::Imp__Energy_Well => 263,
-            Self::Adv__Energy_Well => 264,
-            Self::Exp__Energy_Well => 265,
-            Self::Zio__Biocell => 266,
-            Self::Asb__Biocell_Array => 267,
-            Self::Zio__Biocell_Array => 268,
-            Self::Superbattery => 269,
-            Self::Sml__Matter_Pod => 270,
-            Self::Med__Matter_Pod => 271,
-            Self::Lrg__Matter_Pod => 272,
-            Self::Hcp__Matter_Pod => 273,
-            Self::Com__Matter_Pod => 274,
-            Self::Matter_Compressor => 275,
-            Self::Imp__Matter_Compressor => 276,
-            Self::Adv__Matter_Compressor => 277,
-            Self::Exp__Matter_Compressor => 278,
-            Self::YI_UF0_s_Bottomless_Matter_Pit => 279,
-            Self::Weight_Redist__System => 280,
-            Self::Adv__Weight_Redist__System => 281,
-            Self::Gravity_Neutralizer => 282,
-            Self::Adv__Gravity_Neutralizer => 283,
-            Self::Quantum_Shading_Machine => 284,
-            Self::Asb__Suspension_Frame => 285,
-            Self::Adv__Quantum_Shading_Machine => 286,
-            Self::Asb__Metafiber_Network => 287,
-            Self::Zio__Metafield_Generator => 288,
-            Self::ST_Field_Compressor => 289,
-            Self::Structural_Scanner => 290,
-            Self::Trap_Scanner => 291,
-            Self::Imp__Trap_Scanner => 292,
-            Self::Mak__Trap_Scanner => 293,
-            Self::Adv__Trap_Scanner => 294,
-            Self::Machine_Analyzer => 295,
-            Self::Triangulator => 296,
-            Self::Visual_Processing_Unit => 297,
-            Self::Sub__Optics => 298,
-            Self::Adv__Visual_Processing_Unit => 299,
-            Self::Enh__Optical_Array => 300,
-            Self::Exp__Optical_Array => 301,
-            Self::Spectral_Analyzer => 302,
-            Self::Sensor_Array => 303,
-            Self::Imp__Sensor_Array => 304,
-            Self::Adv__Sensor_Array => 305,
-            Self::Lrn__Sensor_Array => 306,
-            Self::Mak__Sensor_Array => 307,
-            Self::Exp__Sensor_Array => 308,
-            Self::Signal_Interpreter => 309,
-            Self::Imp__Signal_Interpreter => 310,
-            Self::Adv__Signal_Interpreter => 311,
-            Self::Exp__Signal_Interpreter => 312,
-            Self::Active_Sensor_Suite => 313,
-            Self::Terrain_Scanner => 314,
-            Self::Imp__Terrain_Scanner => 315,
-            Self::Adv__Terrain_Scanner => 316,
-            Self::Mak__Terrain_Scanner => 317,
-            Self::Lrn__Terrain_Scanner => 318,
-            Self::Exp__Terrain_Scanner => 319,
-            Self::Surveybot_24_Scanner => 320,
-            Self::Terrain_Scan_Processor => 321,
-            Self::Imp__Terrain_Scan_Processor => 322,
-            Self::Mak__Terrain_Scan_Processor => 323,
-            Self::Adv__Terrain_Scan_Processor => 324,
-            Self::Exp__Terrain_Scan_Processor => 325,
-            Self::Surveybot_24_Chip => 326,
-            Self::Seismic_Detector => 327,
-            Self::Transport_Network_Coupler => 328,
-            Self::Modified_TNC => 329,
-            Self::Encrypted_Comm_Array => 330,
-            Self::_0b10_Decoder_Chip__Generic_ => 331,
-            Self::_0b10_Decoder_Chip__Looter_ => 332,
-            Self::_0b10_Decoder_Chip__Scout_ => 333,
-            Self::_0b10_Decoder_Chip__Skirmisher_ => 334,
-            Self::_0b10_Alert_Chip => 335,
-            Self::Salvage_Targeting_Computer => 336,
-            Self::Imp__Salvage_Targeting_Computer => 337,
-            Self::Mak__Salvage_Targeting_Computer => 338,
-            Self::Adv__Salvage_Targeting_Computer => 339,
-            Self::Asb__Combat_Suite => 340,
-            Self::Targeting_Computer => 341,
-            Self::Imp__Targeting_Computer => 342,
-            Self::Adv__Targeting_Computer => 343,
-            Self::Exp__Targeting_Computer => 344,
-            Self::Multitargeting_Array => 345,
-            Self::Tactical_Coordination_Suite => 346,
-            Self::Melee_Analysis_Suite => 347,
-            Self::Imp__Melee_Analysis_Suite => 348,
-            Self::Adv__Melee_Analysis_Suite => 349,
-            Self::Exp__Melee_Analysis_Suite => 350,
-            Self::Launcher_Guidance_Computer => 351,
-            Self::Imp__Launcher_Guidance_Computer => 352,
-            Self::Adv__Launcher_Guidance_Computer => 353,
-            Self::Weapon_Mount => 354,
-            Self::Target_Analyzer => 355,
-            Self::Imp__Target_Analyzer => 356,
-            Self::Adv__Target_Analyzer => 357,
-            Self::Exp__Target_Analyzer => 358,
-            Self::Core_Analyzer => 359,
-            Self::Exp__Core_Analyzer => 360,
-            Self::Armor_Integrity_Analyzer => 361,
-            Self::Imp__Armor_Integrity_Analyzer => 362,
-            Self::Exp__Armor_Integrity_Analyzer => 363,
-            Self::Recoil_Stabilizer => 364,
-            Self::Recoil_Nullifier => 365,
-            Self::Matter_Filter => 366,
-            Self::Prc__Matter_Filter => 367,
-            Self::Energy_Filter => 368,
-            Self::Prc__Energy_Filter => 369,
-            Self::Particle_Charger => 370,
-            Self::Imp__Particle_Charger => 371,
-            Self::Adv__Particle_Charger => 372,
-            Self::Particle_Accelerator => 373,
-            Self::Imp__Particle_Accelerator => 374,
-            Self::Adv__Particle_Accelerator => 375,
-            Self::Kinecellerator => 376,
-            Self::Imp__Kinecellerator => 377,
-            Self::Adv__Kinecellerator => 378,
-            Self::Heavy_Servo_Lattice => 379,
-            Self::VL_GR5_s_Timing_Chip => 380,
-            Self::Launcher_Loader => 381,
-            Self::Quantum_Capacitor => 382,
-            Self::Weapon_Cycler => 383,
-            Self::Imp__Weapon_Cycler => 384,
-            Self::Adv__Weapon_Cycler => 385,
-            Self::Exp__Weapon_Cycler => 386,
-            Self::Microactuators => 387,
-            Self::Nanoactuators => 388,
-            Self::Femtoactuators => 389,
-            Self::Actuator_Array => 390,
-            Self::Imp__Actuator_Array => 391,
-            Self::Adv__Actuator_Array => 392,
-            Self::Exp__Actuator_Array => 393,
-            Self::Force_Booster => 394,
-            Self::Imp__Force_Booster => 395,
-            Self::Adv__Force_Booster => 396,
-            Self::Stasis_Canceller => 397,
-            Self::Transmission_Jammer => 398,
-            Self::Mak__Transmission_Jammer => 399,
-            Self::Imp__Transmission_Jammer => 400,
-            Self::Adv__Transmission_Jammer => 401,
-            Self::Exp__Transmission_Jammer => 402,
-            Self::ECM_Suite => 403,
-            Self::Mak__ECM_Suite => 404,
-            Self::Adv__ECM_Suite => 405,
-            Self::Exp__ECM_Suite => 406,
-            Self::Active_Sensor_Spoofer => 407,
-            Self::Reaction_Control_System => 408,
-            Self::Imp__Reaction_Control_System => 409,
-            Self::Adv__Reaction_Control_System => 410,
-            Self::Exp__Reaction_Control_System => 411,
-            Self::Phase_Shifter => 412,
-            Self::Imp__Phase_Shifter => 413,
-            Self::Adv__Phase_Shifter => 414,
-            Self::Exp__Phase_Shifter => 415,
-            Self::Cloaking_Device => 416,
-            Self::Mak__Cloaking_Device => 417,
-            Self::Imp__Cloaking_Device => 418,
-            Self::Adv__Cloaking_Device => 419,
-            Self::Exp__Cloaking_Device => 420,
-            Self::Cloak_of_Protection => 421,
-            Self::_5H_AD0_s_Cloak => 422,
-            Self::Core_Shielding => 423,
-            Self::Imp__Core_Shielding => 424,
-            Self::Exp__Core_Shielding => 425,
-            Self::Power_Shielding => 426,
-            Self::Imp__Power_Shielding => 427,
-            Self::Exp__Power_Shielding => 428,
-            Self::Propulsion_Shielding => 429,
-            Self::Imp__Propulsion_Shielding => 430,
-            Self::Exp__Propulsion_Shielding => 431,
-            Self::Utility_Shielding => 432,
-            Self::Imp__Utility_Shielding => 433,
-            Self::Exp__Utility_Shielding => 434,
-            Self::Weapon_Shielding => 435,
-            Self::Imp__Weapon_Shielding => 436,
-            Self::Exp__Weapon_Shielding => 437,
-            Self::Zio__Weapon_Casing => 438,
-            Self::Backup_Plating_I => 439,
-            Self::Backup_Plating_III => 440,
-            Self::Backup_Plating_VII => 441,
-            Self::Lgt__Armor_Plating => 442,
-            Self::Med__Armor_Plating => 443,
-            Self::Hvy__Armor_Plating => 444,
-            Self::Zio__Light_Carapace => 445,
-            Self::Zio__Heavy_Carapace => 446,
-            Self::Hrd__Light_Armor_Plating => 447,
-            Self::Hrd__Medium_Armor_Plating => 448,
-            Self::Hrd__Heavy_Armor_Plating => 449,
-            Self::Imp__Light_Armor_Plating => 450,
-            Self::Imp__Medium_Armor_Plating => 451,
-            Self::Imp__Heavy_Armor_Plating => 452,
-            Self::Mak__Light_Armor_Plating => 453,
-            Self::Lyr__Light_Armor_Plating => 454,
-            Self::Lyr__Medium_Armor_Plating => 455,
-            Self::Lyr__Heavy_Armor_Plating => 456,
-            Self::Gun_Armor => 457,
-            Self::Centrium_Light_Armor_Plating => 458,
-            Self::Centrium_Medium_Armor_Plating => 459,
-            Self::Centrium_Heavy_Armor_Plating => 460,
-            Self::Centrium_Linked_Plating => 461,
-            Self::Reactive_Plating => 462,
-            Self::Med__Reactive_Plating => 463,
-            Self::Mak__Kinetic_Plating => 464,
-            Self::Hvy__Reactive_Plating => 465,
-            Self::Reflective_Plating => 466,
-            Self::Med__Reflective_Plating => 467,
-            Self::Mak__Thermal_Plating => 468,
-            Self::Hvy__Reflective_Plating => 469,
-            Self::_8R_AWN_s_Armor_TH => 470,
-            Self::_8R_AWN_s_Armor_EX => 471,
-            Self::Insulated_Plating => 472,
-            Self::Med__Insulated_Plating => 473,
-            Self::Hvy__Insulated_Plating => 474,
-            Self::Damper_Plating => 475,
-            Self::Zio__Shade_Carapace => 476,
-            Self::Zio__Shade_Armor => 477,
-            Self::Asb__Alloy_Armor => 478,
-            Self::Mak__Ablative_Armor => 479,
-            Self::Active_Cooling_Armor => 480,
-            Self::Scrap_Shield => 481,
-            Self::Powered_Armor => 482,
-            Self::Imp__Powered_Armor => 483,
-            Self::Adv__Powered_Armor => 484,
-            Self::Exp__Powered_Armor => 485,
-            Self::_1C_UTU_s_Buckler => 486,
-            Self::Lgt__Regenerative_Plating => 487,
-            Self::Med__Regenerative_Plating => 488,
-            Self::Hvy__Regenerative_Plating => 489,
-            Self::SHELL_Armor => 490,
-            Self::Phase_Armor => 491,
-            Self::Graphene_Brace => 492,
-            Self::Focal_Shield => 493,
-            Self::Imp__Focal_Shield => 494,
-            Self::Adv__Focal_Shield => 495,
-            Self::Exp__Focal_Shield => 496,
-            Self::Thermal_Shield => 497,
-            Self::Imp__Thermal_Shield => 498,
-            Self::Adv__Thermal_Shield => 499,
-            Self::Exp__Thermal_Shield => 500,
-            Self::Thermal_Barrier => 501,
-            Self::Beam_Splitter => 502,
-            Self::Thermal_Defense_Suite => 503,
-            Self::Imp__Thermal_Defense_Suite => 504,
-            Self::Adv__Thermal_Defense_Suite => 505,
-            Self::Exp__Thermal_Defense_Suite => 506,
-            Self::Shock_Absorption_System => 507,
-            Self::Imp__Shock_Absorption_System => 508,
-            Self::Exp__Shock_Absorption_System => 509,
-            Self::EM_Disruption_Field => 510,
-            Self::Adv__EM_Disruption_Field => 511,
-            Self::Exp__EM_Disruption_Field => 512,
-            Self::ME_RLN_s_Chromatic_Screen => 513,
-            Self::Hardlight_Generator => 514,
-            Self::Imp__Hardlight_Generator => 515,
-            Self::Adv__Hardlight_Generator => 516,
-            Self::Exp__Hardlight_Generator => 517,
-            Self::Shield_Generator => 518,
-            Self::Imp__Shield_Generator => 519,
-            Self::Adv__Shield_Generator => 520,
-            Self::Exp__Shield_Generator => 521,
-            Self::Force_Field => 522,
-            Self::Imp__Force_Field => 523,
-            Self::Adv__Force_Field => 524,
-            Self::Exp__Force_Field => 525,
-            Self::_7V_RTL_s_Ultimate_Field => 526,
-            Self::Vortex_Field_Projector => 527,
-            Self::Remote_Shield => 528,
-            Self::Imp__Remote_Shield => 529,
-            Self::Adv__Remote_Shield => 530,
-            Self::Remote_Force_Field => 531,
-            Self::Imp__Remote_Force_Field => 532,
-            Self::Energy_Mantle => 533,
-            Self::Imp__Energy_Mantle => 534,
-            Self::AEGIS_Remote_Shield => 535,
-            Self::Phase_Redirector => 536,
-            Self::Point_Defense_System => 537,
-            Self::Point_Defense_Array => 538,
-            Self::Antimissile_System => 539,
-            Self::EX_Chip_1 => 540,
-            Self::Hacking_Suite => 541,
-            Self::Mak__Hacking_Suite => 542,
-            Self::Imp__Hacking_Suite => 543,
-            Self::Adv__Hacking_Suite => 544,
-            Self::Exp__Hacking_Suite => 545,
-            Self::System_Mapper => 546,
-            Self::Deep_Network_Scanner => 547,
-            Self::Architect_God_Chip_A => 548,
-            Self::EX_Chip_2 => 549,
-            Self::System_Shield => 550,
-            Self::Mak__System_Shield => 551,
-            Self::Imp__System_Shield => 552,
-            Self::Adv__System_Shield => 553,
-            Self::Exp__System_Shield => 554,
-            Self::Ghost_Barrier => 555,
-            Self::Quantum_Router => 556,
-            Self::Architect_God_Chip_D => 557,
-            Self::Authchip__R_NC_ => 558,
-            Self::Authchip__R_Combat_ => 559,
-            Self::Authchip__Power_ => 560,
-            Self::Authchip__Propulsion_ => 561,
-            Self::Authchip__Device_ => 562,
-            Self::Authchip__Storage_ => 563,
-            Self::Authchip__Processor_ => 564,
-            Self::Authchip__Armor_ => 565,
-            Self::Authchip__Weapon_ => 566,
-            Self::Relay_Coupler__NC_ => 567,
-            Self::Relay_Coupler__C_ => 568,
-            Self::Relay_Coupler__Swarmer_ => 569,
-            Self::Relay_Coupler__Cutter_ => 570,
-            Self::Relay_Coupler__Grunt_ => 571,
-            Self::Relay_Coupler__Brawler_ => 572,
-            Self::Relay_Coupler__Duelist_ => 573,
-            Self::Relay_Coupler__Sentry_ => 574,
-            Self::Relay_Coupler__Demolisher_ => 575,
-            Self::Relay_Coupler__Specialist_ => 576,
-            Self::Relay_Coupler__Hunter_ => 577,
-            Self::Relay_Coupler__Heavy_ => 578,
-            Self::Relay_Coupler__Behemoth_ => 579,
-            Self::Relay_Coupler__Programmer_ => 580,
-            Self::Relay_Coupler__Proto_ => 581,
-            Self::Skeleton_Box => 582,
-            Self::_01_MTF_s_Autohacker => 583,
-            Self::Component_Analysis_Suite => 584,
-            Self::Imp__Component_Analysis_Suite => 585,
-            Self::Adv__Component_Analysis_Suite => 586,
-            Self::Dynamic_Insulation_System => 587,
-            Self::Imp__Dynamic_Insulation_System => 588,
-            Self::Adv__Dynamic_Insulation_System => 589,
-            Self::System_Guard => 590,
-            Self::Imp__System_Guard => 591,
-            Self::Exp__System_Guard => 592,
-            Self::Corruption_Screen => 593,
-            Self::Imp__Corruption_Screen => 594,
-            Self::Adv__Corruption_Screen => 595,
-            Self::System_Restoration_Module => 596,
-            Self::Mak__System_Restoration_Module => 597,
-            Self::Imp__System_Restoration_Module => 598,
-            Self::Adv__System_Restoration_Module => 599,
-            Self::System_Purifier => 600,
-            Self::Exp__System_Purifier => 601,
-            Self::Tractor_Beam => 602,
-            Self::Hpw__Tractor_Beam => 603,
-            Self::Lrn__Tractor_Beam => 604,
-            Self::Mobile_Refinery_Mk__CCLXVII => 605,
-            Self::Trap_Extractor => 606,
-            Self::Imp__Trap_Extractor => 607,
-            Self::Adv__Trap_Extractor => 608,
-            Self::Trap_Reconfigurator => 609,
-            Self::Recalibrator => 610,
-            Self::Imp__Recalibrator => 611,
-            Self::Adv__Recalibrator => 612,
-            Self::Field_Recycling_Unit => 613,
-            Self::Imp__Field_Recycling_Unit => 614,
-            Self::Adv__Field_Recycling_Unit => 615,
-            Self::Exp__Field_Recycling_Unit => 616,
-            Self::Drone_Bay => 617,
-            Self::Z_Drone_Bay => 618,
-            Self::Mni__Drone_Bay => 619,
-            Self::Mapping_Drone_Bay => 620,
-            Self::Adv__Drone_Bay => 621,
-            Self::Bomb_Drone_Bay => 622,
-            Self::Thief_Drone_Bay => 623,
-            Self::Decoy_Drone_Bay => 624,
-            Self::Splice_Drone_Bay => 625,
-            Self::Combat_Drone_Bay => 626,
-            Self::Assault_Drone_Bay => 627,
-            Self::Stealth_Drone_Bay => 628,
-            Self::Swarm_Drone_Bay => 629,
-            Self::Supersonic_Drone_Bay => 630,
-            Self::Sensor_Drone_Bay => 631,
-            Self::Hacking_Drone_Bay => 632,
-            Self::Minesniffer_Drone_Bay => 633,
-            Self::Army_in_a_Box => 634,
-            Self::Wardrone_Bay => 635,
-            Self::Cobbler_Unit => 636,
-            Self::Bomb_Factory_No__2 => 637,
-            Self::Mobile_Assembly_Unit => 638,
-            Self::Heart_of_Steel => 639,
-            Self::GOLEM_Unit => 640,
-            Self::PSU_Rigger => 641,
-            Self::Phasing_AFG => 642,
-            Self::AS_neutralizer_No__17b => 643,
-            Self::V3_11A_s_Pendant => 644,
-            Self::Chronowheel => 645,
-            Self::Power_Bank => 646,
-            Self::Rainbow_Chip => 647,
-            Self::VL_GR5_s_Exoskeleton__Deathgrip_ => 648,
-            Self::Dimensional_Node_Initializer => 649,
-            Self::Transdimensional_Reconstructor => 650,
-            Self::Hpw__Transdimensional_Reconstructor => 651,
-            Self::Supercharged_TR => 652,
-            Self::Core_Expander => 653,
-            Self::Core_Regenerator => 654,
-            Self::Integrity_Redistributor => 655,
-            Self::Integrated_Dissipator => 656,
-            Self::Supercharged_Integrated_Dissipator => 657,
-            Self::Integrated_Thermoelectric_Network => 658,
-            Self::Integrated_Reactor => 659,
-            Self::Supercharged_Integrated_Reactor => 660,
-            Self::Integrated_Mediator => 661,
-            Self::Core_Membrane => 662,
-            Self::Navigation_Efficiency_Matrix => 663,
-            Self::Core_Reset_Matrix => 664,
-            Self::Subatomic_Replicator => 665,
-            Self::Terrabomb => 666,
-            Self::Core_Physics_Calibrator => 667,
-            Self::Spacefold_Activator => 668,
-            Self::Microwarp_Drive => 669,
-            Self::LC_Capacitor => 670,
-            Self::LRC_Armor => 671,
-            Self::LRC_Storage => 672,
-            Self::LRC_Energy_Well => 673,
-            Self::LRC_Matter_Compressor => 674,
-            Self::LRC_Inertial_Stabilizer => 675,
-            Self::LRC_Insulator => 676,
-            Self::EM_Pulse_Gun => 677,
-            Self::Hvy__EM_Pulse_Gun => 678,
-            Self::EM_Shotgun => 679,
-            Self::Imp__EM_Shotgun => 680,
-            Self::Shock_Rifle => 681,
-            Self::Riot_Gun => 682,
-            Self::Hvy__EM_Shotgun => 683,
-            Self::Lightning_Gun => 684,
-            Self::Hvy__Riot_Gun => 685,
-            Self::Arc_Projector => 686,
-            Self::Prc__Shock_Rifle => 687,
-            Self::Hpw__Shock_Rifle => 688,
-            Self::Arc_Thrower => 689,
-            Self::Imp__Lightning_Gun => 690,
-            Self::Tesla_Rifle => 691,
-            Self::Imp__Arc_Projector => 692,
-            Self::Hvy__Tesla_Rifle => 693,
-            Self::Hyp__EM_Gauss_Rifle => 694,
-            Self::Imp__Arc_Thrower => 695,
-            Self::_01_MTF_s_Shockpuncher => 696,
-            Self::Gamma_Rifle => 697,
-            Self::Tachyon_Dispersion_Ray => 698,
-            Self::Immobilizer => 699,
-            Self::Modified_EM_Gauss_Rifle => 700,
-            Self::AWS_EX_DEC => 701,
-            Self::Enh__Gamma_Rifle => 702,
-            Self::Med__Laser => 703,
-            Self::Sml__Laser => 704,
-            Self::Backup_Laser_I => 705,
-            Self::Beam_Rifle => 706,
-            Self::Particle_Gun => 707,
-            Self::Spread_Laser => 708,
-            Self::Imp__Medium_Laser => 709,
-            Self::Sub__Laser => 710,
-            Self::Autobeam => 711,
-            Self::Field_Laser => 712,
-            Self::Pulse_Rifle => 713,
-            Self::Backup_Laser_III => 714,
-            Self::Hvy__Particle_Gun => 715,
-            Self::Prc__Beam_Rifle => 716,
-            Self::Adv__Beam_Rifle => 717,
-            Self::Hvy__Laser => 718,
-            Self::Gatling_Laser => 719,
-            Self::Cld__Pulse_Rifle => 720,
-            Self::Hpw__Field_Laser => 721,
-            Self::Imp__Spread_Laser => 722,
-            Self::Mak__Laser => 723,
-            Self::Plasma_Rifle => 724,
-            Self::Thermic_Laser => 725,
-            Self::Beamcaster => 726,
-            Self::Force_Rifle => 727,
-            Self::Imp__Heavy_Laser => 728,
-            Self::Storm_Laser => 729,
-            Self::Zio__Laser_S => 730,
-            Self::Wave_Gun => 731,
-            Self::Variable_Charge_Gun => 732,
-            Self::Hvy__Beamcaster => 733,
-            Self::Cld__Plasma_Rifle => 734,
-            Self::Enh__Force_Rifle => 735,
-            Self::Hvy__Gatling_Laser => 736,
-            Self::Zio__Phaser_S => 737,
-            Self::Phase_Gun => 738,
-            Self::Dispersion_Rifle => 739,
-            Self::Backup_Laser_VII => 740,
-            Self::Plasma_Storm => 741,
-            Self::Adv__Plasma_Rifle => 742,
-            Self::Hvy__Wave_Gun => 743,
-            Self::Adv__Variable_Charge_Gun => 744,
-            Self::Cld__Phase_Gun => 745,
-            Self::Lrn__Gatling_Laser => 746,
-            Self::Warp_Gun => 747,
-            Self::Vortex_Rifle => 748,
-            Self::Zio__Laser_M => 749,
-            Self::AWS_Thermal => 750,
-            Self::PD_Laser => 751,
-            Self::Quantum_Rifle => 752,
-            Self::Prc__Phase_Gun => 753,
-            Self::Hvy__Dispersion_Rifle => 754,
-            Self::Vortex_Shotgun => 755,
-            Self::Vortex_Rail => 756,
-            Self::Zio__Phaser_M => 757,
-            Self::Enh__Quantum_Rifle => 758,
-            Self::Zio__Laser_H => 759,
-            Self::Hvy__Quantum_Rifle => 760,
-            Self::Zio__Phaser_H => 761,
-            Self::Gatling_Beam => 762,
-            Self::Sigix_Sheargun => 763,
-            Self::Modified_Sigix_Sheargun => 764,
-            Self::Lgt__EM_Pulse_Cannon => 765,
-            Self::EM_Pulse_Cannon => 766,
-            Self::Imp__EM_Pulse_Cannon => 767,
-            Self::Proton_Cannon => 768,
-            Self::Lrn__Proton_Cannon => 769,
-            Self::Disruptor_Cannon => 770,
-            Self::Hvy__Proton_Cannon => 771,
-            Self::HERF_Cannon => 772,
-            Self::Hvy__Disruptor_Cannon => 773,
-            Self::Cld__Proton_Cannon => 774,
-            Self::Proton_Burst_Cannon => 775,
-            Self::Com__HERF_Cannon => 776,
-            Self::Latent_Energy_Streamer => 777,
-            Self::NK_0LA_s_Tesla_Doomcannon => 778,
-            Self::EMDS => 779,
-            Self::Hpw__Disruptor_Cannon => 780,
-            Self::Beam_Cannon => 781,
-            Self::Particle_Cannon => 782,
-            Self::Adv__Beam_Cannon => 783,
-            Self::Sub__Beam_Cannon => 784,
-            Self::Hvy__Particle_Cannon => 785,
-            Self::Ion_Cannon => 786,
-            Self::Plasma_Cannon => 787,
-            Self::Hvy__Ion_Cannon => 788,
-            Self::Cld__Particle_Cannon => 789,
-            Self::Phase_Cannon => 790,
-            Self::Hvy__Plasma_Cannon => 791,
-            Self::Cld__Plasma_Cannon => 792,
-            Self::Com__Ion_Cannon => 793,
-            Self::Ion_Burst_Cannon => 794,
-            Self::Adv__Plasma_Cannon => 795,
-            Self::Hvy__Phase_Cannon => 796,
-            Self::Neutron_Cannon => 797,
-            Self::Powered_Cannon => 798,
-            Self::Matter_Neutralizer => 799,
-            Self::Hvy__Neutron_Cannon => 800,
-            Self::Lrn__Phase_Cannon => 801,
-            Self::Cld__Phase_Cannon => 802,
-            Self::Vortex_Cannon => 803,
-            Self::Gui__Plasma_Cannon => 804,
-            Self::Nova_Cannon => 805,
-            Self::Imp__Matter_Neutralizer => 806,
-            Self::Exp__Thermic_Cannon => 807,
-            Self::Vortex_Driver => 808,
-            Self::Zio__Alpha_Cannon => 809,
-            Self::Asb__Focal_Cannon => 810,
-            Self::Enh__Nova_Cannon => 811,
-            Self::Firepult => 812,
-            Self::YOLO_Cannon => 813,
-            Self::Potential_Cannon => 814,
-            Self::Warp_Cannon => 815,
-            Self::Cld__Nova_Cannon => 816,
-            Self::Null_Cannon => 817,
-            Self::Imp__Potential_Cannon => 818,
-            Self::Multinova_Projection_Cannon => 819,
-            Self::Disintegrator => 820,
-            Self::Core_Cannon => 821,
-            Self::Sigix_Shearcannon => 822,
-            Self::Drained_L_Cannon => 823,
-            Self::L_Cannon => 824,
-            Self::Zio__Alpha_Cannon_Mk__II => 825,
-            Self::BFG_9k_Vortex_Edition => 826,
-            Self::Assault_Rifle => 827,
-            Self::Asb__Rifle => 828,
-            Self::Lgt__Assault_Rifle => 829,
-            Self::Hvy__Assault_Rifle => 830,
-            Self::Autogun => 831,
-            Self::Shotgun => 832,
-            Self::Battle_Rifle => 833,
-            Self::Asb__Heavy_Rifle => 834,
-            Self::Sub__Machine_Gun => 835,
-            Self::Imp__Assault_Rifle => 836,
-            Self::Minigun => 837,
-            Self::Hpw__Shotgun => 838,
-            Self::Asb__Shotgun => 839,
-            Self::Barrage_Gun => 840,
-            Self::Enh__Autogun => 841,
-            Self::Gauss_Rifle => 842,
-            Self::Asb__Gauss_Rifle => 843,
-            Self::Flak_Gun => 844,
-            Self::Prc__Assault_Rifle => 845,
-            Self::Hvy__Battle_Rifle => 846,
-            Self::Mak__Shrapnel_Gun => 847,
-            Self::Coil_Gun => 848,
-            Self::Hyp__Gauss_Rifle => 849,
-            Self::Hvy__Machine_Gun => 850,
-            Self::Imp__Heavy_Machine_Gun => 851,
-            Self::Com__Gauss_Rifle => 852,
-            Self::XL_Autogun_Subcomponent => 853,
-            Self::KE_Penetrator => 854,
-            Self::Hyp__Coil_Gun => 855,
-            Self::Imp__KE_Penetrator => 856,
-            Self::Enh__Coil_Gun => 857,
-            Self::Com__Coil_Gun => 858,
-            Self::Railgun => 859,
-            Self::Adv__KE_Penetrator => 860,
-            Self::Linked_Autogun => 861,
-            Self::AWS_Gauss => 862,
-            Self::Hyp__Railgun => 863,
-            Self::Helical_Railgun => 864,
-            Self::Com__Railgun => 865,
-            Self::XL_Autogun_Array => 866,
-            Self::_12_ASH_s_Boomstick => 867,
-            Self::_8R_AWN_s_Boregun => 868,
-            Self::Multirail => 869,
-            Self::Hyp__Multirail => 870,
-            Self::_5H_AD0_s_Sniper_Rifle => 871,
-            Self::A3_s_Sniper_Rifle => 872,
-            Self::Lgt__Cannon => 873,
-            Self::Imp__Light_Cannon => 874,
-            Self::Battle_Cannon => 875,
-            Self::Assault_Cannon => 876,
-            Self::Hvy__Assault_Cannon => 877,
-            Self::Flak_Cannon => 878,
-            Self::Adv__Assault_Cannon => 879,
-            Self::Gauss_Cannon => 880,
-            Self::Slug_Cannon => 881,
-            Self::Mni__Assault_Cannon => 882,
-            Self::Magnetic_Acceleration_Cannon => 883,
-            Self::Hvy__Flak_Cannon => 884,
-            Self::Bore_Cannon => 885,
-            Self::Antimatter_Cannon => 886,
-            Self::Mass_Driver => 887,
-            Self::Hvy__Mass_Driver => 888,
-            Self::Enh__Gauss_Cannon => 889,
-            Self::Hyp__Gauss_Cannon => 890,
-            Self::Tri_rail_Accelerator => 891,
-            Self::Hardcell_Cannon => 892,
-            Self::Hvy__Gauss_Cannon => 893,
-            Self::Hyp__Mass_Driver => 894,
-            Self::Hvy__Autocannon => 895,
-            Self::DAS_Cannon => 896,
-            Self::Lgt__Antimatter_Cannon => 897,
-            Self::Linear_Accelerator => 898,
-            Self::Hvy__Linear_Accelerator => 899,
-            Self::Com__Mass_Driver => 900,
-            Self::Hvy__Hardcell_Cannon => 901,
-            Self::Com__Linear_Accelerator => 902,
-            Self::Hyp__Linear_Accelerator => 903,
-            Self::Hcl__Linear_Accelerator => 904,
-            Self::Graviton_Cannon => 905,
-            Self::Blast_Cannon => 906,
-            Self::P1_3CE_s_Gatling_Flakker => 907,
-            Self::AWS_Autocannon => 908,
-            Self::Perforator => 909,
-            Self::Omega_Cannon => 910,
-            Self::EMP_Blaster => 911,
-            Self::Shock_Bomb_Launcher => 912,
-            Self::Adv__EMP_Blaster => 913,
-            Self::Proton_Missile_Launcher => 914,
-            Self::Imp__Proton_Missile_Launcher => 915,
-            Self::Gui__EMP_Blaster => 916,
-            Self::Hvy__Shock_Bomb_Launcher => 917,
-            Self::Tesla_Bomb_Launcher => 918,
-            Self::Hvy__Proton_Missile_Launcher => 919,
-            Self::Com__Tesla_Bomb_Launcher => 920,
-            Self::Gamma_Bomb_Array => 921,
-            Self::Enh__Gamma_Bomb_Array => 922,
-            Self::Grenade_Launcher => 923,
-            Self::Rocket_Launcher => 924,
-            Self::Mni__Grenade_Launcher => 925,
-            Self::Sub__Shrapnel_Launcher => 926,
-            Self::Imp__Grenade_Launcher => 927,
-            Self::Missile_Launcher => 928,
-            Self::Concussive_RPG => 929,
-            Self::YI_UF0_s_Doublenader => 930,
-            Self::Hvy__Rocket_Launcher => 931,
-            Self::Hvy__Missile_Launcher => 932,
-            Self::Gui__Missile_Launcher => 933,
-            Self::Prc__Grenade_Launcher => 934,
-            Self::Rocket_Array => 935,
-            Self::Lrn__Missile_Launcher => 936,
-            Self::Imp__Concussive_RPG => 937,
-            Self::Prc__Rocket_Launcher => 938,
-            Self::Scatter_Rocket_Array => 939,
-            Self::Smartbomb_Launcher => 940,
-            Self::Mni__Smartbomb_Launcher => 941,
-            Self::Tiamat_Missile_Launcher => 942,
-            Self::Thermoblaster => 943,
-            Self::Micro_nuke_Launcher => 944,
-            Self::Adv__Concussive_RPG => 945,
-            Self::Neutron_Missile_Launcher => 946,
-            Self::Fusion_Bomb_Launcher => 947,
-            Self::Hellfire_Missile_Launcher => 948,
-            Self::Adv__Micro_nuke_Launcher => 949,
-            Self::Tactical_Quantum_Warhead => 950,
-            Self::Vortex_Catalyst_Activator => 951,
-            Self::Chain_Missile_Launcher => 952,
-            Self::Ragnarok_Missile_Launcher => 953,
-            Self::Point_Singularity_Launcher => 954,
-            Self::Gui__Micro_nuke_Launcher => 955,
-            Self::_99_TNT_s_Nukerbomber => 956,
-            Self::Z_bomb_Delivery_System => 957,
-            Self::Sigix_Terminator => 958,
-            Self::Supercharged_Sigix_Terminator => 959,
-            Self::Containment_Facilitator => 960,
-            Self::Detonator => 961,
-            Self::Dirty_Datajack => 962,
-            Self::Splice_Injector => 963,
-            Self::Master_Link => 964,
-            Self::Datajack => 965,
-            Self::Imp__Datajack => 966,
-            Self::Adv__Datajack => 967,
-            Self::Remote_Datajack => 968,
-            Self::Imp__Remote_Datajack => 969,
-            Self::Adv__Remote_Datajack => 970,
-            Self::Exp__Remote_Datajack => 971,
-            Self::Gui__Remote_Datajack => 972,
-            Self::ME_RLN_s_Wand => 973,
-            Self::Field_Lobotomy_Kit => 974,
-            Self::Mining_Laser => 975,
-            Self::Welding_Torch => 976,
-            Self::Plasma_Cutter => 977,
-            Self::Imp__Plasma_Cutter => 978,
-            Self::Exp__Plasma_Cutter => 979,
-            Self::CPS_Tube => 980,
-            Self::Multitool => 981,
-            Self::Flamer => 982,
-            Self::Asb__F_torch => 983,
-            Self::Enh__Flamer => 984,
-            Self::Plasma_Flamer => 985,
-            Self::Asb__P_torch => 986,
-            Self::RU_N14_s_Throwing_Claymores => 987,
-            Self::RU_N14_s_Throwing_Claymores_v3 => 988,
-            Self::AD_0RF_s_Magmablaster => 989,
-            Self::Stasis_Beam => 990,
-            Self::Stasis_Projector => 991,
-            Self::Exp__Stasis_Projector => 992,
-            Self::Molecular_Deconstructor => 993,
-            Self::SE_WE1 => 994,
-            Self::SE_WE2 => 995,
-            Self::SE_WE3 => 996,
-            Self::SE_WE4 => 997,
-            Self::SE_WE5 => 998,
-            Self::Hammer => 999,
-            Self::Mace => 1000,
-            Self::Flail => 1001,
-            Self::Maul => 1002,
-            Self::Asb__Maul => 1003,
-            Self::Hvy__Hammer => 1004,
-            Self::Hvy__Mace => 1005,
-            Self::Hvy__Flail => 1006,
-            Self::Great_Maul => 1007,
-            Self::Powered_Hammer => 1008,
-            Self::Mak__Hammer => 1009,
-            Self::Power_Maul => 1010,
-            Self::Asb__P_maul => 1011,
-            Self::Impact_Mace => 1012,
-            Self::Shock_Maul => 1013,
-            Self::Thunder_Hammer => 1014,
-            Self::Compactor => 1015,
-            Self::Gravity_Flail => 1016,
-            Self::CL_ANK_s_Mallet => 1017,
-            Self::Core_Stripper => 1018,
-            Self::Mining_Claw => 1019,
-            Self::EC_1 => 1020,
-            Self::Katana => 1021,
-            Self::Asb__Blade => 1022,
-            Self::Scythe => 1023,
-            Self::Axe => 1024,
-            Self::Blade_Saw => 1025,
-            Self::EC_3 => 1026,
-            Self::Chainsword => 1027,
-            Self::Ripper => 1028,
-            Self::Mak__Axe => 1029,
-            Self::Mak__Sword => 1030,
-            Self::Great_Axe => 1031,
-            Self::Greatsword => 1032,
-            Self::Power_Sword => 1033,
-            Self::Asb__P_sword => 1034,
-            Self::Falx => 1035,
-            Self::Carbide_Saw => 1036,
-            Self::Segregator => 1037,
-            Self::Tearclaws => 1038,
-            Self::EC_5 => 1039,
-            Self::Phasing_Sabre => 1040,
-            Self::Plasma_Sword => 1041,
-            Self::Ionic_Axe => 1042,
-            Self::Mni__Tearclaws => 1043,
-            Self::Dual_blade_Saw => 1044,
-            Self::Vibroblade => 1045,
-            Self::Molecular_Scythe => 1046,
-            Self::Centrium_Greatsword => 1047,
-            Self::Master_Tearclaws => 1048,
-            Self::Longsword__1 => 1049,
-            Self::EC_7 => 1050,
-            Self::Nanosword => 1051,
-            Self::Particle_Cleaver => 1052,
-            Self::Centrium_Claws => 1053,
-            Self::_1C_UTU_s_Sword__Lootmaker_ => 1054,
-            Self::Vortex_Shredder => 1055,
-            Self::EC_9 => 1056,
-            Self::_1C_UTU_s_Sword__Choppy_ => 1057,
-            Self::Quantum_Blade => 1058,
-            Self::Sigix_Broadsword => 1059,
-            Self::Spear => 1060,
-            Self::Mak__Spear => 1061,
-            Self::CR_A16_s_Pointy_Stick => 1062,
-            Self::Hvy__Pick => 1063,
-            Self::Lance => 1064,
-            Self::Kinetic_Spear => 1065,
-            Self::Force_Lance => 1066,
-            Self::Plasma_Lance => 1067,
-            Self::Enh__Force_Lance => 1068,
-            Self::Vortex_Lance => 1069,
-            Self::CR_A16_s_Behemoth_Slayer => 1070,
-            Self::Blade_Trap => 1071,
-            Self::Segregator_Trap => 1072,
-            Self::Explosive_Trap => 1073,
-            Self::Heavy_Explosive_Trap => 1074,
-            Self::Tiamat_Bomb_Trap => 1075,
-            Self::Fusion_Bomb_Trap => 1076,
-            Self::Hellfire_Trap => 1077,
-            Self::Armageddon_Trap => 1078,
-            Self::Dirty_Bomb_Trap => 1079,
-            Self::EMP_Trap => 1080,
-            Self::Proton_Bomb_Trap => 1081,
-            Self::Tesla_Bomb_Trap => 1082,
-            Self::Gamma_Bomb_Trap => 1083,
-            Self::Shrapnel_Trap => 1084,
-            Self::Piercing_Trap => 1085,
-            Self::Shock_Trap => 1086,
-            Self::EM_Surge_Trap => 1087,
-            Self::Fire_Trap => 1088,
-            Self::Stasis_Trap => 1089,
-            Self::Scrap_Shield_Fragment => 1090,
-            Self::Signal_Generator => 1091,
-            Self::Self_destruct_Interrupter => 1092,
-            Self::Sapper_Charge => 1093,
-            Self::Zhirov_Special => 1094,
-            Self::Deploy_a_Sentry => 1095,
-            Self::GAL_Module => 1096,
-            Self::Portable_AFG => 1097,
-            Self::SGEMP_Prototype => 1098,
-            Self::Terrabomb_Derivative => 1099,
-            Self::Sigix_Containment_Pod => 1100,
-            Self::Sigix_Corpse => 1101,
-            Self::Meganuke => 1102,
-            Self::Gamma_Refractor => 1103,
-        }
-    }
-
-    pub fn name(&self) -> &'static str {
-        match self {
-            Self::Matter => r#"Matter"#,
-            Self::Data_Core => r#"Data Core"#,
-            Self::Derelict_Log => r#"Derelict Log"#,
-            Self::Schematic_Archive => r#"Schematic Archive"#,
-            Self::Imprinter_Data_Core => r#"Imprinter Data Core"#,
-            Self::A2_Data_Core => r#"A2 Data Core"#,
-            Self::A7_Data_Core => r#"A7 Data Core"#,
-            Self::MAIN_C_Data_Core => r#"MAIN.C Data Core"#,
-            Self::Architect_Data_Core => r#"Architect Data Core"#,
-            Self::Scrap => r#"Scrap"#,
-            Self::Protomatter => r#"Protomatter"#,
-            Self::Ion_Engine => r#"Ion Engine"#,
-            Self::Lgt__Ion_Engine => r#"Lgt. Ion Engine"#,
-            Self::Backup_Power_I => r#"Backup Power I"#,
-            Self::Imp__Ion_Engine => r#"Imp. Ion Engine"#,
-            Self::Rnf__Ion_Engine => r#"Rnf. Ion Engine"#,
-            Self::Hvy__Ion_Engine => r#"Hvy. Ion Engine"#,
-            Self::Cld__Ion_Engine => r#"Cld. Ion Engine"#,
-            Self::Sub__Power_Source => r#"Sub. Power Source"#,
-            Self::Deuterium_Engine => r#"Deuterium Engine"#,
-            Self::Lgt__Deuterium_Engine => r#"Lgt. Deuterium Engine"#,
-            Self::Backup_Power_III => r#"Backup Power III"#,
-            Self::Nuclear_Core => r#"Nuclear Core"#,
-            Self::Lgt__Nuclear_Core => r#"Lgt. Nuclear Core"#,
-            Self::Imp__Deuterium_Engine => r#"Imp. Deuterium Engine"#,
-            Self::Rnf__Deuterium_Engine => r#"Rnf. Deuterium Engine"#,
-            Self::Hyb__Deuterium_Engine => r#"Hyb. Deuterium Engine"#,
-            Self::Hvy__Deuterium_Engine => r#"Hvy. Deuterium Engine"#,
-            Self::Cld__Deuterium_Engine => r#"Cld. Deuterium Engine"#,
-            Self::Enh__Nuclear_Core => r#"Enh. Nuclear Core"#,
-            Self::Mic__Nuclear_Core => r#"Mic. Nuclear Core"#,
-            Self::Mak__Power_Source => r#"Mak. Power Source"#,
-            Self::Angular_Momentum_Engine => r#"Angular Momentum Engine"#,
-            Self::Lgt__Angular_Momentum_Engine => r#"Lgt. Angular Momentum Engine"#,
-            Self::F_cell_Engine => r#"F-cell Engine"#,
-            Self::Fission_Core => r#"Fission Core"#,
-            Self::Lgt__Fission_Core => r#"Lgt. Fission Core"#,
-            Self::Hyb__Fission_Core => r#"Hyb. Fission Core"#,
-            Self::Fusion_Compressor => r#"Fusion Compressor"#,
-            Self::Cold_Fusion_Reactor => r#"Cold Fusion Reactor"#,
-            Self::Neutrino_Core => r#"Neutrino Core"#,
-            Self::Lgt__Neutrino_Core => r#"Lgt. Neutrino Core"#,
-            Self::Rnf__Fission_Core => r#"Rnf. Fission Core"#,
-            Self::Enh__Fission_Core => r#"Enh. Fission Core"#,
-            Self::Cld__Fission_Core => r#"Cld. Fission Core"#,
-            Self::Mic__Fission_Core => r#"Mic. Fission Core"#,
-            Self::Fusion_Reactor => r#"Fusion Reactor"#,
-            Self::Antimatter_Reactor => r#"Antimatter Reactor"#,
-            Self::Lgt__Antimatter_Reactor => r#"Lgt. Antimatter Reactor"#,
-            Self::Rnf__Antimatter_Reactor => r#"Rnf. Antimatter Reactor"#,
-            Self::Hyb__Antimatter_Reactor => r#"Hyb. Antimatter Reactor"#,
-            Self::Backup_Power_VII => r#"Backup Power VII"#,
-            Self::Hvy__Antimatter_Reactor => r#"Hvy. Antimatter Reactor"#,
-            Self::Mni__Fusion_Reactor => r#"Mni. Fusion Reactor"#,
-            Self::Imp__Fusion_Compressor => r#"Imp. Fusion Compressor"#,
-            Self::Mic__Neutrino_Core => r#"Mic. Neutrino Core"#,
-            Self::Zio__Light_DM_Reactor => r#"Zio. Light DM Reactor"#,
-            Self::Zio__Heavy_DM_Reactor => r#"Zio. Heavy DM Reactor"#,
-            Self::Particle_Reactor => r#"Particle Reactor"#,
-            Self::Lgt__Particle_Reactor => r#"Lgt. Particle Reactor"#,
-            Self::Mic__Antimatter_Reactor => r#"Mic. Antimatter Reactor"#,
-            Self::Com__Particle_Reactor => r#"Com. Particle Reactor"#,
-            Self::Cld__Antimatter_Reactor => r#"Cld. Antimatter Reactor"#,
-            Self::Graviton_Reactor => r#"Graviton Reactor"#,
-            Self::Asb__Flux_Generator => r#"Asb. Flux Generator"#,
-            Self::Quantum_Reactor => r#"Quantum Reactor"#,
-            Self::Lgt__Quantum_Reactor => r#"Lgt. Quantum Reactor"#,
-            Self::Rnf__Quantum_Reactor => r#"Rnf. Quantum Reactor"#,
-            Self::Imp__Quantum_Reactor => r#"Imp. Quantum Reactor"#,
-            Self::Zero_point_Reactor => r#"Zero-point Reactor"#,
-            Self::Vortex_Chain_Reactor => r#"Vortex Chain Reactor"#,
-            Self::Singularity_Reactor => r#"Singularity Reactor"#,
-            Self::Matter_Drive => r#"Matter Drive"#,
-            Self::Meta_Core => r#"Meta Core"#,
-            Self::SE_PO1 => r#"SE_PO1"#,
-            Self::Lgt__Treads => r#"Lgt. Treads"#,
-            Self::Imp__Treads => r#"Imp. Treads"#,
-            Self::Spk__Treads => r#"Spk. Treads"#,
-            Self::Arm__Treads => r#"Arm. Treads"#,
-            Self::Adv__Treads => r#"Adv. Treads"#,
-            Self::Med__Treads => r#"Med. Treads"#,
-            Self::Imp__Medium_Treads => r#"Imp. Medium Treads"#,
-            Self::Spk__Medium_Treads => r#"Spk. Medium Treads"#,
-            Self::Arm__Medium_Treads => r#"Arm. Medium Treads"#,
-            Self::Hvy__Treads => r#"Hvy. Treads"#,
-            Self::Adv__Medium_Treads => r#"Adv. Medium Treads"#,
-            Self::Enh__Armored_Medium_Treads => r#"Enh. Armored Medium Treads"#,
-            Self::Imp__Heavy_Treads => r#"Imp. Heavy Treads"#,
-            Self::Hvy__Siege_Treads => r#"Hvy. Siege Treads"#,
-            Self::Spk__Heavy_Treads => r#"Spk. Heavy Treads"#,
-            Self::Biometal_Medium_Treads => r#"Biometal Medium Treads"#,
-            Self::Arm__Heavy_Treads => r#"Arm. Heavy Treads"#,
-            Self::Adv__Heavy_Treads => r#"Adv. Heavy Treads"#,
-            Self::Imp__Heavy_Siege_Treads => r#"Imp. Heavy Siege Treads"#,
-            Self::Centrium_Heavy_Treads => r#"Centrium Heavy Treads"#,
-            Self::Megatreads => r#"Megatreads"#,
-            Self::Biometal_Heavy_Treads => r#"Biometal Heavy Treads"#,
-            Self::Exp__Biometal_Heavy_Treads => r#"Exp. Biometal Heavy Treads"#,
-            Self::Enh__Armored_Heavy_Treads => r#"Enh. Armored Heavy Treads"#,
-            Self::Adv__Heavy_Siege_Treads => r#"Adv. Heavy Siege Treads"#,
-            Self::Hdn__Centrium_Heavy_Treads => r#"Hdn. Centrium Heavy Treads"#,
-            Self::Exp__Biometal_Medium_Treads => r#"Exp. Biometal Medium Treads"#,
-            Self::Aluminum_Leg => r#"Aluminum Leg"#,
-            Self::Imp__Aluminum_Leg => r#"Imp. Aluminum Leg"#,
-            Self::Carbon_fiber_Leg => r#"Carbon-fiber Leg"#,
-            Self::Titanium_Leg => r#"Titanium Leg"#,
-            Self::VSS_Leg => r#"VSS Leg"#,
-            Self::Imp__Carbon_fiber_Leg => r#"Imp. Carbon-fiber Leg"#,
-            Self::Imp__Titanium_Leg => r#"Imp. Titanium Leg"#,
-            Self::Lgt__Armored_Exoskeleton => r#"Lgt. Armored Exoskeleton"#,
-            Self::Mak__Leg => r#"Mak. Leg"#,
-            Self::Flexi_carbon_Leg => r#"Flexi-carbon Leg"#,
-            Self::Arm__Leg => r#"Arm. Leg"#,
-            Self::Imp__VSS_Leg => r#"Imp. VSS Leg"#,
-            Self::Zio__Composite_Leg_I => r#"Zio. Composite Leg I"#,
-            Self::Med__Armored_Exoskeleton => r#"Med. Armored Exoskeleton"#,
-            Self::Enh__Flexi_carbon_Leg => r#"Enh. Flexi-carbon Leg"#,
-            Self::Enh__Armored_Leg => r#"Enh. Armored Leg"#,
-            Self::Arachnoskeleton => r#"Arachnoskeleton"#,
-            Self::Myomer_Leg => r#"Myomer Leg"#,
-            Self::Adv__VSS_Leg => r#"Adv. VSS Leg"#,
-            Self::Adv__Myomer_Leg => r#"Adv. Myomer Leg"#,
-            Self::Exp__Flexi_carbon_Leg => r#"Exp. Flexi-carbon Leg"#,
-            Self::Zio__Composite_Leg_II => r#"Zio. Composite Leg II"#,
-            Self::Cargo_Legs => r#"Cargo Legs"#,
-            Self::Etherial_Tendrils => r#"Etherial Tendrils"#,
-            Self::Biometal_Leg => r#"Biometal Leg"#,
-            Self::Hvy__Armored_Exoskeleton => r#"Hvy. Armored Exoskeleton"#,
-            Self::Enh__Biometal_Leg => r#"Enh. Biometal Leg"#,
-            Self::Exp__Myomer_Leg => r#"Exp. Myomer Leg"#,
-            Self::Tripod_Weapons_Platform => r#"Tripod Weapons Platform"#,
-            Self::T_R_O_L_L__Exoskeleton => r#"T.R.O.L.L. Exoskeleton"#,
-            Self::Asb__Alloy_Leg => r#"Asb. Alloy Leg"#,
-            Self::Centrium_Leg => r#"Centrium Leg"#,
-            Self::Hdn__Centrium_Leg => r#"Hdn. Centrium Leg"#,
-            Self::Exp__Biometal_Leg => r#"Exp. Biometal Leg"#,
-            Self::Zio__Supercomposite_Leg => r#"Zio. Supercomposite Leg"#,
-            Self::Potential_Leg => r#"Potential Leg"#,
-            Self::Myomer_Exoskeleton => r#"Myomer Exoskeleton"#,
-            Self::Sigix_Exoskeleton => r#"Sigix Exoskeleton"#,
-            Self::SE_PR1 => r#"SE_PR1"#,
-            Self::Wheel => r#"Wheel"#,
-            Self::Com__Wheel => r#"Com. Wheel"#,
-            Self::Arm__Wheel => r#"Arm. Wheel"#,
-            Self::Sub__Monowheel => r#"Sub. Monowheel"#,
-            Self::Arm__Huge_Wheel => r#"Arm. Huge Wheel"#,
-            Self::Centrium_Wheel => r#"Centrium Wheel"#,
-            Self::Hdn__Centrium_Wheel => r#"Hdn. Centrium Wheel"#,
-            Self::Hover_Unit => r#"Hover Unit"#,
-            Self::Backup_Propulsion_I => r#"Backup Propulsion I"#,
-            Self::Imp__Hover_Unit => r#"Imp. Hover Unit"#,
-            Self::Airjet => r#"Airjet"#,
-            Self::Aerolev_Unit => r#"Aerolev Unit"#,
-            Self::Asb__Hover_Unit => r#"Asb. Hover Unit"#,
-            Self::Backup_Propulsion_III => r#"Backup Propulsion III"#,
-            Self::Gravmag_System => r#"Gravmag System"#,
-            Self::Asb__Hover_System => r#"Asb. Hover System"#,
-            Self::Imp__Airjet => r#"Imp. Airjet"#,
-            Self::Cld__Airjet => r#"Cld. Airjet"#,
-            Self::Imp__Gravmag_System => r#"Imp. Gravmag System"#,
-            Self::Cld__Aerolev_Unit => r#"Cld. Aerolev Unit"#,
-            Self::Cmb__Airjet => r#"Cmb. Airjet"#,
-            Self::Gravmag_Array => r#"Gravmag Array"#,
-            Self::Mak__Hover_Unit => r#"Mak. Hover Unit"#,
-            Self::Zio__Glidesys_I => r#"Zio. Glidesys I"#,
-            Self::Antigrav_System => r#"Antigrav System"#,
-            Self::Gyrokinetic_Inverter => r#"Gyrokinetic Inverter"#,
-            Self::Linear_Gravjet => r#"Linear Gravjet"#,
-            Self::Cld__Gravmag_System => r#"Cld. Gravmag System"#,
-            Self::Cmb__Gravmag_System => r#"Cmb. Gravmag System"#,
-            Self::Imp__Antigrav_System => r#"Imp. Antigrav System"#,
-            Self::Imp__Gyrokinetic_Inverter => r#"Imp. Gyrokinetic Inverter"#,
-            Self::Imp__Linear_Gravjet => r#"Imp. Linear Gravjet"#,
-            Self::Backup_Propulsion_VII => r#"Backup Propulsion VII"#,
-            Self::Zio__Glidesys_II => r#"Zio. Glidesys II"#,
-            Self::Cld__Antigrav_System => r#"Cld. Antigrav System"#,
-            Self::Cmb__Antigrav_System => r#"Cmb. Antigrav System"#,
-            Self::Antigrav_Array => r#"Antigrav Array"#,
-            Self::Exp__Gyrokinetic_Inverter => r#"Exp. Gyrokinetic Inverter"#,
-            Self::Cld__Linear_Gravjet => r#"Cld. Linear Gravjet"#,
-            Self::Cmb__Linear_Gravjet => r#"Cmb. Linear Gravjet"#,
-            Self::Flight_Unit => r#"Flight Unit"#,
-            Self::VTOL_Module => r#"VTOL Module"#,
-            Self::Imp__Flight_Unit => r#"Imp. Flight Unit"#,
-            Self::Cesium_ion_Thruster => r#"Cesium-ion Thruster"#,
-            Self::Surge_Thruster => r#"Surge Thruster"#,
-            Self::Imp__VTOL_Module => r#"Imp. VTOL Module"#,
-            Self::Xenon_Bombardment_Thruster => r#"Xenon Bombardment Thruster"#,
-            Self::Imp__Cesium_ion_Thruster => r#"Imp. Cesium-ion Thruster"#,
-            Self::Cld__VTOL_Module => r#"Cld. VTOL Module"#,
-            Self::Mak__Flight_Unit => r#"Mak. Flight Unit"#,
-            Self::Nuclear_Pulse_Thruster => r#"Nuclear Pulse Thruster"#,
-            Self::Imp__Surge_Thruster => r#"Imp. Surge Thruster"#,
-            Self::Mni__Nuclear_Pulse_Thruster => r#"Mni. Nuclear Pulse Thruster"#,
-            Self::Cld__Cesium_ion_Thruster => r#"Cld. Cesium-ion Thruster"#,
-            Self::Biomechanical_Wings => r#"Biomechanical Wings"#,
-            Self::Field_Propulsion_Drive => r#"Field Propulsion Drive"#,
-            Self::Cld__Nuclear_Pulse_Thruster => r#"Cld. Nuclear Pulse Thruster"#,
-            Self::Exp__Cesium_ion_Thruster => r#"Exp. Cesium-ion Thruster"#,
-            Self::Nuclear_Pulse_Array => r#"Nuclear Pulse Array"#,
-            Self::Mak__Microthruster => r#"Mak. Microthruster"#,
-            Self::Impulse_Thruster => r#"Impulse Thruster"#,
-            Self::Adv__Surge_Thruster => r#"Adv. Surge Thruster"#,
-            Self::Cld__Field_Propulsion_Drive => r#"Cld. Field Propulsion Drive"#,
-            Self::Field_Propulsion_Array => r#"Field Propulsion Array"#,
-            Self::Diametric_Drive => r#"Diametric Drive"#,
-            Self::Flying_Fortress_v7 => r#"Flying Fortress v7"#,
-            Self::T_thruster => r#"T-thruster"#,
-            Self::Integrated_Tracker_Drive => r#"Integrated Tracker Drive"#,
-            Self::Etherial_Propulsion => r#"Etherial Propulsion"#,
-            Self::Q_thruster => r#"Q-thruster"#,
-            Self::Electron_Diverter => r#"Electron Diverter"#,
-            Self::Imp__Diametric_Drive => r#"Imp. Diametric Drive"#,
-            Self::Cld__Impulse_Thruster => r#"Cld. Impulse Thruster"#,
-            Self::Impulse_Thruster_Array => r#"Impulse Thruster Array"#,
-            Self::Flightbrick => r#"Flightbrick"#,
-            Self::Imp__Q_thruster => r#"Imp. Q-thruster"#,
-            Self::Cld__Q_thruster => r#"Cld. Q-thruster"#,
-            Self::Exp__Q_thruster => r#"Exp. Q-thruster"#,
-            Self::Scrap_Engine => r#"Scrap Engine"#,
-            Self::Arm__Scrap_Engine => r#"Arm. Scrap Engine"#,
-            Self::Desublimator => r#"Desublimator"#,
-            Self::Power_Amplifier => r#"Power Amplifier"#,
-            Self::Adv__Power_Amplifier => r#"Adv. Power Amplifier"#,
-            Self::Exp__Power_Amplifier => r#"Exp. Power Amplifier"#,
-            Self::Thermal_Generator => r#"Thermal Generator"#,
-            Self::Imp__Thermal_Generator => r#"Imp. Thermal Generator"#,
-            Self::Adv__Thermal_Generator => r#"Adv. Thermal Generator"#,
-            Self::Exp__Thermal_Generator => r#"Exp. Thermal Generator"#,
-            Self::Cryofiber_Web => r#"Cryofiber Web"#,
-            Self::Imp__Cryofiber_Web => r#"Imp. Cryofiber Web"#,
-            Self::Adv__Cryofiber_Web => r#"Adv. Cryofiber Web"#,
-            Self::Exp__Cryofiber_Web => r#"Exp. Cryofiber Web"#,
-            Self::Heat_Sink => r#"Heat Sink"#,
-            Self::Imp__Heat_Sink => r#"Imp. Heat Sink"#,
-            Self::Adv__Heat_Sink => r#"Adv. Heat Sink"#,
-            Self::Exp__Heat_Sink => r#"Exp. Heat Sink"#,
-            Self::Cooling_System => r#"Cooling System"#,
-            Self::Imp__Cooling_System => r#"Imp. Cooling System"#,
-            Self::Adv__Cooling_System => r#"Adv. Cooling System"#,
-            Self::Exp__Cooling_System => r#"Exp. Cooling System"#,
-            Self::Coolant_Network => r#"Coolant Network"#,
-            Self::Asb__Nanovents => r#"Asb. Nanovents"#,
-            Self::_2N_1CE_s_Frost_Array => r#"2N-1CE's Frost Array"#,
-            Self::Disposable_Heat_Sink => r#"Disposable Heat Sink"#,
-            Self::Coolant_Injector => r#"Coolant Injector"#,
-            Self::Imp__Coolant_Injector => r#"Imp. Coolant Injector"#,
-            Self::Adv__Coolant_Injector => r#"Adv. Coolant Injector"#,
-            Self::Exp__Coolant_Injector => r#"Exp. Coolant Injector"#,
-            Self::Sml__Storage_Unit => r#"Sml. Storage Unit"#,
-            Self::Med__Storage_Unit => r#"Med. Storage Unit"#,
-            Self::Lrg__Storage_Unit => r#"Lrg. Storage Unit"#,
-            Self::Hcp__Storage_Unit => r#"Hcp. Storage Unit"#,
-            Self::Huge_Storage_Unit => r#"Huge Storage Unit"#,
-            Self::Cargo_Storage_Unit => r#"Cargo Storage Unit"#,
-            Self::Humpback => r#"Humpback"#,
-            Self::Lightpack_2_0 => r#"Lightpack 2.0"#,
-            Self::Sml__Battery => r#"Sml. Battery"#,
-            Self::Med__Battery => r#"Med. Battery"#,
-            Self::Lrg__Battery => r#"Lrg. Battery"#,
-            Self::Hcp__Battery => r#"Hcp. Battery"#,
-            Self::Com__Battery => r#"Com. Battery"#,
-            Self::Energy_Well => r#"Energy Well"#,
-            Self::Imp__Energy_Well => r#"Imp. Energy Well"#,
-            Self::Adv__Energy_Well => r#"Adv. Energy Well"#,
-            Self::Exp__Energy_Well => r#"Exp. Energy Well"#,
-            Self::Zio__Biocell => r#"Zio. Biocell"#,
-            Self::Asb__Biocell_Array => r#"Asb. Biocell Array"#,
-            Self::Zio__Biocell_Array => r#"Zio. Biocell Array"#,
-            Self::Superbattery => r#"Superbattery"#,
-            Self::Sml__Matter_Pod => r#"Sml. Matter Pod"#,
-            Self::Med__Matter_Pod => r#"Med. Matter Pod"#,
-            Self::Lrg__Matter_Pod => r#"Lrg. Matter Pod"#,
-            Self::Hcp__Matter_Pod => r#"Hcp. Matter Pod"#,
-            Self::Com__Matter_Pod => r#"Com. Matter Pod"#,
-            Self::Matter_Compressor => r#"Matter Compressor"#,
-            Self::Imp__Matter_Compressor => r#"Imp. Matter Compressor"#,
-            Self::Adv__Matter_Compressor => r#"Adv. Matter Compressor"#,
-            Self::Exp__Matter_Compressor => r#"Exp. Matter Compressor"#,
-            Self::YI_UF0_s_Bottomless_Matter_Pit => r#"YI-UF0's Bottomless Matter Pit"#,
-            Self::Weight_Redist__System => r#"Weight Redist. System"#,
-            Self::Adv__Weight_Redist__System => r#"Adv. Weight Redist. System"#,
-            Self::Gravity_Neutralizer => r#"Gravity Neutralizer"#,
-            Self::Adv__Gravity_Neutralizer => r#"Adv. Gravity Neutralizer"#,
-            Self::Quantum_Shading_Machine => r#"Quantum Shading Machine"#,
-            Self::Asb__Suspension_Frame => r#"Asb. Suspension Frame"#,
-            Self::Adv__Quantum_Shading_Machine => r#"Adv. Quantum Shading Machine"#,
-            Self::Asb__Metafiber_Network => r#"Asb. Metafiber Network"#,
-            Self::Zio__Metafield_Generator => r#"Zio. Metafield Generator"#,
-            Self::ST_Field_Compressor => r#"ST Field Compressor"#,
-            Self::Structural_Scanner => r#"Structural Scanner"#,
-            Self::Trap_Scanner => r#"Trap Scanner"#,
-            Self::Imp__Trap_Scanner => r#"Imp. Trap Scanner"#,
-            Self::Mak__Trap_Scanner => r#"Mak. Trap Scanner"#,
-            Self::Adv__Trap_Scanner => r#"Adv. Trap Scanner"#,
-            Self::Machine_Analyzer => r#"Machine Analyzer"#,
-            Self::Triangulator => r#"Triangulator"#,
-            Self::Visual_Processing_Unit => r#"Visual Processing Unit"#,
-            Self::Sub__Optics => r#"Sub. Optics"#,
-            Self::Adv__Visual_Processing_Unit => r#"Adv. Visual Processing Unit"#,
-            Self::Enh__Optical_Array => r#"Enh. Optical Array"#,
-            Self::Exp__Optical_Array => r#"Exp. Optical Array"#,
-            Self::Spectral_Analyzer => r#"Spectral Analyzer"#,
-            Self::Sensor_Array => r#"Sensor Array"#,
-            Self::Imp__Sensor_Array => r#"Imp. Sensor Array"#,
-            Self::Adv__Sensor_Array => r#"Adv. Sensor Array"#,
-            Self::Lrn__Sensor_Array => r#"Lrn. Sensor Array"#,
-            Self::Mak__Sensor_Array => r#"Mak. Sensor Array"#,
-            Self::Exp__Sensor_Array => r#"Exp. Sensor Array"#,
-            Self::Signal_Interpreter => r#"Signal Interpreter"#,
-            Self::Imp__Signal_Interpreter => r#"Imp. Signal Interpreter"#,
-            Self::Adv__Signal_Interpreter => r#"Adv. Signal Interpreter"#,
-            Self::Exp__Signal_Interpreter => r#"Exp. Signal Interpreter"#,
-            Self::Active_Sensor_Suite => r#"Active Sensor Suite"#,
-            Self::Terrain_Scanner => r#"Terrain Scanner"#,
-            Self::Imp__Terrain_Scanner => r#"Imp. Terrain Scanner"#,
-            Self::Adv__Terrain_Scanner => r#"Adv. Terrain Scanner"#,
-            Self::Mak__Terrain_Scanner => r#"Mak. Terrain Scanner"#,
-            Self::Lrn__Terrain_Scanner => r#"Lrn. Terrain Scanner"#,
-            Self::Exp__Terrain_Scanner => r#"Exp. Terrain Scanner"#,
-            Self::Surveybot_24_Scanner => r#"Surveybot 24 Scanner"#,
-            Self::Terrain_Scan_Processor => r#"Terrain Scan Processor"#,
-            Self::Imp__Terrain_Scan_Processor => r#"Imp. Terrain Scan Processor"#,
-            Self::Mak__Terrain_Scan_Processor => r#"Mak. Terrain Scan Processor"#,
-            Self::Adv__Terrain_Scan_Processor => r#"Adv. Terrain Scan Processor"#,
-            Self::Exp__Terrain_Scan_Processor => r#"Exp. Terrain Scan Processor"#,
-            Self::Surveybot_24_Chip => r#"Surveybot 24 Chip"#,
-            Self::Seismic_Detector => r#"Seismic Detector"#,
-            Self::Transport_Network_Coupler => r#"Transport Network Coupler"#,
-            Self::Modified_TNC => r#"Modified TNC"#,
-            Self::Encrypted_Comm_Array => r#"Encrypted Comm Array"#,
-            Self::_0b10_Decoder_Chip__Generic_ => r#"0b10 Decoder Chip [Generic]"#,
-            Self::_0b10_Decoder_Chip__Looter_ => r#"0b10 Decoder Chip [Looter]"#,
-            Self::_0b10_Decoder_Chip__Scout_ => r#"0b10 Decoder Chip [Scout]"#,
-            Self::_0b10_Decoder_Chip__Skirmisher_ => r#"0b10 Decoder Chip [Skirmisher]"#,
-            Self::_0b10_Alert_Chip => r#"0b10 Alert Chip"#,
-            Self::Salvage_Targeting_Computer => r#"Salvage Targeting Computer"#,
-            Self::Imp__Salvage_Targeting_Computer => r#"Imp. Salvage Targeting Computer"#,
-            Self::Mak__Salvage_Targeting_Computer => r#"Mak. Salvage Targeting Computer"#,
-            Self::Adv__Salvage_Targeting_Computer => r#"Adv. Salvage Targeting Computer"#,
-            Self::Asb__Combat_Suite => r#"Asb. Combat Suite"#,
-            Self::Targeting_Computer => r#"Targeting Computer"#,
-            Self::Imp__Targeting_Computer => r#"Imp. Targeting Computer"#,
-            Self::Adv__Targeting_Computer => r#"Adv. Targeting Computer"#,
-            Self::Exp__Targeting_Computer => r#"Exp. Targeting Computer"#,
-            Self::Multitargeting_Array => r#"Multitargeting Array"#,
-            Self::Tactical_Coordination_Suite => r#"Tactical Coordination Suite"#,
-            Self::Melee_Analysis_Suite => r#"Melee Analysis Suite"#,
-            Self::Imp__Melee_Analysis_Suite => r#"Imp. Melee Analysis Suite"#,
-            Self::Adv__Melee_Analysis_Suite => r#"Adv. Melee Analysis Suite"#,
-            Self::Exp__Melee_Analysis_Suite => r#"Exp. Melee Analysis Suite"#,
-            Self::Launcher_Guidance_Computer => r#"Launcher Guidance Computer"#,
-            Self::Imp__Launcher_Guidance_Computer => r#"Imp. Launcher Guidance Computer"#,
-            Self::Adv__Launcher_Guidance_Computer => r#"Adv. Launcher Guidance Computer"#,
-            Self::Weapon_Mount => r#"Weapon Mount"#,
-            Self::Target_Analyzer => r#"Target Analyzer"#,
-            Self::Imp__Target_Analyzer => r#"Imp. Target Analyzer"#,
-            Self::Adv__Target_Analyzer => r#"Adv. Target Analyzer"#,
-            Self::Exp__Target_Analyzer => r#"Exp. Target Analyzer"#,
-            Self::Core_Analyzer => r#"Core Analyzer"#,
-            Self::Exp__Core_Analyzer => r#"Exp. Core Analyzer"#,
-            Self::Armor_Integrity_Analyzer => r#"Armor Integrity Analyzer"#,
-            Self::Imp__Armor_Integrity_Analyzer => r#"Imp. Armor Integrity Analyzer"#,
-            Self::Exp__Armor_Integrity_Analyzer => r#"Exp. Armor Integrity Analyzer"#,
-            Self::Recoil_Stabilizer => r#"Recoil Stabilizer"#,
-            Self::Recoil_Nullifier => r#"Recoil Nullifier"#,
-            Self::Matter_Filter => r#"Matter Filter"#,
-            Self::Prc__Matter_Filter => r#"Prc. Matter Filter"#,
-            Self::Energy_Filter => r#"Energy Filter"#,
-            Self::Prc__Energy_Filter => r#"Prc. Energy Filter"#,
-            Self::Particle_Charger => r#"Particle Charger"#,
-            Self::Imp__Particle_Charger => r#"Imp. Particle Charger"#,
-            Self::Adv__Particle_Charger => r#"Adv. Particle Charger"#,
-            Self::Particle_Accelerator => r#"Particle Accelerator"#,
-            Self::Imp__Particle_Accelerator => r#"Imp. Particle Accelerator"#,
-            Self::Adv__Particle_Accelerator => r#"Adv. Particle Accelerator"#,
-            Self::Kinecellerator => r#"Kinecellerator"#,
-            Self::Imp__Kinecellerator => r#"Imp. Kinecellerator"#,
-            Self::Adv__Kinecellerator => r#"Adv. Kinecellerator"#,
-            Self::Heavy_Servo_Lattice => r#"Heavy Servo Lattice"#,
-            Self::VL_GR5_s_Timing_Chip => r#"VL-GR5's Timing Chip"#,
-            Self::Launcher_Loader => r#"Launcher Loader"#,
-            Self::Quantum_Capacitor => r#"Quantum Capacitor"#,
-            Self::Weapon_Cycler => r#"Weapon Cycler"#,
-            Self::Imp__Weapon_Cycler => r#"Imp. Weapon Cycler"#,
-            Self::Adv__Weapon_Cycler => r#"Adv. Weapon Cycler"#,
-            Self::Exp__Weapon_Cycler => r#"Exp. Weapon Cycler"#,
-            Self::Microactuators => r#"Microactuators"#,
-            Self::Nanoactuators => r#"Nanoactuators"#,
-            Self::Femtoactuators => r#"Femtoactuators"#,
-            Self::Actuator_Array => r#"Actuator Array"#,
-            Self::Imp__Actuator_Array => r#"Imp. Actuator Array"#,
-            Self::Adv__Actuator_Array => r#"Adv. Actuator Array"#,
-            Self::Exp__Actuator_Array => r#"Exp. Actuator Array"#,
-            Self::Force_Booster => r#"Force Booster"#,
-            Self::Imp__Force_Booster => r#"Imp. Force Booster"#,
-            Self::Adv__Force_Booster => r#"Adv. Force Booster"#,
-            Self::Stasis_Canceller => r#"Stasis Canceller"#,
-            Self::Transmission_Jammer => r#"Transmission Jammer"#,
-            Self::Mak__Transmission_Jammer => r#"Mak. Transmission Jammer"#,
-            Self::Imp__Transmission_Jammer => r#"Imp. Transmission Jammer"#,
-            Self::Adv__Transmission_Jammer => r#"Adv. Transmission Jammer"#,
-            Self::Exp__Transmission_Jammer => r#"Exp. Transmission Jammer"#,
-            Self::ECM_Suite => r#"ECM Suite"#,
-            Self::Mak__ECM_Suite => r#"Mak. ECM Suite"#,
-            Self::Adv__ECM_Suite => r#"Adv. ECM Suite"#,
-            Self::Exp__ECM_Suite => r#"Exp. ECM Suite"#,
-            Self::Active_Sensor_Spoofer => r#"Active Sensor Spoofer"#,
-            Self::Reaction_Control_System => r#"Reaction Control System"#,
-            Self::Imp__Reaction_Control_System => r#"Imp. Reaction Control System"#,
-            Self::Adv__Reaction_Control_System => r#"Adv. Reaction Control System"#,
-            Self::Exp__Reaction_Control_System => r#"Exp. Reaction Control System"#,
-            Self::Phase_Shifter => r#"Phase Shifter"#,
-            Self::Imp__Phase_Shifter => r#"Imp. Phase Shifter"#,
-            Self::Adv__Phase_Shifter => r#"Adv. Phase Shifter"#,
-            Self::Exp__Phase_Shifter => r#"Exp. Phase Shifter"#,
-            Self::Cloaking_Device => r#"Cloaking Device"#,
-            Self::Mak__Cloaking_Device => r#"Mak. Cloaking Device"#,
-            Self::Imp__Cloaking_Device => r#"Imp. Cloaking Device"#,
-            Self::Adv__Cloaking_Device => r#"Adv. Cloaking Device"#,
-            Self::Exp__Cloaking_Device => r#"Exp. Cloaking Device"#,
-            Self::Cloak_of_Protection => r#"Cloak of Protection"#,
-            Self::_5H_AD0_s_Cloak => r#"5H-AD0's Cloak"#,
-            Self::Core_Shielding => r#"Core Shielding"#,
-            Self::Imp__Core_Shielding => r#"Imp. Core Shielding"#,
-            Self::Exp__Core_Shielding => r#"Exp. Core Shielding"#,
-            Self::Power_Shielding => r#"Power Shielding"#,
-            Self::Imp__Power_Shielding => r#"Imp. Power Shielding"#,
-            Self::Exp__Power_Shielding => r#"Exp. Power Shielding"#,
-            Self::Propulsion_Shielding => r#"Propulsion Shielding"#,
-            Self::Imp__Propulsion_Shielding => r#"Imp. Propulsion Shielding"#,
-            Self::Exp__Propulsion_Shielding => r#"Exp. Propulsion Shielding"#,
-            Self::Utility_Shielding => r#"Utility Shielding"#,
-            Self::Imp__Utility_Shielding => r#"Imp. Utility Shielding"#,
-            Self::Exp__Utility_Shielding => r#"Exp. Utility Shielding"#,
-            Self::Weapon_Shielding => r#"Weapon Shielding"#,
-            Self::Imp__Weapon_Shielding => r#"Imp. Weapon Shielding"#,
-            Self::Exp__Weapon_Shielding => r#"Exp. Weapon Shielding"#,
-            Self::Zio__Weapon_Casing => r#"Zio. Weapon Casing"#,
-            Self::Backup_Plating_I => r#"Backup Plating I"#,
-            Self::Backup_Plating_III => r#"Backup Plating III"#,
-            Self::Backup_Plating_VII => r#"Backup Plating VII"#,
-            Self::Lgt__Armor_Plating => r#"Lgt. Armor Plating"#,
-            Self::Med__Armor_Plating => r#"Med. Armor Plating"#,
-            Self::Hvy__Armor_Plating => r#"Hvy. Armor Plating"#,
-            Self::Zio__Light_Carapace => r#"Zio. Light Carapace"#,
-            Self::Zio__Heavy_Carapace => r#"Zio. Heavy Carapace"#,
-            Self::Hrd__Light_Armor_Plating => r#"Hrd. Light Armor Plating"#,
-            Self::Hrd__Medium_Armor_Plating => r#"Hrd. Medium Armor Plating"#,
-            Self::Hrd__Heavy_Armor_Plating => r#"Hrd. Heavy Armor Plating"#,
-            Self::Imp__Light_Armor_Plating => r#"Imp. Light Armor Plating"#,
-            Self::Imp__Medium_Armor_Plating => r#"Imp. Medium Armor Plating"#,
-            Self::Imp__Heavy_Armor_Plating => r#"Imp. Heavy Armor Plating"#,
-            Self::Mak__Light_Armor_Plating => r#"Mak. Light Armor Plating"#,
-            Self::Lyr__Light_Armor_Plating => r#"Lyr. Light Armor Plating"#,
-            Self::Lyr__Medium_Armor_Plating => r#"Lyr. Medium Armor Plating"#,
-            Self::Lyr__Heavy_Armor_Plating => r#"Lyr. Heavy Armor Plating"#,
-            Self::Gun_Armor => r#"Gun Armor"#,
-            Self::Centrium_Light_Armor_Plating => r#"Centrium Light Armor Plating"#,
-            Self::Centrium_Medium_Armor_Plating => r#"Centrium Medium Armor Plating"#,
-            Self::Centrium_Heavy_Armor_Plating => r#"Centrium Heavy Armor Plating"#,
-            Self::Centrium_Linked_Plating => r#"Centrium Linked Plating"#,
-            Self::Reactive_Plating => r#"Reactive Plating"#,
-            Self::Med__Reactive_Plating => r#"Med. Reactive Plating"#,
-            Self::Mak__Kinetic_Plating => r#"Mak. Kinetic Plating"#,
-            Self::Hvy__Reactive_Plating => r#"Hvy. Reactive Plating"#,
-            Self::Reflective_Plating => r#"Reflective Plating"#,
-            Self::Med__Reflective_Plating => r#"Med. Reflective Plating"#,
-            Self::Mak__Thermal_Plating => r#"Mak. Thermal Plating"#,
-            Self::Hvy__Reflective_Plating => r#"Hvy. Reflective Plating"#,
-            Self::_8R_AWN_s_Armor_TH => r#"8R-AWN's Armor/TH"#,
-            Self::_8R_AWN_s_Armor_EX => r#"8R-AWN's Armor/EX"#,
-            Self::Insulated_Plating => r#"Insulated Plating"#,
-            Self::Med__Insulated_Plating => r#"Med. Insulated Plating"#,
-            Self::Hvy__Insulated_Plating => r#"Hvy. Insulated Plating"#,
-            Self::Damper_Plating => r#"Damper Plating"#,
-            Self::Zio__Shade_Carapace => r#"Zio. Shade Carapace"#,
-            Self::Zio__Shade_Armor => r#"Zio. Shade Armor"#,
-            Self::Asb__Alloy_Armor => r#"Asb. Alloy Armor"#,
-            Self::Mak__Ablative_Armor => r#"Mak. Ablative Armor"#,
-            Self::Active_Cooling_Armor => r#"Active Cooling Armor"#,
-            Self::Scrap_Shield => r#"Scrap Shield"#,
-            Self::Powered_Armor => r#"Powered Armor"#,
-            Self::Imp__Powered_Armor => r#"Imp. Powered Armor"#,
-            Self::Adv__Powered_Armor => r#"Adv. Powered Armor"#,
-            Self::Exp__Powered_Armor => r#"Exp. Powered Armor"#,
-            Self::_1C_UTU_s_Buckler => r#"1C-UTU's Buckler"#,
-            Self::Lgt__Regenerative_Plating => r#"Lgt. Regenerative Plating"#,
-            Self::Med__Regenerative_Plating => r#"Med. Regenerative Plating"#,
-            Self::Hvy__Regenerative_Plating => r#"Hvy. Regenerative Plating"#,
-            Self::SHELL_Armor => r#"SHELL Armor"#,
-            Self::Phase_Armor => r#"Phase Armor"#,
-            Self::Graphene_Brace => r#"Graphene Brace"#,
-            Self::Focal_Shield => r#"Focal Shield"#,
-            Self::Imp__Focal_Shield => r#"Imp. Focal Shield"#,
-            Self::Adv__Focal_Shield => r#"Adv. Focal Shield"#,
-            Self::Exp__Focal_Shield => r#"Exp. Focal Shield"#,
-            Self::Thermal_Shield => r#"Thermal Shield"#,
-            Self::Imp__Thermal_Shield => r#"Imp. Thermal Shield"#,
-            Self::Adv__Thermal_Shield => r#"Adv. Thermal Shield"#,
-            Self::Exp__Thermal_Shield => r#"Exp. Thermal Shield"#,
-            Self::Thermal_Barrier => r#"Thermal Barrier"#,
-            Self::Beam_Splitter => r#"Beam Splitter"#,
-            Self::Thermal_Defense_Suite => r#"Thermal Defense Suite"#,
-            Self::Imp__Thermal_Defense_Suite => r#"Imp. Thermal Defense Suite"#,
-            Self::Adv__Thermal_Defense_Suite => r#"Adv. Thermal Defense Suite"#,
-            Self::Exp__Thermal_Defense_Suite => r#"Exp. Thermal Defense Suite"#,
-            Self::Shock_Absorption_System => r#"Shock Absorption System"#,
-            Self::Imp__Shock_Absorption_System => r#"Imp. Shock Absorption System"#,
-            Self::Exp__Shock_Absorption_System => r#"Exp. Shock Absorption System"#,
-            Self::EM_Disruption_Field => r#"EM Disruption Field"#,
-            Self::Adv__EM_Disruption_Field => r#"Adv. EM Disruption Field"#,
-            Self::Exp__EM_Disruption_Field => r#"Exp. EM Disruption Field"#,
-            Self::ME_RLN_s_Chromatic_Screen => r#"ME-RLN's Chromatic Screen"#,
-            Self::Hardlight_Generator => r#"Hardlight Generator"#,
-            Self::Imp__Hardlight_Generator => r#"Imp. Hardlight Generator"#,
-            Self::Adv__Hardlight_Generator => r#"Adv. Hardlight Generator"#,
-            Self::Exp__Hardlight_Generator => r#"Exp. Hardlight Generator"#,
-            Self::Shield_Generator => r#"Shield Generator"#,
-            Self::Imp__Shield_Generator => r#"Imp. Shield Generator"#,
-            Self::Adv__Shield_Generator => r#"Adv. Shield Generator"#,
-            Self::Exp__Shield_Generator => r#"Exp. Shield Generator"#,
-            Self::Force_Field => r#"Force Field"#,
-            Self::Imp__Force_Field => r#"Imp. Force Field"#,
-            Self::Adv__Force_Field => r#"Adv. Force Field"#,
-            Self::Exp__Force_Field => r#"Exp. Force Field"#,
-            Self::_7V_RTL_s_Ultimate_Field => r#"7V-RTL's Ultimate Field"#,
-            Self::Vortex_Field_Projector => r#"Vortex Field Projector"#,
-            Self::Remote_Shield => r#"Remote Shield"#,
-            Self::Imp__Remote_Shield => r#"Imp. Remote Shield"#,
-            Self::Adv__Remote_Shield => r#"Adv. Remote Shield"#,
-            Self::Remote_Force_Field => r#"Remote Force Field"#,
-            Self::Imp__Remote_Force_Field => r#"Imp. Remote Force Field"#,
-            Self::Energy_Mantle => r#"Energy Mantle"#,
-            Self::Imp__Energy_Mantle => r#"Imp. Energy Mantle"#,
-            Self::AEGIS_Remote_Shield => r#"AEGIS Remote Shield"#,
-            Self::Phase_Redirector => r#"Phase Redirector"#,
-            Self::Point_Defense_System => r#"Point Defense System"#,
-            Self::Point_Defense_Array => r#"Point Defense Array"#,
-            Self::Antimissile_System => r#"Antimissile System"#,
-            Self::EX_Chip_1 => r#"EX Chip 1"#,
-            Self::Hacking_Suite => r#"Hacking Suite"#,
-            Self::Mak__Hacking_Suite => r#"Mak. Hacking Suite"#,
-            Self::Imp__Hacking_Suite => r#"Imp. Hacking Suite"#,
-            Self::Adv__Hacking_Suite => r#"Adv. Hacking Suite"#,
-            Self::Exp__Hacking_Suite => r#"Exp. Hacking Suite"#,
-            Self::System_Mapper => r#"System Mapper"#,
-            Self::Deep_Network_Scanner => r#"Deep Network Scanner"#,
-            Self::Architect_God_Chip_A => r#"Architect God Chip A"#,
-            Self::EX_Chip_2 => r#"EX Chip 2"#,
-            Self::System_Shield => r#"System Shield"#,
-            Self::Mak__System_Shield => r#"Mak. System Shield"#,
-            Self::Imp__System_Shield => r#"Imp. System Shield"#,
-            Self::Adv__System_Shield => r#"Adv. System Shield"#,
-            Self::Exp__System_Shield => r#"Exp. System Shield"#,
-            Self::Ghost_Barrier => r#"Ghost Barrier"#,
-            Self::Quantum_Router => r#"Quantum Router"#,
-            Self::Architect_God_Chip_D => r#"Architect God Chip D"#,
-            Self::Authchip__R_NC_ => r#"Authchip [R/NC]"#,
-            Self::Authchip__R_Combat_ => r#"Authchip [R/Combat]"#,
-            Self::Authchip__Power_ => r#"Authchip [Power]"#,
-            Self::Authchip__Propulsion_ => r#"Authchip [Propulsion]"#,
-            Self::Authchip__Device_ => r#"Authchip [Device]"#,
-            Self::Authchip__Storage_ => r#"Authchip [Storage]"#,
-            Self::Authchip__Processor_ => r#"Authchip [Processor]"#,
-            Self::Authchip__Armor_ => r#"Authchip [Armor]"#,
-            Self::Authchip__Weapon_ => r#"Authchip [Weapon]"#,
-            Self::Relay_Coupler__NC_ => r#"Relay Coupler [NC]"#,
-            Self::Relay_Coupler__C_ => r#"Relay Coupler [C]"#,
-            Self::Relay_Coupler__Swarmer_ => r#"Relay Coupler [Swarmer]"#,
-            Self::Relay_Coupler__Cutter_ => r#"Relay Coupler [Cutter]"#,
-            Self::Relay_Coupler__Grunt_ => r#"Relay Coupler [Grunt]"#,
-            Self::Relay_Coupler__Brawler_ => r#"Relay Coupler [Brawler]"#,
-            Self::Relay_Coupler__Duelist_ => r#"Relay Coupler [Duelist]"#,
-            Self::Relay_Coupler__Sentry_ => r#"Relay Coupler [Sentry]"#,
-            Self::Relay_Coupler__Demolisher_ => r#"Relay Coupler [Demolisher]"#,
-            Self::Relay_Coupler__Specialist_ => r#"Relay Coupler [Specialist]"#,
-            Self::Relay_Coupler__Hunter_ => r#"Relay Coupler [Hunter]"#,
-            Self::Relay_Coupler__Heavy_ => r#"Relay Coupler [Heavy]"#,
-            Self::Relay_Coupler__Behemoth_ => r#"Relay Coupler [Behemoth]"#,
-            Self::Relay_Coupler__Programmer_ => r#"Relay Coupler [Programmer]"#,
-            Self::Relay_Coupler__Proto_ => r#"Relay Coupler [Proto]"#,
-            Self::Skeleton_Box => r#"Skeleton Box"#,
-            Self::_01_MTF_s_Autohacker => r#"01-MTF's Autohacker"#,
-            Self::Component_Analysis_Suite => r#"Component Analysis Suite"#,
-            Self::Imp__Component_Analysis_Suite => r#"Imp. Component Analysis Suite"#,
-            Self::Adv__Component_Analysis_Suite => r#"Adv. Component Analysis Suite"#,
-            Self::Dynamic_Insulation_System => r#"Dynamic Insulation System"#,
-            Self::Imp__Dynamic_Insulation_System => r#"Imp. Dynamic Insulation System"#,
-            Self::Adv__Dynamic_Insulation_System => r#"Adv. Dynamic Insulation System"#,
-            Self::System_Guard => r#"System Guard"#,
-            Self::Imp__System_Guard => r#"Imp. System Guard"#,
-            Self::Exp__System_Guard => r#"Exp. System Guard"#,
-            Self::Corruption_Screen => r#"Corruption Screen"#,
-            Self::Imp__Corruption_Screen => r#"Imp. Corruption Screen"#,
-            Self::Adv__Corruption_Screen => r#"Adv. Corruption Screen"#,
-            Self::System_Restoration_Module => r#"System Restoration Module"#,
-            Self::Mak__System_Restoration_Module => r#"Mak. System Restoration Module"#,
-            Self::Imp__System_Restoration_Module => r#"Imp. System Restoration Module"#,
-            Self::Adv__System_Restoration_Module => r#"Adv. System Restoration Module"#,
-            Self::System_Purifier => r#"System Purifier"#,
-            Self::Exp__System_Purifier => r#"Exp. System Purifier"#,
-            Self::Tractor_Beam => r#"Tractor Beam"#,
-            Self::Hpw__Tractor_Beam => r#"Hpw. Tractor Beam"#,
-            Self::Lrn__Tractor_Beam => r#"Lrn. Tractor Beam"#,
-            Self::Mobile_Refinery_Mk__CCLXVII => r#"Mobile Refinery Mk. CCLXVII"#,
-            Self::Trap_Extractor => r#"Trap Extractor"#,
-            Self::Imp__Trap_Extractor => r#"Imp. Trap Extractor"#,
-            Self::Adv__Trap_Extractor => r#"Adv. Trap Extractor"#,
-            Self::Trap_Reconfigurator => r#"Trap Reconfigurator"#,
-            Self::Recalibrator => r#"Recalibrator"#,
-            Self::Imp__Recalibrator => r#"Imp. Recalibrator"#,
-            Self::Adv__Recalibrator => r#"Adv. Recalibrator"#,
-            Self::Field_Recycling_Unit => r#"Field Recycling Unit"#,
-            Self::Imp__Field_Recycling_Unit => r#"Imp. Field Recycling Unit"#,
-            Self::Adv__Field_Recycling_Unit => r#"Adv. Field Recycling Unit"#,
-            Self::Exp__Field_Recycling_Unit => r#"Exp. Field Recycling Unit"#,
-            Self::Drone_Bay => r#"Drone Bay"#,
-            Self::Z_Drone_Bay => r#"Z-Drone Bay"#,
-            Self::Mni__Drone_Bay => r#"Mni. Drone Bay"#,
-            Self::Mapping_Drone_Bay => r#"Mapping Drone Bay"#,
-            Self::Adv__Drone_Bay => r#"Adv. Drone Bay"#,
-            Self::Bomb_Drone_Bay => r#"Bomb Drone Bay"#,
-            Self::Thief_Drone_Bay => r#"Thief Drone Bay"#,
-            Self::Decoy_Drone_Bay => r#"Decoy Drone Bay"#,
-            Self::Splice_Drone_Bay => r#"Splice Drone Bay"#,
-            Self::Combat_Drone_Bay => r#"Combat Drone Bay"#,
-            Self::Assault_Drone_Bay => r#"Assault Drone Bay"#,
-            Self::Stealth_Drone_Bay => r#"Stealth Drone Bay"#,
-            Self::Swarm_Drone_Bay => r#"Swarm Drone Bay"#,
-            Self::Supersonic_Drone_Bay => r#"Supersonic Drone Bay"#,
-            Self::Sensor_Drone_Bay => r#"Sensor Drone Bay"#,
-            Self::Hacking_Drone_Bay => r#"Hacking Drone Bay"#,
-            Self::Minesniffer_Drone_Bay => r#"Minesniffer Drone Bay"#,
-            Self::Army_in_a_Box => r#"Army-in-a-Box"#,
-            Self::Wardrone_Bay => r#"Wardrone Bay"#,
-            Self::Cobbler_Unit => r#"Cobbler Unit"#,
-            Self::Bomb_Factory_No__2 => r#"Bomb Factory No. 2"#,
-            Self::Mobile_Assembly_Unit => r#"Mobile Assembly Unit"#,
-            Self::Heart_of_Steel => r#"Heart of Steel"#,
-            Self::GOLEM_Unit => r#"GOLEM Unit"#,
-            Self::PSU_Rigger => r#"PSU Rigger"#,
-            Self::Phasing_AFG => r#"Phasing AFG"#,
-            Self::AS_neutralizer_No__17b => r#"AS-neutralizer No. 17b"#,
-            Self::V3_11A_s_Pendant => r#"V3-11A's Pendant"#,
-            Self::Chronowheel => r#"Chronowheel"#,
-            Self::Power_Bank => r#"Power Bank"#,
-            Self::Rainbow_Chip => r#"Rainbow Chip"#,
-            Self::VL_GR5_s_Exoskeleton__Deathgrip_ => r#"VL-GR5's Exoskeleton "Deathgrip""#,
-            Self::Dimensional_Node_Initializer => r#"Dimensional Node Initializer"#,
-            Self::Transdimensional_Reconstructor => r#"Transdimensional Reconstructor"#,
-            Self::Hpw__Transdimensional_Reconstructor => r#"Hpw. Transdimensional Reconstructor"#,
-            Self::Supercharged_TR => r#"Supercharged TR"#,
-            Self::Core_Expander => r#"Core Expander"#,
-            Self::Core_Regenerator => r#"Core Regenerator"#,
-            Self::Integrity_Redistributor => r#"Integrity Redistributor"#,
-            Self::Integrated_Dissipator => r#"Integrated Dissipator"#,
-            Self::Supercharged_Integrated_Dissipator => r#"Supercharged Integrated Dissipator"#,
-            Self::Integrated_Thermoelectric_Network => r#"Integrated Thermoelectric Network"#,
-            Self::Integrated_Reactor => r#"Integrated Reactor"#,
-            Self::Supercharged_Integrated_Reactor => r#"Supercharged Integrated Reactor"#,
-            Self::Integrated_Mediator => r#"Integrated Mediator"#,
-            Self::Core_Membrane => r#"Core Membrane"#,
-            Self::Navigation_Efficiency_Matrix => r#"Navigation Efficiency Matrix"#,
-            Self::Core_Reset_Matrix => r#"Core Reset Matrix"#,
-            Self::Subatomic_Replicator => r#"Subatomic Replicator"#,
-            Self::Terrabomb => r#"Terrabomb"#,
-            Self::Core_Physics_Calibrator => r#"Core Physics Calibrator"#,
-            Self::Spacefold_Activator => r#"Spacefold Activator"#,
-            Self::Microwarp_Drive => r#"Microwarp Drive"#,
-            Self::LC_Capacitor => r#"LC Capacitor"#,
-            Self::LRC_Armor => r#"LRC Armor"#,
-            Self::LRC_Storage => r#"LRC Storage"#,
-            Self::LRC_Energy_Well => r#"LRC Energy Well"#,
-            Self::LRC_Matter_Compressor => r#"LRC Matter Compressor"#,
-            Self::LRC_Inertial_Stabilizer => r#"LRC Inertial Stabilizer"#,
-            Self::LRC_Insulator => r#"LRC Insulator"#,
-            Self::EM_Pulse_Gun => r#"EM Pulse Gun"#,
-            Self::Hvy__EM_Pulse_Gun => r#"Hvy. EM Pulse Gun"#,
-            Self::EM_Shotgun => r#"EM Shotgun"#,
-            Self::Imp__EM_Shotgun => r#"Imp. EM Shotgun"#,
-            Self::Shock_Rifle => r#"Shock Rifle"#,
-            Self::Riot_Gun => r#"Riot Gun"#,
-            Self::Hvy__EM_Shotgun => r#"Hvy. EM Shotgun"#,
-            Self::Lightning_Gun => r#"Lightning Gun"#,
-            Self::Hvy__Riot_Gun => r#"Hvy. Riot Gun"#,
-            Self::Arc_Projector => r#"Arc Projector"#,
-            Self::Prc__Shock_Rifle => r#"Prc. Shock Rifle"#,
-            Self::Hpw__Shock_Rifle => r#"Hpw. Shock Rifle"#,
-            Self::Arc_Thrower => r#"Arc Thrower"#,
-            Self::Imp__Lightning_Gun => r#"Imp. Lightning Gun"#,
-            Self::Tesla_Rifle => r#"Tesla Rifle"#,
-            Self::Imp__Arc_Projector => r#"Imp. Arc Projector"#,
-            Self::Hvy__Tesla_Rifle => r#"Hvy. Tesla Rifle"#,
-            Self::Hyp__EM_Gauss_Rifle => r#"Hyp. EM Gauss Rifle"#,
-            Self::Imp__Arc_Thrower => r#"Imp. Arc Thrower"#,
-            Self::_01_MTF_s_Shockpuncher => r#"01-MTF's Shockpuncher"#,
-            Self::Gamma_Rifle => r#"Gamma Rifle"#,
-            Self::Tachyon_Dispersion_Ray => r#"Tachyon Dispersion Ray"#,
-            Self::Immobilizer => r#"Immobilizer"#,
-            Self::Modified_EM_Gauss_Rifle => r#"Modified EM Gauss Rifle"#,
-            Self::AWS_EX_DEC => r#"AWS/EX-DEC"#,
-            Self::Enh__Gamma_Rifle => r#"Enh. Gamma Rifle"#,
-            Self::Med__Laser => r#"Med. Laser"#,
-            Self::Sml__Laser => r#"Sml. Laser"#,
-            Self::Backup_Laser_I => r#"Backup Laser I"#,
-            Self::Beam_Rifle => r#"Beam Rifle"#,
-            Self::Particle_Gun => r#"Particle Gun"#,
-            Self::Spread_Laser => r#"Spread Laser"#,
-            Self::Imp__Medium_Laser => r#"Imp. Medium Laser"#,
-            Self::Sub__Laser => r#"Sub. Laser"#,
-            Self::Autobeam => r#"Autobeam"#,
-            Self::Field_Laser => r#"Field Laser"#,
-            Self::Pulse_Rifle => r#"Pulse Rifle"#,
-            Self::Backup_Laser_III => r#"Backup Laser III"#,
-            Self::Hvy__Particle_Gun => r#"Hvy. Particle Gun"#,
-            Self::Prc__Beam_Rifle => r#"Prc. Beam Rifle"#,
-            Self::Adv__Beam_Rifle => r#"Adv. Beam Rifle"#,
-            Self::Hvy__Laser => r#"Hvy. Laser"#,
-            Self::Gatling_Laser => r#"Gatling Laser"#,
-            Self::Cld__Pulse_Rifle => r#"Cld. Pulse Rifle"#,
-            Self::Hpw__Field_Laser => r#"Hpw. Field Laser"#,
-            Self::Imp__Spread_Laser => r#"Imp. Spread Laser"#,
-            Self::Mak__Laser => r#"Mak. Laser"#,
-            Self::Plasma_Rifle => r#"Plasma Rifle"#,
-            Self::Thermic_Laser => r#"Thermic Laser"#,
-            Self::Beamcaster => r#"Beamcaster"#,
-            Self::Force_Rifle => r#"Force Rifle"#,
-            Self::Imp__Heavy_Laser => r#"Imp. Heavy Laser"#,
-            Self::Storm_Laser => r#"Storm Laser"#,
-            Self::Zio__Laser_S => r#"Zio. Laser-S"#,
-            Self::Wave_Gun => r#"Wave Gun"#,
-            Self::Variable_Charge_Gun => r#"Variable Charge Gun"#,
-            Self::Hvy__Beamcaster => r#"Hvy. Beamcaster"#,
-            Self::Cld__Plasma_Rifle => r#"Cld. Plasma Rifle"#,
-            Self::Enh__Force_Rifle => r#"Enh. Force Rifle"#,
-            Self::Hvy__Gatling_Laser => r#"Hvy. Gatling Laser"#,
-            Self::Zio__Phaser_S => r#"Zio. Phaser-S"#,
-            Self::Phase_Gun => r#"Phase Gun"#,
-            Self::Dispersion_Rifle => r#"Dispersion Rifle"#,
-            Self::Backup_Laser_VII => r#"Backup Laser VII"#,
-            Self::Plasma_Storm => r#"Plasma Storm"#,
-            Self::Adv__Plasma_Rifle => r#"Adv. Plasma Rifle"#,
-            Self::Hvy__Wave_Gun => r#"Hvy. Wave Gun"#,
-            Self::Adv__Variable_Charge_Gun => r#"Adv. Variable Charge Gun"#,
-            Self::Cld__Phase_Gun => r#"Cld. Phase Gun"#,
-            Self::Lrn__Gatling_Laser => r#"Lrn. Gatling Laser"#,
-            Self::Warp_Gun => r#"Warp Gun"#,
-            Self::Vortex_Rifle => r#"Vortex Rifle"#,
-            Self::Zio__Laser_M => r#"Zio. Laser-M"#,
-            Self::AWS_Thermal => r#"AWS/Thermal"#,
-            Self::PD_Laser => r#"PD Laser"#,
-            Self::Quantum_Rifle => r#"Quantum Rifle"#,
-            Self::Prc__Phase_Gun => r#"Prc. Phase Gun"#,
-            Self::Hvy__Dispersion_Rifle => r#"Hvy. Dispersion Rifle"#,
-            Self::Vortex_Shotgun => r#"Vortex Shotgun"#,
-            Self::Vortex_Rail => r#"Vortex Rail"#,
-            Self::Zio__Phaser_M => r#"Zio. Phaser-M"#,
-            Self::Enh__Quantum_Rifle => r#"Enh. Quantum Rifle"#,
-            Self::Zio__Laser_H => r#"Zio. Laser-H"#,
-            Self::Hvy__Quantum_Rifle => r#"Hvy. Quantum Rifle"#,
-            Self::Zio__Phaser_H => r#"Zio. Phaser-H"#,
-            Self::Gatling_Beam => r#"Gatling Beam"#,
-            Self::Sigix_Sheargun => r#"Sigix Sheargun"#,
-            Self::Modified_Sigix_Sheargun => r#"Modified Sigix Sheargun"#,
-            Self::Lgt__EM_Pulse_Cannon => r#"Lgt. EM Pulse Cannon"#,
-            Self::EM_Pulse_Cannon => r#"EM Pulse Cannon"#,
-            Self::Imp__EM_Pulse_Cannon => r#"Imp. EM Pulse Cannon"#,
-            Self::Proton_Cannon => r#"Proton Cannon"#,
-            Self::Lrn__Proton_Cannon => r#"Lrn. Proton Cannon"#,
-            Self::Disruptor_Cannon => r#"Disruptor Cannon"#,
-            Self::Hvy__Proton_Cannon => r#"Hvy. Proton Cannon"#,
-            Self::HERF_Cannon => r#"HERF Cannon"#,
-            Self::Hvy__Disruptor_Cannon => r#"Hvy. Disruptor Cannon"#,
-            Self::Cld__Proton_Cannon => r#"Cld. Proton Cannon"#,
-            Self::Proton_Burst_Cannon => r#"Proton Burst Cannon"#,
-            Self::Com__HERF_Cannon => r#"Com. HERF Cannon"#,
-            Self::Latent_Energy_Streamer => r#"Latent Energy Streamer"#,
-            Self::NK_0LA_s_Tesla_Doomcannon => r#"NK-0LA's Tesla Doomcannon"#,
-            Self::EMDS => r#"EMDS"#,
-            Self::Hpw__Disruptor_Cannon => r#"Hpw. Disruptor Cannon"#,
-            Self::Beam_Cannon => r#"Beam Cannon"#,
-            Self::Particle_Cannon => r#"Particle Cannon"#,
-            Self::Adv__Beam_Cannon => r#"Adv. Beam Cannon"#,
-            Self::Sub__Beam_Cannon => r#"Sub. Beam Cannon"#,
-            Self::Hvy__Particle_Cannon => r#"Hvy. Particle Cannon"#,
-            Self::Ion_Cannon => r#"Ion Cannon"#,
-            Self::Plasma_Cannon => r#"Plasma Cannon"#,
-            Self::Hvy__Ion_Cannon => r#"Hvy. Ion Cannon"#,
-            Self::Cld__Particle_Cannon => r#"Cld. Particle Cannon"#,
-            Self::Phase_Cannon => r#"Phase Cannon"#,
-            Self::Hvy__Plasma_Cannon => r#"Hvy. Plasma Cannon"#,
-            Self::Cld__Plasma_Cannon => r#"Cld. Plasma Cannon"#,
-            Self::Com__Ion_Cannon => r#"Com. Ion Cannon"#,
-            Self::Ion_Burst_Cannon => r#"Ion Burst Cannon"#,
-            Self::Adv__Plasma_Cannon => r#"Adv. Plasma Cannon"#,
-            Self::Hvy__Phase_Cannon => r#"Hvy. Phase Cannon"#,
-            Self::Neutron_Cannon => r#"Neutron Cannon"#,
-            Self::Powered_Cannon => r#"Powered Cannon"#,
-            Self::Matter_Neutralizer => r#"Matter Neutralizer"#,
-            Self::Hvy__Neutron_Cannon => r#"Hvy. Neutron Cannon"#,
-            Self::Lrn__Phase_Cannon => r#"Lrn. Phase Cannon"#,
-            Self::Cld__Phase_Cannon => r#"Cld. Phase Cannon"#,
-            Self::Vortex_Cannon => r#"Vortex Cannon"#,
-            Self::Gui__Plasma_Cannon => r#"Gui. Plasma Cannon"#,
-            Self::Nova_Cannon => r#"Nova Cannon"#,
-            Self::Imp__Matter_Neutralizer => r#"Imp. Matter Neutralizer"#,
-            Self::Exp__Thermic_Cannon => r#"Exp. Thermic Cannon"#,
-            Self::Vortex_Driver => r#"Vortex Driver"#,
-            Self::Zio__Alpha_Cannon => r#"Zio. Alpha Cannon"#,
-            Self::Asb__Focal_Cannon => r#"Asb. Focal Cannon"#,
-            Self::Enh__Nova_Cannon => r#"Enh. Nova Cannon"#,
-            Self::Firepult => r#"Firepult"#,
-            Self::YOLO_Cannon => r#"YOLO Cannon"#,
-            Self::Potential_Cannon => r#"Potential Cannon"#,
-            Self::Warp_Cannon => r#"Warp Cannon"#,
-            Self::Cld__Nova_Cannon => r#"Cld. Nova Cannon"#,
-            Self::Null_Cannon => r#"Null Cannon"#,
-            Self::Imp__Potential_Cannon => r#"Imp. Potential Cannon"#,
-            Self::Multinova_Projection_Cannon => r#"Multinova Projection Cannon"#,
-            Self::Disintegrator => r#"Disintegrator"#,
-            Self::Core_Cannon => r#"Core Cannon"#,
-            Self::Sigix_Shearcannon => r#"Sigix Shearcannon"#,
-            Self::Drained_L_Cannon => r#"Drained L-Cannon"#,
-            Self::L_Cannon => r#"L-Cannon"#,
-            Self::Zio__Alpha_Cannon_Mk__II => r#"Zio. Alpha Cannon Mk. II"#,
-            Self::BFG_9k_Vortex_Edition => r#"BFG-9k Vortex Edition"#,
-            Self::Assault_Rifle => r#"Assault Rifle"#,
-            Self::Asb__Rifle => r#"Asb. Rifle"#,
-            Self::Lgt__Assault_Rifle => r#"Lgt. Assault Rifle"#,
-            Self::Hvy__Assault_Rifle => r#"Hvy. Assault Rifle"#,
-            Self::Autogun => r#"Autogun"#,
-            Self::Shotgun => r#"Shotgun"#,
-            Self::Battle_Rifle => r#"Battle Rifle"#,
-            Self::Asb__Heavy_Rifle => r#"Asb. Heavy Rifle"#,
-            Self::Sub__Machine_Gun => r#"Sub. Machine Gun"#,
-            Self::Imp__Assault_Rifle => r#"Imp. Assault Rifle"#,
-            Self::Minigun => r#"Minigun"#,
-            Self::Hpw__Shotgun => r#"Hpw. Shotgun"#,
-            Self::Asb__Shotgun => r#"Asb. Shotgun"#,
-            Self::Barrage_Gun => r#"Barrage Gun"#,
-            Self::Enh__Autogun => r#"Enh. Autogun"#,
-            Self::Gauss_Rifle => r#"Gauss Rifle"#,
-            Self::Asb__Gauss_Rifle => r#"Asb. Gauss Rifle"#,
-            Self::Flak_Gun => r#"Flak Gun"#,
-            Self::Prc__Assault_Rifle => r#"Prc. Assault Rifle"#,
-            Self::Hvy__Battle_Rifle => r#"Hvy. Battle Rifle"#,
-            Self::Mak__Shrapnel_Gun => r#"Mak. Shrapnel Gun"#,
-            Self::Coil_Gun => r#"Coil Gun"#,
-            Self::Hyp__Gauss_Rifle => r#"Hyp. Gauss Rifle"#,
-            Self::Hvy__Machine_Gun => r#"Hvy. Machine Gun"#,
-            Self::Imp__Heavy_Machine_Gun => r#"Imp. Heavy Machine Gun"#,
-            Self::Com__Gauss_Rifle => r#"Com. Gauss Rifle"#,
-            Self::XL_Autogun_Subcomponent => r#"XL Autogun Subcomponent"#,
-            Self::KE_Penetrator => r#"KE Penetrator"#,
-            Self::Hyp__Coil_Gun => r#"Hyp. Coil Gun"#,
-            Self::Imp__KE_Penetrator => r#"Imp. KE Penetrator"#,
-            Self::Enh__Coil_Gun => r#"Enh. Coil Gun"#,
-            Self::Com__Coil_Gun => r#"Com. Coil Gun"#,
-            Self::Railgun => r#"Railgun"#,
-            Self::Adv__KE_Penetrator => r#"Adv. KE Penetrator"#,
-            Self::Linked_Autogun => r#"Linked Autogun"#,
-            Self::AWS_Gauss => r#"AWS/Gauss"#,
-            Self::Hyp__Railgun => r#"Hyp. Railgun"#,
-            Self::Helical_Railgun => r#"Helical Railgun"#,
-            Self::Com__Railgun => r#"Com. Railgun"#,
-            Self::XL_Autogun_Array => r#"XL Autogun Array"#,
-            Self::_12_ASH_s_Boomstick => r#"12-ASH's Boomstick"#,
-            Self::_8R_AWN_s_Boregun => r#"8R-AWN's Boregun"#,
-            Self::Multirail => r#"Multirail"#,
-            Self::Hyp__Multirail => r#"Hyp. Multirail"#,
-            Self::_5H_AD0_s_Sniper_Rifle => r#"5H-AD0's Sniper Rifle"#,
-            Self::A3_s_Sniper_Rifle => r#"A3's Sniper Rifle"#,
-            Self::Lgt__Cannon => r#"Lgt. Cannon"#,
-            Self::Imp__Light_Cannon => r#"Imp. Light Cannon"#,
-            Self::Battle_Cannon => r#"Battle Cannon"#,
-            Self::Assault_Cannon => r#"Assault Cannon"#,
-            Self::Hvy__Assault_Cannon => r#"Hvy. Assault Cannon"#,
-            Self::Flak_Cannon => r#"Flak Cannon"#,
-            Self::Adv__Assault_Cannon => r#"Adv. Assault Cannon"#,
-            Self::Gauss_Cannon => r#"Gauss Cannon"#,
-            Self::Slug_Cannon => r#"Slug Cannon"#,
-            Self::Mni__Assault_Cannon => r#"Mni. Assault Cannon"#,
-            Self::Magnetic_Acceleration_Cannon => r#"Magnetic Acceleration Cannon"#,
-            Self::Hvy__Flak_Cannon => r#"Hvy. Flak Cannon"#,
-            Self::Bore_Cannon => r#"Bore Cannon"#,
-            Self::Antimatter_Cannon => r#"Antimatter Cannon"#,
-            Self::Mass_Driver => r#"Mass Driver"#,
-            Self::Hvy__Mass_Driver => r#"Hvy. Mass Driver"#,
-            Self::Enh__Gauss_Cannon => r#"Enh. Gauss Cannon"#,
-            Self::Hyp__Gauss_Cannon => r#"Hyp. Gauss Cannon"#,
-            Self::Tri_rail_Accelerator => r#"Tri-rail Accelerator"#,
-            Self::Hardcell_Cannon => r#"Hardcell Cannon"#,
-            Self::Hvy__Gauss_Cannon => r#"Hvy. Gauss Cannon"#,
-            Self::Hyp__Mass_Driver => r#"Hyp. Mass Driver"#,
-            Self::Hvy__Autocannon => r#"Hvy. Autocannon"#,
-            Self::DAS_Cannon => r#"DAS Cannon"#,
-            Self::Lgt__Antimatter_Cannon => r#"Lgt. Antimatter Cannon"#,
-            Self::Linear_Accelerator => r#"Linear Accelerator"#,
-            Self::Hvy__Linear_Accelerator => r#"Hvy. Linear Accelerator"#,
-            Self::Com__Mass_Driver => r#"Com. Mass Driver"#,
-            Self::Hvy__Hardcell_Cannon => r#"Hvy. Hardcell Cannon"#,
-            Self::Com__Linear_Accelerator => r#"Com. Linear Accelerator"#,
-            Self::Hyp__Linear_Accelerator => r#"Hyp. Linear Accelerator"#,
-            Self::Hcl__Linear_Accelerator => r#"Hcl. Linear Accelerator"#,
-            Self::Graviton_Cannon => r#"Graviton Cannon"#,
-            Self::Blast_Cannon => r#"Blast Cannon"#,
-            Self::P1_3CE_s_Gatling_Flakker => r#"P1-3CE's Gatling Flakker"#,
-            Self::AWS_Autocannon => r#"AWS/Autocannon"#,
-            Self::Perforator => r#"Perforator"#,
-            Self::Omega_Cannon => r#"Omega Cannon"#,
-            Self::EMP_Blaster => r#"EMP Blaster"#,
-            Self::Shock_Bomb_Launcher => r#"Shock Bomb Launcher"#,
-            Self::Adv__EMP_Blaster => r#"Adv. EMP Blaster"#,
-            Self::Proton_Missile_Launcher => r#"Proton Missile Launcher"#,
-            Self::Imp__Proton_Missile_Launcher => r#"Imp. Proton Missile Launcher"#,
-            Self::Gui__EMP_Blaster => r#"Gui. EMP Blaster"#,
-            Self::Hvy__Shock_Bomb_Launcher => r#"Hvy. Shock Bomb Launcher"#,
-            Self::Tesla_Bomb_Launcher => r#"Tesla Bomb Launcher"#,
-            Self::Hvy__Proton_Missile_Launcher => r#"Hvy. Proton Missile Launcher"#,
-            Self::Com__Tesla_Bomb_Launcher => r#"Com. Tesla Bomb Launcher"#,
-            Self::Gamma_Bomb_Array => r#"Gamma Bomb Array"#,
-            Self::Enh__Gamma_Bomb_Array => r#"Enh. Gamma Bomb Array"#,
-            Self::Grenade_Launcher => r#"Grenade Launcher"#,
-            Self::Rocket_Launcher => r#"Rocket Launcher"#,
-            Self::Mni__Grenade_Launcher => r#"Mni. Grenade Launcher"#,
-            Self::Sub__Shrapnel_Launcher => r#"Sub. Shrapnel Launcher"#,
-            Self::Imp__Grenade_Launcher => r#"Imp. Grenade Launcher"#,
-            Self::Missile_Launcher => r#"Missile Launcher"#,
-            Self::Concussive_RPG => r#"Concussive RPG"#,
-            Self::YI_UF0_s_Doublenader => r#"YI-UF0's Doublenader"#,
-            Self::Hvy__Rocket_Launcher => r#"Hvy. Rocket Launcher"#,
-            Self::Hvy__Missile_Launcher => r#"Hvy. Missile Launcher"#,
-            Self::Gui__Missile_Launcher => r#"Gui. Missile Launcher"#,
-            Self::Prc__Grenade_Launcher => r#"Prc. Grenade Launcher"#,
-            Self::Rocket_Array => r#"Rocket Array"#,
-            Self::Lrn__Missile_Launcher => r#"Lrn. Missile Launcher"#,
-            Self::Imp__Concussive_RPG => r#"Imp. Concussive RPG"#,
-            Self::Prc__Rocket_Launcher => r#"Prc. Rocket Launcher"#,
-            Self::Scatter_Rocket_Array => r#"Scatter Rocket Array"#,
-            Self::Smartbomb_Launcher => r#"Smartbomb Launcher"#,
-            Self::Mni__Smartbomb_Launcher => r#"Mni. Smartbomb Launcher"#,
-            Self::Tiamat_Missile_Launcher => r#"Tiamat Missile Launcher"#,
-            Self::Thermoblaster => r#"Thermoblaster"#,
-            Self::Micro_nuke_Launcher => r#"Micro-nuke Launcher"#,
-            Self::Adv__Concussive_RPG => r#"Adv. Concussive RPG"#,
-            Self::Neutron_Missile_Launcher => r#"Neutron Missile Launcher"#,
-            Self::Fusion_Bomb_Launcher => r#"Fusion Bomb Launcher"#,
-            Self::Hellfire_Missile_Launcher => r#"Hellfire Missile Launcher"#,
-            Self::Adv__Micro_nuke_Launcher => r#"Adv. Micro-nuke Launcher"#,
-            Self::Tactical_Quantum_Warhead => r#"Tactical Quantum Warhead"#,
-            Self::Vortex_Catalyst_Activator => r#"Vortex Catalyst Activator"#,
-            Self::Chain_Missile_Launcher => r#"Chain Missile Launcher"#,
-            Self::Ragnarok_Missile_Launcher => r#"Ragnarok Missile Launcher"#,
-            Self::Point_Singularity_Launcher => r#"Point Singularity Launcher"#,
-            Self::Gui__Micro_nuke_Launcher => r#"Gui. Micro-nuke Launcher"#,
-            Self::_99_TNT_s_Nukerbomber => r#"99-TNT's Nukerbomber"#,
-            Self::Z_bomb_Delivery_System => r#"Z-bomb Delivery System"#,
-            Self::Sigix_Terminator => r#"Sigix Terminator"#,
-            Self::Supercharged_Sigix_Terminator => r#"Supercharged Sigix Terminator"#,
-            Self::Containment_Facilitator => r#"Containment Facilitator"#,
-            Self::Detonator => r#"Detonator"#,
-            Self::Dirty_Datajack => r#"Dirty Datajack"#,
-            Self::Splice_Injector => r#"Splice Injector"#,
-            Self::Master_Link => r#"Master Link"#,
-            Self::Datajack => r#"Datajack"#,
-            Self::Imp__Datajack => r#"Imp. Datajack"#,
-            Self::Adv__Datajack => r#"Adv. Datajack"#,
-            Self::Remote_Datajack => r#"Remote Datajack"#,
-            Self::Imp__Remote_Datajack => r#"Imp. Remote Datajack"#,
-            Self::Adv__Remote_Datajack => r#"Adv. Remote Datajack"#,
-            Self::Exp__Remote_Datajack => r#"Exp. Remote Datajack"#,
-            Self::Gui__Remote_Datajack => r#"Gui. Remote Datajack"#,
-            Self::ME_RLN_s_Wand => r#"ME-RLN's Wand"#,
-            Self::Field_Lobotomy_Kit => r#"Field Lobotomy Kit"#,
-            Self::Mining_Laser => r#"Mining Laser"#,
-            Self::Welding_Torch => r#"Welding Torch"#,
-            Self::Plasma_Cutter => r#"Plasma Cutter"#,
-            Self::Imp__Plasma_Cutter => r#"Imp. Plasma Cutter"#,
-            Self::Exp__Plasma_Cutter => r#"Exp. Plasma Cutter"#,
-            Self::CPS_Tube => r#"CPS Tube"#,
-            Self::Multitool => r#"Multitool"#,
-            Self::Flamer => r#"Flamer"#,
-            Self::Asb__F_torch => r#"Asb. F-torch"#,
-            Self::Enh__Flamer => r#"Enh. Flamer"#,
-            Self::Plasma_Flamer => r#"Plasma Flamer"#,
-            Self::Asb__P_torch => r#"Asb. P-torch"#,
-            Self::RU_N14_s_Throwing_Claymores => r#"RU-N14's Throwing Claymores"#,
-            Self::RU_N14_s_Throwing_Claymores_v3 => r#"RU-N14's Throwing Claymores v3"#,
-            Self::AD_0RF_s_Magmablaster => r#"AD-0RF's Magmablaster"#,
-            Self::Stasis_Beam => r#"Stasis Beam"#,
-            Self::Stasis_Projector => r#"Stasis Projector"#,
-            Self::Exp__Stasis_Projector => r#"Exp. Stasis Projector"#,
-            Self::Molecular_Deconstructor => r#"Molecular Deconstructor"#,
-            Self::SE_WE1 => r#"SE_WE1"#,
-            Self::SE_WE2 => r#"SE_WE2"#,
-            Self::SE_WE3 => r#"SE_WE3"#,
-            Self::SE_WE4 => r#"SE_WE4"#,
-            Self::SE_WE5 => r#"SE_WE5"#,
-            Self::Hammer => r#"Hammer"#,
-            Self::Mace => r#"Mace"#,
-            Self::Flail => r#"Flail"#,
-            Self::Maul => r#"Maul"#,
-            Self::Asb__Maul => r#"Asb. Maul"#,
-            Self::Hvy__Hammer => r#"Hvy. Hammer"#,
-            Self::Hvy__Mace => r#"Hvy. Mace"#,
-            Self::Hvy__Flail => r#"Hvy. Flail"#,
-            Self::Great_Maul => r#"Great Maul"#,
-            Self::Powered_Hammer => r#"Powered Hammer"#,
-            Self::Mak__Hammer => r#"Mak. Hammer"#,
-            Self::Power_Maul => r#"Power Maul"#,
-            Self::Asb__P_maul => r#"Asb. P-maul"#,
-            Self::Impact_Mace => r#"Impact Mace"#,
-            Self::Shock_Maul => r#"Shock Maul"#,
-            Self::Thunder_Hammer => r#"Thunder Hammer"#,
-            Self::Compactor => r#"Compactor"#,
-            Self::Gravity_Flail => r#"Gravity Flail"#,
-            Self::CL_ANK_s_Mallet => r#"CL-ANK's Mallet"#,
-            Self::Core_Stripper => r#"Core Stripper"#,
-            Self::Mining_Claw => r#"Mining Claw"#,
-            Self::EC_1 => r#"EC_1"#,
-            Self::Katana => r#"Katana"#,
-            Self::Asb__Blade => r#"Asb. Blade"#,
-            Self::Scythe => r#"Scythe"#,
-            Self::Axe => r#"Axe"#,
-            Self::Blade_Saw => r#"Blade Saw"#,
-            Self::EC_3 => r#"EC_3"#,
-            Self::Chainsword => r#"Chainsword"#,
-            Self::Ripper => r#"Ripper"#,
-            Self::Mak__Axe => r#"Mak. Axe"#,
-            Self::Mak__Sword => r#"Mak. Sword"#,
-            Self::Great_Axe => r#"Great Axe"#,
-            Self::Greatsword => r#"Greatsword"#,
-            Self::Power_Sword => r#"Power Sword"#,
-            Self::Asb__P_sword => r#"Asb. P-sword"#,
-            Self::Falx => r#"Falx"#,
-            Self::Carbide_Saw => r#"Carbide Saw"#,
-            Self::Segregator => r#"Segregator"#,
-            Self::Tearclaws => r#"Tearclaws"#,
-            Self::EC_5 => r#"EC_5"#,
-            Self::Phasing_Sabre => r#"Phasing Sabre"#,
-            Self::Plasma_Sword => r#"Plasma Sword"#,
-            Self::Ionic_Axe => r#"Ionic Axe"#,
-            Self::Mni__Tearclaws => r#"Mni. Tearclaws"#,
-            Self::Dual_blade_Saw => r#"Dual-blade Saw"#,
-            Self::Vibroblade => r#"Vibroblade"#,
-            Self::Molecular_Scythe => r#"Molecular Scythe"#,
-            Self::Centrium_Greatsword => r#"Centrium Greatsword"#,
-            Self::Master_Tearclaws => r#"Master Tearclaws"#,
-            Self::Longsword__1 => r#"Longsword +1"#,
-            Self::EC_7 => r#"EC_7"#,
-            Self::Nanosword => r#"Nanosword"#,
-            Self::Particle_Cleaver => r#"Particle Cleaver"#,
-            Self::Centrium_Claws => r#"Centrium Claws"#,
-            Self::_1C_UTU_s_Sword__Lootmaker_ => r#"1C-UTU's Sword "Lootmaker""#,
-            Self::Vortex_Shredder => r#"Vortex Shredder"#,
-            Self::EC_9 => r#"EC_9"#,
-            Self::_1C_UTU_s_Sword__Choppy_ => r#"1C-UTU's Sword "Choppy""#,
-            Self::Quantum_Blade => r#"Quantum Blade"#,
-            Self::Sigix_Broadsword => r#"Sigix Broadsword"#,
-            Self::Spear => r#"Spear"#,
-            Self::Mak__Spear => r#"Mak. Spear"#,
-            Self::CR_A16_s_Pointy_Stick => r#"CR-A16's Pointy Stick"#,
-            Self::Hvy__Pick => r#"Hvy. Pick"#,
-            Self::Lance => r#"Lance"#,
-            Self::Kinetic_Spear => r#"Kinetic Spear"#,
-            Self::Force_Lance => r#"Force Lance"#,
-            Self::Plasma_Lance => r#"Plasma Lance"#,
-            Self::Enh__Force_Lance => r#"Enh. Force Lance"#,
-            Self::Vortex_Lance => r#"Vortex Lance"#,
-            Self::CR_A16_s_Behemoth_Slayer => r#"CR-A16's Behemoth Slayer"#,
-            Self::Blade_Trap => r#"Blade Trap"#,
-            Self::Segregator_Trap => r#"Segregator Trap"#,
-            Self::Explosive_Trap => r#"Explosive Trap"#,
-            Self::Heavy_Explosive_Trap => r#"Heavy Explosive Trap"#,
-            Self::Tiamat_Bomb_Trap => r#"Tiamat Bomb Trap"#,
-            Self::Fusion_Bomb_Trap => r#"Fusion Bomb Trap"#,
-            Self::Hellfire_Trap => r#"Hellfire Trap"#,
-            Self::Armageddon_Trap => r#"Armageddon Trap"#,
-            Self::Dirty_Bomb_Trap => r#"Dirty Bomb Trap"#,
-            Self::EMP_Trap => r#"EMP Trap"#,
-            Self::Proton_Bomb_Trap => r#"Proton Bomb Trap"#,
-            Self::Tesla_Bomb_Trap => r#"Tesla Bomb Trap"#,
-            Self::Gamma_Bomb_Trap => r#"Gamma Bomb Trap"#,
-            Self::Shrapnel_Trap => r#"Shrapnel Trap"#,
-            Self::Piercing_Trap => r#"Piercing Trap"#,
-            Self::Shock_Trap => r#"Shock Trap"#,
-            Self::EM_Surge_Trap => r#"EM Surge Trap"#,
-            Self::Fire_Trap => r#"Fire Trap"#,
-            Self::Stasis_Trap => r#"Stasis Trap"#,
-            Self::Scrap_Shield_Fragment => r#"Scrap Shield Fragment"#,
-            Self::Signal_Generator => r#"Signal Generator"#,
-            Self::Self_destruct_Interrupter => r#"Self-destruct Interrupter"#,
-            Self::Sapper_Charge => r#"Sapper Charge"#,
-            Self::Zhirov_Special => r#"Zhirov Special"#,
-            Self::Deploy_a_Sentry => r#"Deploy-a-Sentry"#,
-            Self::GAL_Module => r#"GAL Module"#,
-            Self::Portable_AFG => r#"Portable AFG"#,
-            Self::SGEMP_Prototype => r#"SGEMP Prototype"#,
-            Self::Terrabomb_Derivative => r#"Terrabomb Derivative"#,
-            Self::Sigix_Containment_Pod => r#"Sigix Containment Pod"#,
-            Self::Sigix_Corpse => r#"Sigix Corpse"#,
-            Self::Meganuke => r#"Meganuke"#,
-            Self::Gamma_Refractor => r#"Gamma Refractor"#,
-        }
-    }
-}
-
-impl TryFrom<i32> for ItemId {
-    type Error = &'static str;
-    fn try_from(id: i32) -> Result<Self, Self::Error> {
-        Self::from_id(id).ok_or("unknown id")
-    }
-}
-
-impl Into<i32> for ItemId {
-    fn into(self) -> i32 {
-        self.id()
-    }
-}
-
-impl std::fmt::Display for ItemId {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        f.write_str(self.name())
-    }
-}
+include!(concat!(env!("OUT_DIR"), "/generated.rs"));
@@ -0,0 +1,26 @@
+//! Reads Cogmind's `-luigiAi` memory interface
+//!
+//! Attach a [`memory::MemorySource`] to the game (or a snapshot of it), find
+//! the `LuigiAi` block with [`locator::LuigiLocator`] and decode everything it
+//! points to into a [`GameState`].
+
+#[macro_use]
+extern crate log;
+
+pub mod config;
+#[cfg(feature = "presence")]
+pub mod discord;
+pub mod generated;
+pub mod locator;
+pub mod memory;
+pub mod process;
+pub mod propulsion;
+pub mod regions;
+pub mod snapshot;
+pub mod state;
+pub mod template;
+pub mod timeline;
+pub mod types;
+
+pub use generated::{CellId, EntityId, ItemId, PropId};
+pub use state::GameState;
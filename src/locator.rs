@@ -0,0 +1,192 @@
+use crate::memory::MemorySource;
+use crate::types::{Decode, LuigiAi, LUIGI_MAGIC1};
+use anyhow::anyhow;
+use std::mem;
+
+/// How much memory the signature scan reads per call
+const SCAN_CHUNK_SIZE: usize = 0x1_0000;
+
+pub fn get_base_address(source: &dyn MemorySource) -> anyhow::Result<usize> {
+    let check_value = LUIGI_MAGIC1.to_le_bytes();
+
+    for region in source.regions()? {
+        // Walk each region in large chunks instead of one word at a time
+        for chunk_start in (region.start..region.end).step_by(SCAN_CHUNK_SIZE) {
+            let chunk_len = SCAN_CHUNK_SIZE.min(region.end - chunk_start);
+            let bytes = match source.read(chunk_start, chunk_len) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    debug!(
+                        "Skipping unreadable chunk 0x{:X}-0x{:X}: {}",
+                        chunk_start,
+                        chunk_start + chunk_len,
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            // Regions are page aligned, so the magic never straddles two chunks
+            let candidates = bytes
+                .chunks_exact(mem::size_of::<u32>())
+                .enumerate()
+                .filter(|(_, word)| *word == check_value)
+                .map(|(i, _)| chunk_start + i * mem::size_of::<u32>());
+
+            for address in candidates {
+                match read_luigi_ai(source, address) {
+                    Ok(_) => return Ok(address),
+                    Err(e) => debug!("Rejected LuigiAi candidate at 0x{:X}: {}", address, e),
+                }
+            }
+        }
+    }
+
+    // If we reach this point, it means we did not find the check_value at any address
+    Err(anyhow!("Could not find base address"))
+}
+
+/// Remembers where the `LuigiAi` block lives between polls
+#[derive(Default)]
+pub struct LuigiLocator {
+    base: Option<usize>,
+}
+
+impl LuigiLocator {
+    /// Start from a known address, e.g. the root saved in a snapshot
+    pub fn with_base(base: usize) -> Self {
+        Self { base: Some(base) }
+    }
+
+    /// Address of the last `LuigiAi` block read
+    pub fn base(&self) -> Option<usize> {
+        self.base
+    }
+
+    /// Read the `LuigiAi` block and its address, rescanning only when the
+    /// cached address stops checking out
+    pub fn read(&mut self, source: &dyn MemorySource) -> anyhow::Result<(usize, LuigiAi)> {
+        if let Some(base) = self.base {
+            match read_luigi_ai(source, base) {
+                Ok(val) => return Ok((base, val)),
+                Err(e) => {
+                    info!("Cached LuigiAi address 0x{:X} went stale: {}", base, e);
+                    self.base = None;
+                }
+            }
+        }
+
+        debug!("Scanning for LuigiAi...");
+        let base = get_base_address(source)?;
+        let val = read_luigi_ai(source, base)?;
+        self.base = Some(base);
+        Ok((base, val))
+    }
+}
+
+pub fn read_luigi_ai(source: &dyn MemorySource, address: usize) -> anyhow::Result<LuigiAi> {
+    let bytes = source.read(address, LuigiAi::SIZE)?;
+    let val = LuigiAi::decode(&bytes)?;
+    val.validate()?;
+    Ok(val)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MockSource;
+    use crate::regions::Region;
+    use crate::types::LUIGI_MAGIC2;
+    use std::cell::{Cell, RefCell};
+
+    /// A `LuigiAi` block for a 3x2 map, with the fields the validation looks at
+    fn block(magic2: i32, map_width: i32, player: u32) -> Vec<u8> {
+        let words = [
+            LUIGI_MAGIC1,
+            magic2,
+            1,
+            map_width,
+            2,
+            -10,
+            2,
+            0x0200_0000,
+            -1,
+            player as i32,
+            0,
+        ];
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    fn valid_block() -> Vec<u8> {
+        block(LUIGI_MAGIC2, 3, 0x0300_0000)
+    }
+
+    #[test]
+    fn skips_stray_copies_of_the_magic() {
+        let mut bytes = vec![0; 0x200];
+        let decoys = [
+            block(0x1234, 3, 0x0300_0000),
+            block(LUIGI_MAGIC2, 5000, 0x0300_0000),
+            block(LUIGI_MAGIC2, 3, 0x10),
+        ];
+        for (i, decoy) in decoys.iter().enumerate() {
+            bytes[i * 0x40..][..decoy.len()].copy_from_slice(decoy);
+        }
+        bytes[0x100..][..LuigiAi::SIZE].copy_from_slice(&valid_block());
+
+        let mut memory = MockSource::new();
+        memory.insert(0x0100_0000, bytes);
+        assert_eq!(get_base_address(&memory).unwrap(), 0x0100_0100);
+    }
+
+    #[test]
+    fn fails_without_a_valid_block() {
+        let mut memory = MockSource::new();
+        memory.insert(0x0100_0000, block(0x1234, 3, 0x0300_0000));
+        assert!(get_base_address(&memory).is_err());
+    }
+
+    /// Counts how often the locator lists regions, which only a scan does
+    #[derive(Default)]
+    struct CountingSource {
+        memory: RefCell<MockSource>,
+        scans: Cell<usize>,
+    }
+
+    impl MemorySource for CountingSource {
+        fn read(&self, addr: usize, len: usize) -> anyhow::Result<Vec<u8>> {
+            self.memory.borrow().read(addr, len)
+        }
+
+        fn regions(&self) -> anyhow::Result<Vec<Region>> {
+            self.scans.set(self.scans.get() + 1);
+            self.memory.borrow().regions()
+        }
+    }
+
+    #[test]
+    fn reuses_the_address_until_the_block_moves() {
+        let source = CountingSource::default();
+        source
+            .memory
+            .borrow_mut()
+            .insert(0x0100_0000, valid_block());
+        let mut locator = LuigiLocator::default();
+
+        locator.read(&source).unwrap();
+        assert_eq!(source.scans.get(), 1);
+        let (base, _) = locator.read(&source).unwrap();
+        assert_eq!(source.scans.get(), 1);
+        assert_eq!(base, 0x0100_0000);
+
+        // The game frees the old block and allocates a new one elsewhere
+        let mut moved = MockSource::new();
+        moved.insert(0x0100_0000, vec![0; LuigiAi::SIZE]);
+        moved.insert(0x0800_0000, valid_block());
+        source.memory.replace(moved);
+
+        let (base, _) = locator.read(&source).unwrap();
+        assert_eq!(source.scans.get(), 2);
+        assert_eq!(base, 0x0800_0000);
+    }
+}
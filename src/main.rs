@@ -1,180 +1,495 @@
 #[macro_use]
 extern crate log;
 
-mod discord;
-mod generated;
-mod types;
-
-use crate::discord::PresenceProvider;
-use crate::types::{LuigiAi, MapType};
-use anyhow::{anyhow, Error};
-use discord_rich_presence::DiscordIpc;
+use anyhow::{anyhow, Context};
+use clap::{Parser, Subcommand};
 use env_logger::Env;
-use read_process_memory::copy_address;
-use read_process_memory::{Pid, ProcessHandle};
+use read_process_memory::Pid;
 #[cfg(target_os = "macos")]
 use security_framework::authorization::{Authorization, AuthorizationItemSetBuilder, Flags};
-use std::{mem, thread, time};
-use sysinfo::{PidExt, ProcessExt, System, SystemExt};
+use statmind::config::{self, Config, ProcessConfig};
+use statmind::discord::PresenceProvider;
+use statmind::locator::LuigiLocator;
+use statmind::memory::{MemorySource, ProcessSource, SnapshotSource};
+use statmind::state::{self, ReadStats};
+use statmind::types::{MapType, Relation};
+use statmind::GameState;
+use statmind::{process, snapshot};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{process as std_process, thread, time};
+use sysinfo::{System, SystemExt};
+
+/// First delay between looks for the game process
+const MIN_WAIT: time::Duration = time::Duration::from_secs(1);
+/// Longest delay between looks for the game process
+const MAX_WAIT: time::Duration = time::Duration::from_secs(30);
+/// How often a running session checks that the game is still there
+const EXIT_CHECK_INTERVAL: time::Duration = time::Duration::from_secs(1);
+/// How quickly sleeps notice a shutdown request
+const SHUTDOWN_CHECK_INTERVAL: time::Duration = time::Duration::from_millis(100);
+/// Time between game polls for `run`; Discord itself is only updated on changes
+const DEFAULT_RUN_INTERVAL: time::Duration = time::Duration::from_secs(2);
+/// Time between redraws for `watch`
+const DEFAULT_WATCH_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
+/// Discord Rich Presence and memory inspection for Cogmind's `-luigiAi` mode
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Attach to this process instead of searching for Cogmind
+    #[arg(long, global = true)]
+    pid: Option<Pid>,
+
+    /// Seconds between polls of the game
+    #[arg(long, global = true, value_name = "SECONDS")]
+    interval: Option<f64>,
+
+    /// Read memory from a snapshot file instead of the game (dump, watch, inspect, snapshot)
+    #[arg(long, global = true, value_name = "PATH")]
+    snapshot: Option<PathBuf>,
+
+    /// Read settings from this TOML file [default: <config dir>/statmind/config.toml]
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Log filter, e.g. `info` or `statmind=debug` [env: MY_LOG_LEVEL]
+    #[arg(long, global = true, value_name = "LEVEL")]
+    log_level: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Publish the current map to Discord (the default)
+    Run,
+    /// Write the decoded game state as JSON
+    Dump {
+        /// File to write instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Show a live readout of the game state
+    Watch,
+    /// Hex dump raw game memory
+    Inspect {
+        /// Address to start at, in hex (`0x...`) or decimal
+        #[arg(long, value_parser = parse_address)]
+        addr: usize,
+        /// Number of bytes to show
+        #[arg(long, default_value_t = 256)]
+        len: usize,
+    },
+    /// Save game memory to a file that `--snapshot` can read back
+    Snapshot {
+        #[command(subcommand)]
+        action: SnapshotAction,
+    },
+    /// Manage the configuration file
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Write a commented example config to `--config` or the default location
+    Init {
+        /// Overwrite an existing file
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum SnapshotAction {
+    /// Save the `LuigiAi` block and everything it points to
+    Capture {
+        /// File to write
+        output: PathBuf,
+    },
+}
+
+/// Set by SIGINT/SIGTERM; long-running commands wind down when they see it
+static SHUTDOWN: AtomicBool = AtomicBool::new(false);
+
+/// Command line options merged over the config file
+struct Settings {
+    pid: Option<Pid>,
+    snapshot: Option<PathBuf>,
+    interval: Option<time::Duration>,
+    config: Config,
+}
+
+impl Settings {
+    fn interval_or(&self, default: time::Duration) -> time::Duration {
+        self.interval.unwrap_or(default)
+    }
+}
 
 fn main() -> anyhow::Result<()> {
-    // Init logger
+    let cli = Cli::parse();
+    if let Some(Command::Config { action }) = &cli.command {
+        return config_command(action, cli.config.as_deref());
+    }
+    let config = Config::find(cli.config.as_deref())?;
+
+    // Init logger; the command line beats MY_LOG_LEVEL, which beats the config file
     let env = Env::default()
-        .filter_or("MY_LOG_LEVEL", "info")
+        .filter_or(
+            "MY_LOG_LEVEL",
+            config.log_level.as_deref().unwrap_or("info"),
+        )
         .write_style_or("MY_LOG_STYLE", "always");
-    env_logger::init_from_env(env);
+    let mut builder = env_logger::Builder::from_env(env);
+    if let Some(level) = &cli.log_level {
+        builder.parse_filters(level);
+    }
+    builder.init();
+
+    let settings = Settings {
+        pid: cli.pid,
+        snapshot: cli.snapshot,
+        interval: match cli.interval {
+            Some(secs) => Some(config::parse_interval(secs).context("--interval")?),
+            None => config.interval()?,
+        },
+        config,
+    };
 
     // Get debug introspection (taskport) right on macOS
     #[cfg(target_os = "macos")]
     acquire_taskport_right()?;
 
-    // Create a new System object and refresh process list
-    let mut sys = System::new_all();
-    sys.refresh_processes();
-
-    // Find the process
-    let native_process = sys
-        .processes()
-        .iter()
-        .find(|(_, proc)| {
-            proc.name().to_lowercase().contains("cogmind.exe")
-                && proc.cmd().contains(&"-luigiAi".to_owned())
-        })
-        .map(|(_, proc)| proc);
-    let wine_process = sys
-        .processes()
-        .iter()
-        .find(|(_, proc)| {
-            proc.name().to_lowercase().contains("wine")
-                && proc.cmd().contains(&"-luigiAi".to_owned())
-        })
-        .map(|(_, proc)| proc);
-    let process = native_process.or(wine_process);
-
-    if let Some(process) = process {
-        debug!("Opening handle to process...");
-        // Get a handle to the process
-        let pid = process.pid().as_u32() as Pid;
-        let handle: ProcessHandle = (pid).try_into()?;
-
-        let mut presence = PresenceProvider::try_init()?;
-
-        loop {
-            debug!("Reading Cogmind process memory...");
-            let map_string = get_luigi_map(&handle)?;
-            let result = presence
-                .client
-                .set_activity(presence.activity.clone().state(&map_string));
-            match result {
-                Ok(_) => {
-                    info!("State updated! {}", map_string);
-                    thread::sleep(time::Duration::from_secs(60));
-                }
-                Err(e) => {
-                    error!("Error updating state:\n{}", e);
-                    thread::sleep(time::Duration::from_secs(5));
-                }
-            }
+    match cli.command.unwrap_or(Command::Run) {
+        Command::Run if settings.snapshot.is_some() => {
+            Err(anyhow!("`run` needs the live game, not --snapshot"))
+        }
+        Command::Run => run(&settings),
+        Command::Dump { output } => dump(&settings, output.as_deref()),
+        Command::Watch => watch(&settings),
+        Command::Inspect { addr, len } => inspect(&settings, addr, len),
+        Command::Snapshot {
+            action: SnapshotAction::Capture { output },
+        } => capture(&settings, &output),
+        Command::Config { .. } => unreachable!("handled before loading the config"),
+    }
+}
+
+fn config_command(action: &ConfigAction, path: Option<&Path>) -> anyhow::Result<()> {
+    match action {
+        ConfigAction::Init { force } => {
+            let path = match path {
+                Some(path) => path.to_owned(),
+                None => Config::default_path().ok_or(anyhow!("No config directory found"))?,
+            };
+            Config::write_example(&path, *force)?;
+            println!("Wrote {}", path.display());
+            Ok(())
+        }
+    }
+}
+
+fn run(settings: &Settings) -> anyhow::Result<()> {
+    handle_shutdown_signals()?;
+    let mut sys = System::new();
+    let mut presence = PresenceProvider::new(&settings.config.presence)?;
+    let interval = settings.interval_or(DEFAULT_RUN_INTERVAL);
+
+    while !shutting_down() {
+        let pid = match settings.pid {
+            Some(pid) => pid,
+            None => match wait_for_process(&mut sys, &settings.config.process) {
+                Some(pid) => pid,
+                None => break,
+            },
+        };
+        match run_session(&mut sys, pid, &mut presence, interval) {
+            Ok(()) if shutting_down() => {}
+            Ok(()) => info!("Cogmind (pid {}) exited", pid),
+            Err(e) => error!("Lost Cogmind (pid {}): {}", pid, e),
+        }
+
+        // Don't leave a stale map on the profile, whether the game or we are exiting
+        if let Err(e) = presence.close() {
+            warn!("Error clearing state: {}", e);
+        }
+
+        // An explicit pid can't come back, so there is nothing left to wait for
+        if settings.pid.is_some() {
+            break;
+        }
+    }
+
+    if shutting_down() {
+        info!("Shutting down");
+    }
+    Ok(())
+}
+
+fn dump(settings: &Settings, output: Option<&Path>) -> anyhow::Result<()> {
+    let (source, mut locator) = open_source(settings)?;
+    let state =
+        GameState::read_consistent(source.as_ref(), &mut locator, &mut ReadStats::default())?;
+
+    match output {
+        Some(path) => {
+            let file = File::create(path)?;
+            serde_json::to_writer_pretty(file, &state)?;
+            info!("Wrote game state to {}", path.display());
         }
-    } else {
-        error!("No process found...");
+        None => {
+            let mut stdout = io::stdout().lock();
+            serde_json::to_writer_pretty(&mut stdout, &state)?;
+            writeln!(stdout)?;
+        }
+    }
+    Ok(())
+}
+
+fn watch(settings: &Settings) -> anyhow::Result<()> {
+    handle_shutdown_signals()?;
+    let (source, mut locator) = open_source(settings)?;
+    let interval = settings.interval_or(DEFAULT_WATCH_INTERVAL);
+    let mut stats = ReadStats::default();
+
+    while !shutting_down() {
+        let mut readout =
+            match GameState::read_consistent(source.as_ref(), &mut locator, &mut stats) {
+                Ok(state) => describe(&state),
+                Err(e) => format!("Error reading Cogmind memory: {}\n", e),
+            };
+        readout += &format!("Retried:   {} of {} reads\n", stats.retries, stats.reads);
+        // Clear the screen and home the cursor before redrawing
+        print!("\x1b[2J\x1b[H{}", readout);
+        io::stdout().flush()?;
+        sleep_unless_shutdown(interval);
     }
     Ok(())
 }
 
-fn get_presence(depth: i32, map_type: MapType) -> String {
-    let map = match map_type {
-        MapType::MapNone => "None",
-        MapType::MapSan => "Sandbox",
-        MapType::MapScr => "Junkyard",
-        MapType::MapMat => "Materials",
-        MapType::MapFac => "Factory",
-        MapType::MapRes => "Research",
-        MapType::MapAcc => "Access",
-        MapType::MapSur => "Surface",
-        MapType::MapMin => "Mines",
-        MapType::MapExi => "Exiles",
-        MapType::MapSto => "Storage",
-        MapType::MapRec => "Recycling",
-        MapType::MapWas => "Wastes",
-        MapType::MapGar => "Garrison",
-        MapType::MapDsf => "DSF",
-        MapType::MapSub => "Subcaves",
-        MapType::MapLow => "Lower Caves",
-        MapType::MapUpp => "Upper Caves",
-        MapType::MapPro => "Proxy Caves",
-        MapType::MapDee => "Deep Caves",
-        MapType::MapZio => "Zion",
-        MapType::MapDat => "Data Miner",
-        MapType::MapZhi => "Zhirov",
-        MapType::MapWar => "Warlord",
-        MapType::MapExt => "Extension",
-        MapType::MapCet => "Cetus",
-        MapType::MapArc => "Archives",
-        MapType::MapHub => "Hub_04(d)",
-        MapType::MapArm => "Armory",
-        MapType::MapLab => "Lab",
-        MapType::MapQua => "Quarantine",
-        MapType::MapTes => "Testing",
-        MapType::MapSec => "Section 7",
-        MapType::MapCom => "Command",
-        MapType::MapAc0 => "Access 0",
-        MapType::MapLai => "Abomination Lair",
-        MapType::MapTow => "Wartown",
-        MapType::MapW00 => "w0",
-        MapType::MapW01 => "w1",
-        MapType::MapW02 => "w2",
-        MapType::MapW03 => "w3",
-        MapType::MapW04 => "w4",
-        MapType::MapW05 => "w5",
-        MapType::MapW06 => "w6",
-        MapType::MapW07 => "w7",
-        MapType::MapW08 => "w8",
+fn inspect(settings: &Settings, addr: usize, len: usize) -> anyhow::Result<()> {
+    let (source, _) = open_source(settings)?;
+    let bytes = source.read(addr, len)?;
+
+    let mut stdout = io::stdout().lock();
+    for (i, row) in bytes.chunks(16).enumerate() {
+        let hex: Vec<String> = row.iter().map(|b| format!("{:02x}", b)).collect();
+        let ascii: String = row
+            .iter()
+            .map(|&b| if b.is_ascii_graphic() { b as char } else { '.' })
+            .collect();
+        writeln!(
+            stdout,
+            "0x{:08X}  {:<47}  |{}|",
+            addr + i * 16,
+            hex.join(" "),
+            ascii
+        )?;
+    }
+    Ok(())
+}
+
+fn capture(settings: &Settings, output: &Path) -> anyhow::Result<()> {
+    let (source, mut locator) = open_source(settings)?;
+    let (root, snapshot) = state::read_settled(
+        source.as_ref(),
+        &mut locator,
+        &mut ReadStats::default(),
+        |root, _| Ok((root, snapshot::capture(source.as_ref(), root)?)),
+    )?;
+    snapshot.save(output)?;
+    info!(
+        "Wrote {} segments from LuigiAi at 0x{:X} to {}",
+        snapshot.segments().count(),
+        root,
+        output.display()
+    );
+    Ok(())
+}
+
+/// Memory to read: `--snapshot`, else `--pid` or the first Cogmind found
+fn open_source(settings: &Settings) -> anyhow::Result<(Box<dyn MemorySource>, LuigiLocator)> {
+    if let Some(path) = &settings.snapshot {
+        let snapshot = SnapshotSource::open(path)
+            .with_context(|| format!("Failed to open snapshot {}", path.display()))?;
+        let locator = snapshot
+            .root()
+            .map_or_else(LuigiLocator::default, LuigiLocator::with_base);
+        return Ok((Box::new(snapshot), locator));
+    }
+
+    let pid = match settings.pid {
+        Some(pid) => pid,
+        None => process::find_cogmind(&mut System::new(), &settings.config.process)
+            .ok_or(anyhow!("No process found..."))?,
     };
+    debug!("Opening handle to process {}...", pid);
+    Ok((
+        Box::new(ProcessSource::attach(pid)?),
+        LuigiLocator::default(),
+    ))
+}
+
+/// Plain-text summary for `watch`
+fn describe(state: &GameState) -> String {
+    let ai = &state.ai;
+    let map = MapType::try_from(ai.location_map)
+        .map(|map| map.name())
+        .unwrap_or("Unknown");
+
+    let mut text = format!(
+        "Location:  {}/{} ({}x{})\n",
+        ai.location_depth, map, ai.map_width, ai.map_height
+    );
+    match &state.player {
+        Some(player) => {
+            let p = &player.data;
+            text += &format!(
+                "Integrity: {}\nEnergy:    {}\nMatter:    {}\nHeat:      {}\nCorruption: {}\nSpeed:     {}\n",
+                p.integrity, p.energy, p.matter, p.heat, p.system_corruption, p.speed
+            );
+            if let Some(propulsion) = state.propulsion() {
+                text += &format!("Propulsion: {}\n", propulsion);
+            }
+            text += "Equipped:\n";
+            for item in player.inventory.iter().filter(|item| item.equipped) {
+                let name = item.item_id().map(|id| id.name()).unwrap_or("?");
+                text += &format!("  {} ({})\n", name, item.integrity);
+            }
+        }
+        None => text += "No player\n",
+    }
+    let hostiles = state
+        .entities()
+        .filter(|(_, _, entity)| entity.relation == Relation::Hostile)
+        .count();
+    text += &format!(
+        "Entities on map: {} ({} hostile)\nItems on map: {}\n",
+        state.entities().count(),
+        hostiles,
+        state.items().count()
+    );
+    if let Some(hacking) = &state.machine_hacking {
+        text += &format!(
+            "Hacking:   detect {}% trace {}%\n",
+            hacking.detect_chance, hacking.trace_progress
+        );
+    }
+    text
+}
 
-    format!("Current map: {}/{}", depth, map)
+fn parse_address(text: &str) -> Result<usize, String> {
+    let parsed = match text.strip_prefix("0x").or(text.strip_prefix("0X")) {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => text.parse(),
+    };
+    parsed.map_err(|e| format!("invalid address {:?}: {}", text, e))
 }
 
-fn get_base_address(handle: &ProcessHandle) -> anyhow::Result<usize, Error> {
-    let check_value = 0x64AD_FA4C;
-    let start_address = 0xC0_0000;
-    let end_address = 0xFFFF_FFFF; // Assuming a 32-bit address space for simplicity
+/// Block until a Cogmind with `-luigiAi` shows up, backing off between looks;
+/// `None` if we are asked to shut down first
+fn wait_for_process(sys: &mut System, config: &ProcessConfig) -> Option<Pid> {
+    let mut delay = MIN_WAIT;
+    info!("Waiting for Cogmind to start...");
 
-    // Iterate over each possible address starting from 0x40000
-    for address in (start_address..=end_address).step_by(mem::size_of::<u32>()) {
-        // Attempt to copy 4 bytes (size of u32) from the current address
-        match copy_address(address, mem::size_of::<u32>(), handle) {
-            Ok(bytes) if bytes.len() == mem::size_of::<u32>() => {
-                // Convert the bytes to an i32 using little-endian byte order
-                let val = i32::from_le_bytes(bytes.try_into().unwrap());
+    while !shutting_down() {
+        if let Some(pid) = process::find_cogmind(sys, config) {
+            info!("Found Cogmind (pid {})", pid);
+            return Some(pid);
+        }
+        debug!("No process found, retrying in {:?}", delay);
+        sleep_unless_shutdown(delay);
+        delay = (delay * 2).min(MAX_WAIT);
+    }
+    None
+}
 
-                // Check if the value matches the check_value
-                if val == check_value {
-                    // If it matches, return the current address
-                    return Ok(address);
+/// Publish the current map until the game goes away
+fn run_session(
+    sys: &mut System,
+    pid: Pid,
+    presence: &mut PresenceProvider,
+    interval: time::Duration,
+) -> anyhow::Result<()> {
+    debug!("Opening handle to process...");
+    let source = ProcessSource::attach(pid)?;
+    let mut locator = LuigiLocator::default();
+    let mut stats = ReadStats::default();
+
+    while !shutting_down() && process::is_running(sys, pid) {
+        debug!("Reading Cogmind process memory...");
+        let delay = match GameState::read_consistent(&source, &mut locator, &mut stats) {
+            Ok(state) => {
+                let text = presence.render(&state);
+                // The provider reconnects and resends on its own, so keep the normal pace
+                match presence.set_activity(&text) {
+                    Ok(true) => info!("State updated! {}", text.state),
+                    Ok(false) => {}
+                    Err(e) => warn!("Presence not updated: {}", e),
                 }
-            }
-            Ok(_) => {
-                // If we did not get exactly 4 bytes, continue to the next address
-                continue;
+                interval
             }
             Err(e) => {
-                eprintln!("Error reading from address 0x{:X}: {}", address, e);
-                continue;
+                warn!("Error reading Cogmind memory: {}", e);
+                // Still deliver whatever was held back by the rate limit
+                if let Err(e) = presence.poll() {
+                    warn!("Presence not updated: {}", e);
+                }
+                time::Duration::from_secs(5)
             }
+        };
+        sleep_while_running(sys, pid, delay);
+    }
+
+    info!(
+        "Retried {} of {} reads because the game was mid-turn",
+        stats.retries, stats.reads
+    );
+    Ok(())
+}
+
+/// Sleep for `duration`, waking early if the process exits or we are shutting down
+fn sleep_while_running(sys: &mut System, pid: Pid, duration: time::Duration) {
+    let deadline = time::Instant::now() + duration;
+    while !shutting_down() && process::is_running(sys, pid) {
+        let remaining = deadline.saturating_duration_since(time::Instant::now());
+        if remaining.is_zero() {
+            break;
         }
+        sleep_unless_shutdown(remaining.min(EXIT_CHECK_INTERVAL));
     }
+}
 
-    // If we reach this point, it means we did not find the check_value at any address
-    Err(anyhow!("Could not find base address"))
+/// Sleep for `duration`, waking early on shutdown
+fn sleep_unless_shutdown(duration: time::Duration) {
+    let deadline = time::Instant::now() + duration;
+    while !shutting_down() {
+        let remaining = deadline.saturating_duration_since(time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        thread::sleep(remaining.min(SHUTDOWN_CHECK_INTERVAL));
+    }
 }
 
-fn get_luigi_map(handle: &ProcessHandle) -> Result<String, Error> {
-    let bytes = copy_address(get_base_address(handle)?, mem::size_of::<LuigiAi>(), handle)?;
-    let val: LuigiAi = LuigiAi::from(&bytes);
-    let map_type =
-        MapType::try_from(val.location_map).map_err(|_e| anyhow!("Failed to convert map type!"))?;
-    Ok(get_presence(val.location_depth, map_type))
+fn shutting_down() -> bool {
+    SHUTDOWN.load(Ordering::Relaxed)
+}
+
+/// Turn SIGINT/SIGTERM into a graceful shutdown; a second signal exits at once
+fn handle_shutdown_signals() -> anyhow::Result<()> {
+    ctrlc::set_handler(|| {
+        if SHUTDOWN.swap(true, Ordering::Relaxed) {
+            std_process::exit(130);
+        }
+    })
+    .context("Failed to install signal handler")
 }
 
 #[cfg(target_os = "macos")]
@@ -0,0 +1,296 @@
+use crate::regions::{self, Region};
+use anyhow::{anyhow, bail};
+use read_process_memory::{copy_address, Pid, ProcessHandle};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Anything the `LuigiAi` decoders can read bytes out of
+pub trait MemorySource {
+    /// Read exactly `len` bytes starting at `addr`
+    fn read(&self, addr: usize, len: usize) -> anyhow::Result<Vec<u8>>;
+
+    /// Read every `(addr, len)` in `requests`, one result per request
+    ///
+    /// Sources that can gather several ranges in one call should override this.
+    fn read_many(&self, requests: &[(usize, usize)]) -> Vec<anyhow::Result<Vec<u8>>> {
+        requests
+            .iter()
+            .map(|&(addr, len)| self.read(addr, len))
+            .collect()
+    }
+
+    /// Readable regions worth scanning for the `LuigiAi` magic
+    fn regions(&self) -> anyhow::Result<Vec<Region>>;
+
+    /// Whether the memory can change between reads, as in a running game
+    fn is_live(&self) -> bool {
+        false
+    }
+}
+
+/// Most ranges `process_vm_readv` takes in one call (`UIO_MAXIOV`)
+#[cfg(target_os = "linux")]
+const MAX_IOVECS: usize = 1024;
+
+/// A running Cogmind process
+pub struct ProcessSource {
+    pid: Pid,
+    handle: ProcessHandle,
+}
+
+impl ProcessSource {
+    pub fn attach(pid: Pid) -> anyhow::Result<Self> {
+        let handle: ProcessHandle = pid.try_into()?;
+        Ok(Self { pid, handle })
+    }
+
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+}
+
+impl MemorySource for ProcessSource {
+    fn read(&self, addr: usize, len: usize) -> anyhow::Result<Vec<u8>> {
+        Ok(copy_address(addr, len, &self.handle)?)
+    }
+
+    /// One `process_vm_readv` per batch of ranges instead of one per range
+    #[cfg(target_os = "linux")]
+    fn read_many(&self, requests: &[(usize, usize)]) -> Vec<anyhow::Result<Vec<u8>>> {
+        let mut results = Vec::with_capacity(requests.len());
+        for batch in requests.chunks(MAX_IOVECS) {
+            let mut buffers: Vec<Vec<u8>> = batch.iter().map(|&(_, len)| vec![0; len]).collect();
+            let local: Vec<libc::iovec> = buffers
+                .iter_mut()
+                .map(|buffer| libc::iovec {
+                    iov_base: buffer.as_mut_ptr().cast(),
+                    iov_len: buffer.len(),
+                })
+                .collect();
+            let remote: Vec<libc::iovec> = batch
+                .iter()
+                .map(|&(addr, len)| libc::iovec {
+                    iov_base: addr as *mut libc::c_void,
+                    iov_len: len,
+                })
+                .collect();
+            // SAFETY: each local iovec points into a live buffer of exactly its length
+            let read = unsafe {
+                libc::process_vm_readv(
+                    self.pid,
+                    local.as_ptr(),
+                    local.len() as libc::c_ulong,
+                    remote.as_ptr(),
+                    remote.len() as libc::c_ulong,
+                    0,
+                )
+            };
+
+            // The kernel stops at the first unreadable range; read the rest
+            // one by one so each gets its own result
+            let mut left = read.max(0) as usize;
+            for (&(addr, len), buffer) in batch.iter().zip(buffers) {
+                if left >= len {
+                    left -= len;
+                    results.push(Ok(buffer));
+                } else {
+                    left = 0;
+                    results.push(self.read(addr, len));
+                }
+            }
+        }
+        results
+    }
+
+    fn regions(&self) -> anyhow::Result<Vec<Region>> {
+        regions::readable_regions(self.pid)
+    }
+
+    fn is_live(&self) -> bool {
+        true
+    }
+}
+
+/// Fixed blocks of memory keyed by their start address
+#[derive(Debug, Default, Clone)]
+pub struct MockSource {
+    segments: BTreeMap<usize, Vec<u8>>,
+}
+
+impl MockSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `bytes` at `addr`, replacing any segment that already starts there
+    pub fn insert(&mut self, addr: usize, bytes: Vec<u8>) {
+        self.segments.insert(addr, bytes);
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = (usize, &[u8])> {
+        self.segments
+            .iter()
+            .map(|(addr, bytes)| (*addr, bytes.as_slice()))
+    }
+}
+
+impl MemorySource for MockSource {
+    fn read(&self, addr: usize, len: usize) -> anyhow::Result<Vec<u8>> {
+        let (start, bytes) = self
+            .segments
+            .range(..=addr)
+            .next_back()
+            .ok_or(anyhow!("Address 0x{:X} is not mapped", addr))?;
+        let offset = addr - start;
+        bytes
+            .get(offset..offset + len)
+            .map(|slice| slice.to_vec())
+            .ok_or(anyhow!(
+                "Read of {} bytes at 0x{:X} runs past the mapped segment",
+                len,
+                addr
+            ))
+    }
+
+    fn regions(&self) -> anyhow::Result<Vec<Region>> {
+        Ok(self
+            .segments()
+            .filter_map(|(addr, bytes)| {
+                Region {
+                    start: addr,
+                    end: addr + bytes.len(),
+                }
+                .clamp()
+            })
+            .collect())
+    }
+}
+
+const SNAPSHOT_MAGIC: &[u8; 8] = b"STATMIND";
+const SNAPSHOT_VERSION: u32 = 2;
+
+/// Memory saved to disk so it can be decoded without the game running
+///
+/// The file is the magic and a little-endian `u32` version. Version 2 follows
+/// with the `LuigiAi` address as a `u64` (0 if unknown) and a `u32` segment
+/// count, then each segment as a `u16`-prefixed UTF-8 label, a `u64` address,
+/// a `u64` length and its bytes. Version 1 has no root address or labels.
+#[derive(Debug, Default, Clone)]
+pub struct SnapshotSource {
+    memory: MockSource,
+    root: Option<usize>,
+    labels: BTreeMap<usize, String>,
+}
+
+impl SnapshotSource {
+    pub fn new(root: Option<usize>) -> Self {
+        Self {
+            root,
+            ..Self::default()
+        }
+    }
+
+    /// Add a segment; `label` says what the bytes are, e.g. `tiles`
+    pub fn insert(&mut self, addr: usize, label: &str, bytes: Vec<u8>) {
+        self.memory.insert(addr, bytes);
+        self.labels.insert(addr, label.to_owned());
+    }
+
+    /// Address of the `LuigiAi` block, if the snapshot recorded it
+    pub fn root(&self) -> Option<usize> {
+        self.root
+    }
+
+    /// Address, label and bytes of every segment, in address order
+    pub fn segments(&self) -> impl Iterator<Item = (usize, &str, &[u8])> {
+        self.memory.segments().map(|(addr, bytes)| {
+            let label = self.labels.get(&addr).map_or("", String::as_str);
+            (addr, label, bytes)
+        })
+    }
+
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let data = fs::read(path)?;
+        let mut reader = SnapshotReader { data: &data };
+
+        if reader.take(SNAPSHOT_MAGIC.len())? != SNAPSHOT_MAGIC {
+            bail!("{} is not a statmind snapshot", path.display());
+        }
+        let version = reader.u32()?;
+        let mut snapshot = match version {
+            1 => Self::new(None),
+            2 => Self::new(Some(reader.u64()? as usize).filter(|&root| root != 0)),
+            _ => bail!("Unsupported snapshot version {}", version),
+        };
+
+        for _ in 0..reader.u32()? {
+            let label = match version {
+                1 => "",
+                _ => {
+                    let len = reader.u16()? as usize;
+                    std::str::from_utf8(reader.take(len)?)?
+                }
+            };
+            let addr = reader.u64()? as usize;
+            let len = reader.u64()? as usize;
+            snapshot.insert(addr, label, reader.take(len)?.to_vec());
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot to `path` in the current format
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut data = Vec::new();
+        data.extend_from_slice(SNAPSHOT_MAGIC);
+        data.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+        data.extend_from_slice(&(self.root.unwrap_or(0) as u64).to_le_bytes());
+        data.extend_from_slice(&(self.labels.len() as u32).to_le_bytes());
+        for (addr, label, bytes) in self.segments() {
+            data.extend_from_slice(&(label.len() as u16).to_le_bytes());
+            data.extend_from_slice(label.as_bytes());
+            data.extend_from_slice(&(addr as u64).to_le_bytes());
+            data.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+            data.extend_from_slice(bytes);
+        }
+        Ok(fs::write(path, data)?)
+    }
+}
+
+impl MemorySource for SnapshotSource {
+    fn read(&self, addr: usize, len: usize) -> anyhow::Result<Vec<u8>> {
+        self.memory.read(addr, len)
+    }
+
+    fn regions(&self) -> anyhow::Result<Vec<Region>> {
+        self.memory.regions()
+    }
+}
+
+struct SnapshotReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> SnapshotReader<'a> {
+    fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        if self.data.len() < len {
+            bail!("Snapshot is truncated");
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn u16(&mut self) -> anyhow::Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into()?))
+    }
+
+    fn u32(&mut self) -> anyhow::Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn u64(&mut self) -> anyhow::Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::config::ProcessConfig;
+use read_process_memory::Pid;
+use sysinfo::{PidExt, Process, ProcessExt, ProcessRefreshKind, System, SystemExt};
+
+/// Find a Cogmind started with the configured argument, trying each name in order
+pub fn find_cogmind(sys: &mut System, config: &ProcessConfig) -> Option<Pid> {
+    sys.refresh_processes_specifics(ProcessRefreshKind::new());
+
+    config.names.iter().find_map(|name| {
+        let name = name.to_lowercase();
+        sys.processes()
+            .values()
+            .find(|proc| {
+                proc.name().to_lowercase().contains(&name) && proc.cmd().contains(&config.argument)
+            })
+            .map(|proc| proc.pid().as_u32() as Pid)
+    })
+}
+
+/// Whether the process we attached to is still around
+pub fn is_running(sys: &mut System, pid: Pid) -> bool {
+    let pid = sysinfo::Pid::from_u32(pid as u32);
+    sys.refresh_process_specifics(pid, ProcessRefreshKind::new())
+        && sys.process(pid).is_some_and(is_alive)
+}
+
+fn is_alive(proc: &Process) -> bool {
+    !matches!(
+        proc.status(),
+        sysinfo::ProcessStatus::Zombie | sysinfo::ProcessStatus::Dead
+    )
+}
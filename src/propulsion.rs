@@ -0,0 +1,157 @@
+//! Which kind of propulsion the player is moving on
+//!
+//! The item list keeps each propulsion class in one contiguous block of IDs, so
+//! an item is classified by the block its ID falls in.
+
+use crate::generated::ItemId;
+use crate::types::LuigiItem;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Propulsion {
+    Treads,
+    Legs,
+    Wheels,
+    Hover,
+    Flight,
+}
+
+impl Propulsion {
+    /// Slowest first, which is also how Cogmind breaks ties
+    pub const ALL: [Propulsion; 5] = [
+        Self::Treads,
+        Self::Legs,
+        Self::Wheels,
+        Self::Hover,
+        Self::Flight,
+    ];
+
+    pub fn of(item: ItemId) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.ids().contains(&item.id()))
+    }
+
+    /// The propulsion Cogmind moves on: the kind with the most equipped, intact
+    /// parts, the slower kind on a tie
+    pub fn active(inventory: &[LuigiItem]) -> Option<Self> {
+        let mut counts = [0usize; Self::ALL.len()];
+        for item in inventory
+            .iter()
+            .filter(|item| item.equipped && item.integrity > 0)
+        {
+            if let Some(kind) = item.item_id().and_then(Self::of) {
+                counts[kind as usize] += 1;
+            }
+        }
+        // max_by_key keeps the last maximum, so walk from the fastest kind down
+        Self::ALL
+            .into_iter()
+            .rev()
+            .filter(|&kind| counts[kind as usize] > 0)
+            .max_by_key(|&kind| counts[kind as usize])
+    }
+
+    /// Lowercase name used as a config key
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Treads => "treads",
+            Self::Legs => "legs",
+            Self::Wheels => "wheels",
+            Self::Hover => "hover",
+            Self::Flight => "flight",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Treads => "Treads",
+            Self::Legs => "Legs",
+            Self::Wheels => "Wheels",
+            Self::Hover => "Hover",
+            Self::Flight => "Flight",
+        }
+    }
+
+    fn ids(&self) -> RangeInclusive<i32> {
+        let (first, last) = match self {
+            Self::Treads => (ItemId::Lgt__Treads, ItemId::Exp__Biometal_Medium_Treads),
+            Self::Legs => (ItemId::Aluminum_Leg, ItemId::Sigix_Exoskeleton),
+            Self::Wheels => (ItemId::Wheel, ItemId::Hdn__Centrium_Wheel),
+            Self::Hover => (ItemId::Hover_Unit, ItemId::Cmb__Linear_Gravjet),
+            Self::Flight => (ItemId::Flight_Unit, ItemId::Exp__Q_thruster),
+        };
+        first.id()..=last.id()
+    }
+}
+
+impl fmt::Display for Propulsion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn equipped(id: ItemId) -> LuigiItem {
+        LuigiItem {
+            id: id.id(),
+            integrity: 10,
+            equipped: true,
+        }
+    }
+
+    #[test]
+    fn classifies_items() {
+        assert_eq!(Propulsion::of(ItemId::Megatreads), Some(Propulsion::Treads));
+        assert_eq!(
+            Propulsion::of(ItemId::Arachnoskeleton),
+            Some(Propulsion::Legs)
+        );
+        assert_eq!(
+            Propulsion::of(ItemId::Sub__Monowheel),
+            Some(Propulsion::Wheels)
+        );
+        assert_eq!(
+            Propulsion::of(ItemId::Backup_Propulsion_I),
+            Some(Propulsion::Hover)
+        );
+        assert_eq!(
+            Propulsion::of(ItemId::Biomechanical_Wings),
+            Some(Propulsion::Flight)
+        );
+        assert_eq!(Propulsion::of(ItemId::SE_PR1), None);
+        assert_eq!(Propulsion::of(ItemId::Matter_Drive), None);
+    }
+
+    #[test]
+    fn most_parts_win_and_ties_go_slow() {
+        let flight = equipped(ItemId::Flight_Unit);
+        let hover = equipped(ItemId::Hover_Unit);
+        assert_eq!(Propulsion::active(&[]), None);
+        assert_eq!(
+            Propulsion::active(&[flight.clone(), flight.clone(), hover.clone()]),
+            Some(Propulsion::Flight)
+        );
+        assert_eq!(
+            Propulsion::active(&[flight.clone(), hover.clone()]),
+            Some(Propulsion::Hover)
+        );
+    }
+
+    #[test]
+    fn ignores_unequipped_and_broken_parts() {
+        let mut stored = equipped(ItemId::Wheel);
+        stored.equipped = false;
+        let mut broken = equipped(ItemId::Wheel);
+        broken.integrity = 0;
+        let legs = equipped(ItemId::Aluminum_Leg);
+        assert_eq!(
+            Propulsion::active(&[stored, broken, legs]),
+            Some(Propulsion::Legs)
+        );
+    }
+}
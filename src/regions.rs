@@ -0,0 +1,155 @@
+use read_process_memory::Pid;
+
+/// Lowest address the `LuigiAi` block can live at
+pub const SCAN_START: usize = 0xC0_0000;
+/// Cogmind is a 32-bit program, so nothing it points to lives above this
+pub const SCAN_END: usize = 0xFFFF_FFFF;
+/// Exclusive end of the scan window, which doesn't fit a 32-bit `usize`
+const WINDOW_END: u64 = SCAN_END as u64 + 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Region {
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Clamp the region to the 32-bit scan window
+    pub fn clamp(&self) -> Option<Region> {
+        let region = Region {
+            start: self.start.max(SCAN_START),
+            end: window_end().min(self.end),
+        };
+        (!region.is_empty()).then_some(region)
+    }
+}
+
+/// List the readable memory regions of a process inside the scan window
+#[cfg(target_os = "linux")]
+pub fn readable_regions(pid: Pid) -> anyhow::Result<Vec<Region>> {
+    let maps = std::fs::read_to_string(format!("/proc/{}/maps", pid))?;
+    Ok(parse_maps(&maps)
+        .into_iter()
+        .filter_map(|r| r.clamp())
+        .collect())
+}
+
+/// Without a region listing we fall back to sweeping the whole window
+#[cfg(not(target_os = "linux"))]
+pub fn readable_regions(_pid: Pid) -> anyhow::Result<Vec<Region>> {
+    Ok(vec![Region {
+        start: SCAN_START,
+        end: window_end(),
+    }])
+}
+
+/// `WINDOW_END` as an address; a 32-bit host stops one byte short, where no
+/// `LuigiAi` block could start anyway
+fn window_end() -> usize {
+    usize::try_from(WINDOW_END).unwrap_or(usize::MAX)
+}
+
+/// Parse the readable entries of a `/proc/<pid>/maps` listing
+#[cfg(target_os = "linux")]
+fn parse_maps(maps: &str) -> Vec<Region> {
+    let mut regions = Vec::new();
+
+    for line in maps.lines() {
+        let mut fields = line.split_whitespace();
+        let (Some(range), Some(perms)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if !perms.starts_with('r') {
+            continue;
+        }
+        let Some((start, end)) = range.split_once('-') else {
+            continue;
+        };
+        match (
+            usize::from_str_radix(start, 16),
+            usize::from_str_radix(end, 16),
+        ) {
+            (Ok(start), Ok(end)) => regions.push(Region { start, end }),
+            _ => debug!("Skipping malformed maps line: {}", line),
+        }
+    }
+
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_maps_keeps_readable_entries() {
+        let maps = "\
+00400000-00452000 r-xp 00000000 08:02 173521 /usr/bin/wine
+00651000-00652000 ---p 00051000 08:02 173521 /usr/bin/wine
+00652000-00655000 rw-p 00052000 08:02 173521 /usr/bin/wine
+garbage
+zz000000-00700000 rw-p 00000000 00:00 0
+00700000 rw-p 00000000 00:00 0
+7ffd1000-7ffd3000 rw-p 00000000 00:00 0 [stack]
+";
+        assert_eq!(
+            parse_maps(maps),
+            [
+                Region {
+                    start: 0x40_0000,
+                    end: 0x45_2000
+                },
+                Region {
+                    start: 0x65_2000,
+                    end: 0x65_5000
+                },
+                Region {
+                    start: 0x7FFD_1000,
+                    end: 0x7FFD_3000
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn clamp_keeps_regions_inside_the_scan_window() {
+        let clamp = |start, end| Region { start, end }.clamp();
+
+        assert_eq!(clamp(0x40_0000, 0x50_0000), None);
+        assert_eq!(
+            clamp(0x40_0000, SCAN_START + 0x1000),
+            Some(Region {
+                start: SCAN_START,
+                end: SCAN_START + 0x1000
+            })
+        );
+        assert_eq!(
+            clamp(0x1000_0000, 0x2000_0000),
+            Some(Region {
+                start: 0x1000_0000,
+                end: 0x2000_0000
+            })
+        );
+    }
+
+    /// Regions above 4 GiB only exist on 64-bit hosts
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn clamp_cuts_regions_at_4_gib() {
+        let clamp = |start, end| Region { start, end }.clamp();
+
+        assert_eq!(
+            clamp(0xFFFF_0000, 0x1_0001_0000),
+            Some(Region {
+                start: 0xFFFF_0000,
+                end: 0x1_0000_0000
+            })
+        );
+        assert_eq!(clamp(0x1_0000_0000, 0x1_0001_0000), None);
+    }
+}
@@ -0,0 +1,111 @@
+//! Copy everything reachable from `LuigiAi` into a [`SnapshotSource`]
+
+use crate::locator::read_luigi_ai;
+use crate::memory::{MemorySource, SnapshotSource};
+use crate::state::MAX_INVENTORY_SIZE;
+use crate::types::{
+    Decode, LuigiAi, LuigiEntity, LuigiItem, LuigiMachineHacking, LuigiProp, LuigiTile,
+};
+use anyhow::{bail, Context};
+use std::collections::BTreeMap;
+
+/// Save the `LuigiAi` block at `root` and every block it points to, labelled by type
+pub fn capture(source: &dyn MemorySource, root: usize) -> anyhow::Result<SnapshotSource> {
+    let ai = read_luigi_ai(source, root)?;
+    let mut blocks = Blocks::default();
+    blocks.add(root, LuigiAi::SIZE, "LuigiAi");
+    // Before the tiles, so the entity standing for the player keeps that label
+    blocks.add_entity(source, ai.player, "player")?;
+    blocks.add_pointer(
+        ai.machine_hacking,
+        LuigiMachineHacking::SIZE,
+        "machine_hacking",
+    );
+
+    let tile_count = (ai.map_width * ai.map_height) as usize;
+    if tile_count > 0 {
+        let len = tile_count * LuigiTile::SIZE;
+        blocks.add(ai.map_data as usize, len, "tiles");
+        let bytes = source
+            .read(ai.map_data as usize, len)
+            .context("Failed to read tiles")?;
+        for chunk in bytes.chunks_exact(LuigiTile::SIZE) {
+            let tile = LuigiTile::decode(chunk)?;
+            blocks.add_pointer(tile.prop, LuigiProp::SIZE, "prop");
+            blocks.add_pointer(tile.item, LuigiItem::SIZE, "item");
+            blocks.add_entity(source, tile.entity, "entity")?;
+        }
+    }
+
+    let mut snapshot = SnapshotSource::new(Some(root));
+    for (addr, (len, label)) in blocks.merged() {
+        let bytes = source
+            .read(addr, len)
+            .with_context(|| format!("Failed to read {} at 0x{:X}", label, addr))?;
+        snapshot.insert(addr, &label, bytes);
+    }
+    Ok(snapshot)
+}
+
+/// Address ranges to save, with what they hold
+#[derive(Default)]
+struct Blocks {
+    ranges: BTreeMap<usize, (usize, String)>,
+}
+
+impl Blocks {
+    fn add(&mut self, addr: usize, len: usize, label: &str) {
+        let (old_len, _) = self
+            .ranges
+            .entry(addr)
+            .or_insert_with(|| (len, label.to_owned()));
+        *old_len = (*old_len).max(len);
+    }
+
+    fn add_pointer(&mut self, ptr: u32, len: usize, label: &str) {
+        if ptr != 0 {
+            self.add(ptr as usize, len, label);
+        }
+    }
+
+    /// An entity and its inventory array
+    fn add_entity(
+        &mut self,
+        source: &dyn MemorySource,
+        ptr: u32,
+        label: &str,
+    ) -> anyhow::Result<()> {
+        if ptr == 0 {
+            return Ok(());
+        }
+        self.add(ptr as usize, LuigiEntity::SIZE, label);
+        let bytes = source.read(ptr as usize, LuigiEntity::SIZE)?;
+        let entity = LuigiEntity::decode(&bytes)?;
+        if !(0..=MAX_INVENTORY_SIZE).contains(&entity.inventory_size) {
+            bail!("Implausible inventory size {}", entity.inventory_size);
+        }
+        let len = entity.inventory_size as usize * LuigiItem::SIZE;
+        if len > 0 {
+            self.add_pointer(entity.inventory, len, "inventory");
+        }
+        Ok(())
+    }
+
+    /// Overlapping ranges joined, so every read the decoders make falls inside one segment
+    fn merged(&self) -> Vec<(usize, (usize, String))> {
+        let mut merged: Vec<(usize, (usize, String))> = Vec::new();
+        for (&addr, (len, label)) in &self.ranges {
+            match merged.last_mut() {
+                Some((start, (merged_len, labels))) if addr < *start + *merged_len => {
+                    *merged_len = (*merged_len).max(addr + len - *start);
+                    if !labels.split('+').any(|known| known == label) {
+                        labels.push('+');
+                        labels.push_str(label);
+                    }
+                }
+                _ => merged.push((addr, (*len, label.clone()))),
+            }
+        }
+        merged
+    }
+}
@@ -0,0 +1,410 @@
+use crate::locator::LuigiLocator;
+use crate::memory::MemorySource;
+use crate::propulsion::Propulsion;
+use crate::types::{
+    ActiveState, Decode, LuigiAi, LuigiEntity, LuigiItem, LuigiMachineHacking, LuigiProp,
+    LuigiTile, Relation,
+};
+use anyhow::{anyhow, bail, Context};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::thread;
+use std::time::Duration;
+
+/// Far more slots than any build can have; anything above is garbage
+pub(crate) const MAX_INVENTORY_SIZE: i32 = 256;
+
+/// Reads of one poll before giving up on the game holding still
+const MAX_READ_ATTEMPTS: u64 = 10;
+/// Long enough for the game to finish most turns
+const RETRY_DELAY: Duration = Duration::from_millis(20);
+
+/// Everything reachable from the `LuigiAi` root, copied out of game memory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameState {
+    pub ai: LuigiAi,
+    /// Row-major, `map_width * map_height` tiles
+    pub tiles: Vec<Tile>,
+    pub player: Option<Entity>,
+    pub machine_hacking: Option<LuigiMachineHacking>,
+}
+
+/// How often reads had to be repeated because the game was mid-turn
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReadStats {
+    /// Calls to [`read_settled`]
+    pub reads: u64,
+    /// Reads thrown away and done again
+    pub retries: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Tile {
+    pub last_action: i32,
+    pub last_fov: i32,
+    pub cell: i32,
+    pub door_open: bool,
+    pub prop: Option<LuigiProp>,
+    pub entity: Option<Entity>,
+    pub item: Option<LuigiItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Entity {
+    pub data: LuigiEntity,
+    pub relation: Relation,
+    pub active_state: ActiveState,
+    pub inventory: Vec<LuigiItem>,
+}
+
+impl GameState {
+    /// Follow every pointer hanging off `ai` and decode what it points to
+    ///
+    /// The tile array is read in one go, then the records it points to in two
+    /// batches: props, items, entities and the hacking state, then inventories.
+    /// Pointers shared between tiles are only read once.
+    pub fn read(source: &dyn MemorySource, ai: LuigiAi) -> anyhow::Result<Self> {
+        ai.validate()?;
+
+        let tile_count = (ai.map_width * ai.map_height) as usize;
+        let raw_tiles = if tile_count > 0 {
+            let bytes = source
+                .read(ai.map_data as usize, tile_count * LuigiTile::SIZE)
+                .context("Failed to read tiles")?;
+            bytes
+                .chunks_exact(LuigiTile::SIZE)
+                .map(LuigiTile::decode)
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
+
+        let mut records = Records::default();
+        records.want_entity(ai.player);
+        records.want(ai.machine_hacking, LuigiMachineHacking::SIZE);
+        for tile in &raw_tiles {
+            records.want(tile.prop, LuigiProp::SIZE);
+            records.want(tile.item, LuigiItem::SIZE);
+            records.want_entity(tile.entity);
+        }
+        records.fetch(source);
+
+        let mut inventories = Records::default();
+        for &ptr in &records.entities {
+            let data: LuigiEntity = records.decode(ptr)?;
+            if !(0..=MAX_INVENTORY_SIZE).contains(&data.inventory_size) {
+                bail!(
+                    "Implausible inventory size {} at 0x{:X}",
+                    data.inventory_size,
+                    ptr
+                );
+            }
+            inventories.want(
+                data.inventory,
+                data.inventory_size as usize * LuigiItem::SIZE,
+            );
+        }
+        inventories.fetch(source);
+
+        let entity = |ptr: u32| -> anyhow::Result<Option<Entity>> {
+            if ptr == 0 {
+                return Ok(None);
+            }
+            let data: LuigiEntity = records.decode(ptr)?;
+            let inventory = match (data.inventory, data.inventory_size) {
+                (0, _) | (_, 0) => Vec::new(),
+                (inventory, _) => inventories
+                    .bytes(inventory)?
+                    .chunks_exact(LuigiItem::SIZE)
+                    .map(LuigiItem::decode)
+                    .collect::<Result<_, _>>()?,
+            };
+            Ok(Some(Entity {
+                relation: data.relation(),
+                active_state: data.active_state(),
+                data,
+                inventory,
+            }))
+        };
+
+        let tiles = raw_tiles
+            .into_iter()
+            .map(|tile| {
+                Ok(Tile {
+                    last_action: tile.last_action,
+                    last_fov: tile.last_fov,
+                    cell: tile.cell,
+                    door_open: tile.door_open,
+                    prop: records.decode_pointer(tile.prop)?,
+                    entity: entity(tile.entity)?,
+                    item: records.decode_pointer(tile.item)?,
+                })
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        let player = entity(ai.player)?;
+        let machine_hacking = records.decode_pointer(ai.machine_hacking)?;
+
+        Ok(Self {
+            ai,
+            tiles,
+            player,
+            machine_hacking,
+        })
+    }
+
+    /// Read the state, retrying while the game changes it underneath us
+    ///
+    /// See [`read_settled`].
+    pub fn read_consistent(
+        source: &dyn MemorySource,
+        locator: &mut LuigiLocator,
+        stats: &mut ReadStats,
+    ) -> anyhow::Result<Self> {
+        read_settled(source, locator, stats, |_, ai| Self::read(source, ai))
+    }
+
+    pub fn tile(&self, x: i32, y: i32) -> Option<&Tile> {
+        if !(0..self.ai.map_width).contains(&x) || !(0..self.ai.map_height).contains(&y) {
+            return None;
+        }
+        self.tiles.get((y * self.ai.map_width + x) as usize)
+    }
+
+    /// What the player is moving on, if anything
+    pub fn propulsion(&self) -> Option<Propulsion> {
+        self.player
+            .as_ref()
+            .and_then(|player| Propulsion::active(&player.inventory))
+    }
+
+    /// Entities standing on the map, with their coordinates
+    pub fn entities(&self) -> impl Iterator<Item = (i32, i32, &Entity)> {
+        self.positioned(|tile| tile.entity.as_ref())
+    }
+
+    /// Items lying on the map, with their coordinates
+    pub fn items(&self) -> impl Iterator<Item = (i32, i32, &LuigiItem)> {
+        self.positioned(|tile| tile.item.as_ref())
+    }
+
+    fn positioned<'a, T: 'a>(
+        &'a self,
+        get: impl Fn(&'a Tile) -> Option<&'a T> + 'a,
+    ) -> impl Iterator<Item = (i32, i32, &'a T)> {
+        let width = self.ai.map_width.max(1);
+        self.tiles.iter().enumerate().filter_map(move |(i, tile)| {
+            get(tile).map(|val| (i as i32 % width, i as i32 / width, val))
+        })
+    }
+}
+
+/// Records to read in one batch, keyed by the game pointer to them
+/// Run `read` on the `LuigiAi` block, retrying while the game changes it underneath us
+///
+/// `action_ready` and the magic are sampled before and after the read. A
+/// read is kept only when both samples match and `action_ready` is set,
+/// meaning no turn was being processed. Discarded reads count as retries
+/// in `stats`. Sources that aren't [live](MemorySource::is_live) can't change,
+/// so they are read once as they are.
+pub fn read_settled<T>(
+    source: &dyn MemorySource,
+    locator: &mut LuigiLocator,
+    stats: &mut ReadStats,
+    mut read: impl FnMut(usize, LuigiAi) -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    stats.reads += 1;
+    if !source.is_live() {
+        let (base, ai) = locator.read(source)?;
+        return read(base, ai);
+    }
+    for attempt in 1..=MAX_READ_ATTEMPTS {
+        let (base, before) = locator.read(source)?;
+        let result = read(base, before.clone());
+        let after = LuigiAi::decode(&source.read(base, LuigiAi::SIZE)?)?;
+
+        let settled = before.action_ready != 0
+            && (before.magic1, before.magic2, before.action_ready)
+                == (after.magic1, after.magic2, after.action_ready);
+        if settled {
+            return result;
+        }
+        debug!(
+            "Game changed during read {} (action_ready {} -> {})",
+            attempt, before.action_ready, after.action_ready
+        );
+        if attempt < MAX_READ_ATTEMPTS {
+            stats.retries += 1;
+            thread::sleep(RETRY_DELAY);
+        }
+    }
+    bail!(
+        "Cogmind stayed mid-turn for {} reads in a row",
+        MAX_READ_ATTEMPTS
+    )
+}
+
+#[derive(Default)]
+struct Records {
+    sizes: BTreeMap<u32, usize>,
+    /// Which of the pointers are entities
+    entities: BTreeSet<u32>,
+    bytes: BTreeMap<u32, anyhow::Result<Vec<u8>>>,
+}
+
+impl Records {
+    /// Queue `len` bytes at `ptr`, keeping the longest request for a shared pointer
+    fn want(&mut self, ptr: u32, len: usize) {
+        if ptr != 0 && len > 0 {
+            let size = self.sizes.entry(ptr).or_default();
+            *size = (*size).max(len);
+        }
+    }
+
+    fn want_entity(&mut self, ptr: u32) {
+        if ptr != 0 {
+            self.want(ptr, LuigiEntity::SIZE);
+            self.entities.insert(ptr);
+        }
+    }
+
+    fn fetch(&mut self, source: &dyn MemorySource) {
+        let requests: Vec<(usize, usize)> = self
+            .sizes
+            .iter()
+            .map(|(&ptr, &len)| (ptr as usize, len))
+            .collect();
+        let results = source.read_many(&requests);
+        self.bytes = self.sizes.keys().copied().zip(results).collect();
+    }
+
+    fn bytes(&self, ptr: u32) -> anyhow::Result<&[u8]> {
+        match self.bytes.get(&ptr) {
+            Some(Ok(bytes)) => Ok(bytes),
+            Some(Err(e)) => Err(anyhow!("Failed to follow pointer 0x{:X}: {:#}", ptr, e)),
+            None => Err(anyhow!("Pointer 0x{:X} was never read", ptr)),
+        }
+    }
+
+    fn decode<T: Decode>(&self, ptr: u32) -> anyhow::Result<T> {
+        Ok(T::decode(self.bytes(ptr)?)?)
+    }
+
+    /// Decode the target of a 32-bit game pointer, mapping null to `None`
+    fn decode_pointer<T: Decode>(&self, ptr: u32) -> anyhow::Result<Option<T>> {
+        match ptr {
+            0 => Ok(None),
+            ptr => self.decode(ptr).map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MockSource;
+    use crate::types::{LUIGI_MAGIC1, LUIGI_MAGIC2};
+
+    const TILES: u32 = 0x0200_0000;
+    const PLAYER: u32 = 0x0300_0000;
+    const INVENTORY: u32 = 0x0300_1000;
+    const HACKING: u32 = 0x0400_0000;
+    const ITEM: u32 = 0x0500_0000;
+
+    fn words(values: &[i32]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    fn ai(player: u32, machine_hacking: u32) -> LuigiAi {
+        LuigiAi {
+            magic1: LUIGI_MAGIC1,
+            magic2: LUIGI_MAGIC2,
+            action_ready: 1,
+            map_width: 2,
+            map_height: 1,
+            location_depth: -10,
+            location_map: 2,
+            map_data: TILES,
+            map_cursor_index: -1,
+            player,
+            machine_hacking,
+        }
+    }
+
+    /// The player on the first tile, an item on the second
+    fn memory() -> MockSource {
+        let mut memory = MockSource::new();
+        let mut tiles = words(&[0, 7, 1, 0, 0, PLAYER as i32, 0]);
+        tiles.extend(words(&[0, 7, 2, 1, 0, 0, ITEM as i32]));
+        memory.insert(TILES as usize, tiles);
+        memory.insert(
+            PLAYER as usize,
+            words(&[1, 800, 0, 1, 0, 90, 150, 30, 2, 100, 2, INVENTORY as i32]),
+        );
+        memory.insert(INVENTORY as usize, words(&[75, 40, 1, 148, 20, 0]));
+        memory.insert(HACKING as usize, words(&[3, 25, 10, 1]));
+        memory.insert(ITEM as usize, words(&[12, 50, 0]));
+        memory
+    }
+
+    #[test]
+    fn follows_every_pointer() {
+        let state = GameState::read(&memory(), ai(PLAYER, HACKING)).unwrap();
+
+        let player = state.player.as_ref().unwrap();
+        assert_eq!(player.data.integrity, 800);
+        assert_eq!(
+            player.inventory,
+            [
+                LuigiItem {
+                    id: 75,
+                    integrity: 40,
+                    equipped: true
+                },
+                LuigiItem {
+                    id: 148,
+                    integrity: 20,
+                    equipped: false
+                },
+            ]
+        );
+        assert_eq!(
+            state.machine_hacking,
+            Some(LuigiMachineHacking {
+                action_ready: 3,
+                detect_chance: 25,
+                trace_progress: 10,
+                last_hack_success: true,
+            })
+        );
+
+        let first = state.tile(0, 0).unwrap();
+        assert_eq!(first.entity.as_ref(), Some(player));
+        assert_eq!((first.prop.as_ref(), first.item.as_ref()), (None, None));
+        let second = state.tile(1, 0).unwrap();
+        assert!(second.door_open);
+        assert_eq!(second.entity, None);
+        assert_eq!(second.item.as_ref().map(|item| item.id), Some(12));
+    }
+
+    #[test]
+    fn entities_serialize_typed_fields_once() {
+        let state = GameState::read(&memory(), ai(PLAYER, 0)).unwrap();
+        let json = serde_json::to_value(state.player.unwrap()).unwrap();
+        assert_eq!(json["relation"], "player");
+        assert_eq!(json["active_state"], "active");
+        assert_eq!(json["data"].get("relation"), None);
+        assert_eq!(json["data"].get("active_state"), None);
+    }
+
+    #[test]
+    fn maps_null_pointers_to_none() {
+        let state = GameState::read(&memory(), ai(0, 0)).unwrap();
+        assert_eq!(state.player, None);
+        assert_eq!(state.machine_hacking, None);
+        assert_eq!(state.tiles.len(), 2);
+    }
+}
@@ -0,0 +1,292 @@
+//! Presence text templates
+//!
+//! `{name}` inserts a value and `{name|fallback}` inserts `fallback` when the
+//! value is missing. `{#name}...{/name}` only renders its body when `name` has
+//! a value and `{^name}...{/name}` only when it doesn't. `{{` and `}}` are
+//! literal braces.
+
+use crate::state::GameState;
+use crate::types::{MapType, Relation};
+use anyhow::bail;
+use std::collections::BTreeMap;
+
+/// Every placeholder a template may use
+pub const PLACEHOLDERS: &[&str] = &[
+    "depth",
+    "map",
+    "map_code",
+    "integrity",
+    "energy",
+    "matter",
+    "heat",
+    "corruption",
+    "propulsion",
+    "hostiles_in_view",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Value {
+        name: String,
+        fallback: Option<String>,
+    },
+    Section {
+        name: String,
+        inverted: bool,
+        body: Vec<Part>,
+    },
+}
+
+impl Template {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut chars = text.chars().peekable();
+        // Open sections, innermost last; the bottom frame is the template itself
+        let mut stack = vec![Frame::default()];
+        let mut literal = String::new();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => bail!("unmatched '}}' (use '}}}}' for a literal brace)"),
+                '{' => {
+                    let mut tag = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(ch) => tag.push(ch),
+                            None => bail!("unclosed '{{{}'", tag),
+                        }
+                    }
+
+                    let parts = &mut stack.last_mut().unwrap().parts;
+                    if !literal.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut literal)));
+                    }
+
+                    if let Some(name) = tag.strip_prefix('#') {
+                        stack.push(Frame::section(check_name(name)?, false));
+                    } else if let Some(name) = tag.strip_prefix('^') {
+                        stack.push(Frame::section(check_name(name)?, true));
+                    } else if let Some(name) = tag.strip_prefix('/') {
+                        let name = check_name(name)?;
+                        let Frame {
+                            section,
+                            parts: body,
+                        } = stack.pop().unwrap();
+                        match section {
+                            Some((open, inverted)) if open == name => {
+                                stack.last_mut().unwrap().parts.push(Part::Section {
+                                    name,
+                                    inverted,
+                                    body,
+                                })
+                            }
+                            Some((open, _)) => {
+                                bail!("{{/{}}} closes {{{}}}", name, open)
+                            }
+                            None => bail!("{{/{}}} has no matching section", name),
+                        }
+                    } else {
+                        let (name, fallback) = match tag.split_once('|') {
+                            Some((name, fallback)) => (name, Some(fallback.to_owned())),
+                            None => (tag.as_str(), None),
+                        };
+                        parts.push(Part::Value {
+                            name: check_name(name)?,
+                            fallback,
+                        });
+                    }
+                }
+                ch => literal.push(ch),
+            }
+        }
+
+        let Frame { section, mut parts } = stack.pop().unwrap();
+        if let Some((name, _)) = section {
+            bail!("section {{{}}} is never closed", name);
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Text(literal));
+        }
+        Ok(Self { parts })
+    }
+
+    pub fn render(&self, values: &Placeholders) -> String {
+        let mut out = String::new();
+        render_parts(&self.parts, values, &mut out);
+        out
+    }
+}
+
+/// Parts collected inside one open `{#name}`/`{^name}` section
+#[derive(Default)]
+struct Frame {
+    section: Option<(String, bool)>,
+    parts: Vec<Part>,
+}
+
+impl Frame {
+    fn section(name: String, inverted: bool) -> Self {
+        Self {
+            section: Some((name, inverted)),
+            parts: Vec::new(),
+        }
+    }
+}
+
+fn check_name(name: &str) -> anyhow::Result<String> {
+    let name = name.trim();
+    if !PLACEHOLDERS.contains(&name) {
+        bail!(
+            "unknown placeholder {{{}}}, expected one of {}",
+            name,
+            PLACEHOLDERS.join(", ")
+        );
+    }
+    Ok(name.to_owned())
+}
+
+fn render_parts(parts: &[Part], values: &Placeholders, out: &mut String) {
+    for part in parts {
+        match part {
+            Part::Text(text) => out.push_str(text),
+            Part::Value { name, fallback } => {
+                if let Some(value) = values.get(name).or(fallback.as_deref()) {
+                    out.push_str(value);
+                }
+            }
+            Part::Section {
+                name,
+                inverted,
+                body,
+            } => {
+                if values.get(name).is_some() != *inverted {
+                    render_parts(body, values, out);
+                }
+            }
+        }
+    }
+}
+
+/// Placeholder values for one poll; missing values are simply absent
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Placeholders {
+    values: BTreeMap<&'static str, String>,
+}
+
+impl Placeholders {
+    pub fn from_state(state: &GameState) -> Self {
+        let mut values = Self::default();
+        let ai = &state.ai;
+
+        values.set("depth", ai.location_depth);
+        if let Ok(map) = MapType::try_from(ai.location_map) {
+            values.set("map", map.name());
+            values.set("map_code", map.code());
+        }
+
+        if let Some(player) = &state.player {
+            let p = &player.data;
+            values.set("integrity", p.integrity);
+            values.set("energy", p.energy);
+            values.set("matter", p.matter);
+            values.set("heat", p.heat);
+            values.set("corruption", p.system_corruption);
+        }
+        if let Some(propulsion) = state.propulsion() {
+            values.set("propulsion", propulsion);
+        }
+
+        // The most recent FOV stamp on the map is the current turn's view
+        if let Some(current_fov) = state.tiles.iter().map(|tile| tile.last_fov).max() {
+            let hostiles = state
+                .tiles
+                .iter()
+                .filter(|tile| tile.last_fov == current_fov)
+                .filter_map(|tile| tile.entity.as_ref())
+                .filter(|entity| entity.relation == Relation::Hostile)
+                .count();
+            values.set("hostiles_in_view", hostiles);
+        }
+
+        values
+    }
+
+    pub fn set(&mut self, name: &'static str, value: impl ToString) {
+        self.values.insert(name, value.to_string());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> Placeholders {
+        let mut values = Placeholders::default();
+        values.set("depth", -7);
+        values.set("map", "Factory");
+        values
+    }
+
+    #[test]
+    fn substitutes_values() {
+        let template = Template::parse("Current map: {depth}/{map}").unwrap();
+        assert_eq!(template.render(&values()), "Current map: -7/Factory");
+    }
+
+    #[test]
+    fn fallback_only_when_missing() {
+        let template = Template::parse("{map|Somewhere} at {integrity|?} integrity").unwrap();
+        assert_eq!(template.render(&values()), "Factory at ? integrity");
+    }
+
+    #[test]
+    fn sections_depend_on_presence() {
+        let template =
+            Template::parse("{#integrity}Core {integrity}{/integrity}{^integrity}Dead{/integrity}")
+                .unwrap();
+        assert_eq!(template.render(&values()), "Dead");
+
+        let mut values = values();
+        values.set("integrity", 850);
+        assert_eq!(template.render(&values), "Core 850");
+    }
+
+    #[test]
+    fn escaped_braces() {
+        let template = Template::parse("{{{map}}}").unwrap();
+        assert_eq!(template.render(&values()), "{Factory}");
+    }
+
+    #[test]
+    fn rejects_bad_templates() {
+        for (text, error) in [
+            ("{mapp}", "unknown placeholder {mapp}"),
+            ("{map", "unclosed '{map'"),
+            ("map}", "unmatched '}'"),
+            ("{#map}x", "section {map} is never closed"),
+            ("{#map}x{/depth}", "{/depth} closes {map}"),
+            ("x{/map}", "{/map} has no matching section"),
+        ] {
+            let err = Template::parse(text).unwrap_err().to_string();
+            assert!(err.starts_with(error), "{:?}: {}", text, err);
+        }
+    }
+}
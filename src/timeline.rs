@@ -0,0 +1,160 @@
+//! When the current run and map started
+//!
+//! Cogmind doesn't expose run boundaries, so they are inferred from the
+//! location: a run starts over when the game drops back to where the run began
+//! (or anywhere below it) after making progress, or after passing through
+//! `MapNone` (the menus between runs).
+
+use crate::types::MapType;
+
+/// Where the player is: depth and raw `MapType` value
+type Location = (i32, i32);
+
+/// Tracks run and map start times from successive locations
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    run: Option<Run>,
+    /// When the game went to `MapNone`, while it is still there
+    menu: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Run {
+    started: i64,
+    first: Location,
+    /// Highest depth reached; depths count up towards the surface
+    furthest: i32,
+    location: Location,
+    entered: i64,
+}
+
+impl Timeline {
+    /// Record the location seen at `now` (Unix seconds)
+    pub fn observe(&mut self, depth: i32, map: i32, now: i64) {
+        if map == MapType::MapNone as i32 {
+            self.menu.get_or_insert(now);
+            return;
+        }
+        let location = (depth, map);
+        let between_runs = self.menu.take().is_some();
+
+        match &mut self.run {
+            Some(run) if !between_runs && !run.restarted_at(location) => {
+                if run.location != location {
+                    debug!("Entered {}/{}", depth, map_name(map));
+                    run.location = location;
+                    run.entered = now;
+                }
+                run.furthest = run.furthest.max(depth);
+            }
+            _ => {
+                info!("New run at {}/{}", depth, map_name(map));
+                self.run = Some(Run {
+                    started: now,
+                    first: location,
+                    furthest: depth,
+                    location,
+                    entered: now,
+                });
+            }
+        }
+    }
+
+    /// Forget everything, e.g. because the game exited
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// When the game went back to the menus, if it is there now
+    pub fn menu_start(&self) -> Option<i64> {
+        self.menu
+    }
+
+    /// When the current run started
+    pub fn run_start(&self) -> Option<i64> {
+        self.run.as_ref().map(|run| run.started)
+    }
+
+    /// When the current map was entered
+    pub fn map_start(&self) -> Option<i64> {
+        self.run.as_ref().map(|run| run.entered)
+    }
+}
+
+impl Run {
+    fn restarted_at(&self, (depth, map): Location) -> bool {
+        let (first_depth, first_map) = self.first;
+        depth < first_depth
+            || (depth == first_depth && map == first_map && self.furthest > first_depth)
+    }
+}
+
+fn map_name(map: i32) -> &'static str {
+    MapType::try_from(map).map_or("Unknown", |map| map.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCR: i32 = MapType::MapScr as i32;
+    const MAT: i32 = MapType::MapMat as i32;
+    const STO: i32 = MapType::MapSto as i32;
+    const NONE: i32 = MapType::MapNone as i32;
+
+    #[test]
+    fn map_changes_keep_the_run() {
+        let mut timeline = Timeline::default();
+        timeline.observe(-11, SCR, 100);
+        timeline.observe(-11, SCR, 150);
+        assert_eq!(timeline.map_start(), Some(100));
+
+        timeline.observe(-10, MAT, 200);
+        timeline.observe(-9, STO, 300);
+        timeline.observe(-9, MAT, 400);
+        assert_eq!(timeline.run_start(), Some(100));
+        assert_eq!(timeline.map_start(), Some(400));
+    }
+
+    #[test]
+    fn returning_to_the_start_is_a_new_run() {
+        let mut timeline = Timeline::default();
+        timeline.observe(-11, SCR, 100);
+        timeline.observe(-10, MAT, 200);
+        timeline.observe(-11, SCR, 300);
+        assert_eq!(timeline.run_start(), Some(300));
+        assert_eq!(timeline.map_start(), Some(300));
+    }
+
+    #[test]
+    fn map_none_between_runs() {
+        let mut timeline = Timeline::default();
+        timeline.observe(-11, SCR, 100);
+        timeline.observe(0, NONE, 200);
+        timeline.observe(-11, SCR, 300);
+        assert_eq!(timeline.run_start(), Some(300));
+        assert_eq!(timeline.menu_start(), None);
+    }
+
+    #[test]
+    fn menu_time_counts_from_the_first_map_none() {
+        let mut timeline = Timeline::default();
+        timeline.observe(0, NONE, 100);
+        timeline.observe(0, NONE, 200);
+        assert_eq!(timeline.menu_start(), Some(100));
+        assert_eq!(timeline.run_start(), None);
+
+        timeline.observe(-11, SCR, 300);
+        timeline.observe(0, NONE, 400);
+        timeline.observe(0, NONE, 500);
+        assert_eq!(timeline.menu_start(), Some(400));
+    }
+
+    #[test]
+    fn starting_mid_run_then_restarting() {
+        let mut timeline = Timeline::default();
+        timeline.observe(-6, MAT, 100);
+        timeline.observe(-11, SCR, 200);
+        assert_eq!(timeline.run_start(), Some(200));
+    }
+}
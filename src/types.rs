@@ -1,5 +1,17 @@
+use crate::generated::{CellId, EntityId, ItemId, PropId};
+use crate::regions::{SCAN_END, SCAN_START};
+use anyhow::{anyhow, bail};
+use serde::Serialize;
 use std::mem;
-#[derive(Debug)]
+
+/// First word of the `LuigiAi` block
+pub const LUIGI_MAGIC1: i32 = 0x64AD_FA4C;
+/// Second word of the `LuigiAi` block
+pub const LUIGI_MAGIC2: i32 = 0x7953_3ED9;
+/// Upper bound on either map dimension; real maps are a few hundred cells at most
+pub const MAX_MAP_DIMENSION: i32 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum MapType {
     MapNone = 0,
@@ -50,7 +62,171 @@ pub enum MapType {
     MapW08 = 1008,
 }
 
-pub struct InvalidMapType(i32);
+impl MapType {
+    /// Every location, in declaration order
+    pub const ALL: [MapType; 46] = [
+        Self::MapNone,
+        Self::MapSan,
+        Self::MapScr,
+        Self::MapMat,
+        Self::MapFac,
+        Self::MapRes,
+        Self::MapAcc,
+        Self::MapSur,
+        Self::MapMin,
+        Self::MapExi,
+        Self::MapSto,
+        Self::MapRec,
+        Self::MapWas,
+        Self::MapGar,
+        Self::MapDsf,
+        Self::MapSub,
+        Self::MapLow,
+        Self::MapUpp,
+        Self::MapPro,
+        Self::MapDee,
+        Self::MapZio,
+        Self::MapDat,
+        Self::MapZhi,
+        Self::MapWar,
+        Self::MapExt,
+        Self::MapCet,
+        Self::MapArc,
+        Self::MapHub,
+        Self::MapArm,
+        Self::MapLab,
+        Self::MapQua,
+        Self::MapTes,
+        Self::MapSec,
+        Self::MapCom,
+        Self::MapAc0,
+        Self::MapLai,
+        Self::MapTow,
+        Self::MapW00,
+        Self::MapW01,
+        Self::MapW02,
+        Self::MapW03,
+        Self::MapW04,
+        Self::MapW05,
+        Self::MapW06,
+        Self::MapW07,
+        Self::MapW08,
+    ];
+
+    /// Human-readable location name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MapNone => "None",
+            Self::MapSan => "Sandbox",
+            Self::MapScr => "Junkyard",
+            Self::MapMat => "Materials",
+            Self::MapFac => "Factory",
+            Self::MapRes => "Research",
+            Self::MapAcc => "Access",
+            Self::MapSur => "Surface",
+            Self::MapMin => "Mines",
+            Self::MapExi => "Exiles",
+            Self::MapSto => "Storage",
+            Self::MapRec => "Recycling",
+            Self::MapWas => "Wastes",
+            Self::MapGar => "Garrison",
+            Self::MapDsf => "DSF",
+            Self::MapSub => "Subcaves",
+            Self::MapLow => "Lower Caves",
+            Self::MapUpp => "Upper Caves",
+            Self::MapPro => "Proxy Caves",
+            Self::MapDee => "Deep Caves",
+            Self::MapZio => "Zion",
+            Self::MapDat => "Data Miner",
+            Self::MapZhi => "Zhirov",
+            Self::MapWar => "Warlord",
+            Self::MapExt => "Extension",
+            Self::MapCet => "Cetus",
+            Self::MapArc => "Archives",
+            Self::MapHub => "Hub_04(d)",
+            Self::MapArm => "Armory",
+            Self::MapLab => "Lab",
+            Self::MapQua => "Quarantine",
+            Self::MapTes => "Testing",
+            Self::MapSec => "Section 7",
+            Self::MapCom => "Command",
+            Self::MapAc0 => "Access 0",
+            Self::MapLai => "Abomination Lair",
+            Self::MapTow => "Wartown",
+            Self::MapW00 => "w0",
+            Self::MapW01 => "w1",
+            Self::MapW02 => "w2",
+            Self::MapW03 => "w3",
+            Self::MapW04 => "w4",
+            Self::MapW05 => "w5",
+            Self::MapW06 => "w6",
+            Self::MapW07 => "w7",
+            Self::MapW08 => "w8",
+        }
+    }
+
+    /// Look a location up by its code, ignoring case
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|map| map.code().eq_ignore_ascii_case(code))
+    }
+
+    /// Short location code, e.g. `FAC`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MapNone => "NONE",
+            Self::MapSan => "SAN",
+            Self::MapScr => "SCR",
+            Self::MapMat => "MAT",
+            Self::MapFac => "FAC",
+            Self::MapRes => "RES",
+            Self::MapAcc => "ACC",
+            Self::MapSur => "SUR",
+            Self::MapMin => "MIN",
+            Self::MapExi => "EXI",
+            Self::MapSto => "STO",
+            Self::MapRec => "REC",
+            Self::MapWas => "WAS",
+            Self::MapGar => "GAR",
+            Self::MapDsf => "DSF",
+            Self::MapSub => "SUB",
+            Self::MapLow => "LOW",
+            Self::MapUpp => "UPP",
+            Self::MapPro => "PRO",
+            Self::MapDee => "DEE",
+            Self::MapZio => "ZIO",
+            Self::MapDat => "DAT",
+            Self::MapZhi => "ZHI",
+            Self::MapWar => "WAR",
+            Self::MapExt => "EXT",
+            Self::MapCet => "CET",
+            Self::MapArc => "ARC",
+            Self::MapHub => "HUB",
+            Self::MapArm => "ARM",
+            Self::MapLab => "LAB",
+            Self::MapQua => "QUA",
+            Self::MapTes => "TES",
+            Self::MapSec => "SEC",
+            Self::MapCom => "COM",
+            Self::MapAc0 => "AC0",
+            Self::MapLai => "LAI",
+            Self::MapTow => "TOW",
+            Self::MapW00 => "W00",
+            Self::MapW01 => "W01",
+            Self::MapW02 => "W02",
+            Self::MapW03 => "W03",
+            Self::MapW04 => "W04",
+            Self::MapW05 => "W05",
+            Self::MapW06 => "W06",
+            Self::MapW07 => "W07",
+            Self::MapW08 => "W08",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidMapType(pub i32);
 
 impl TryFrom<i32> for MapType {
     type Error = InvalidMapType;
@@ -108,57 +284,250 @@ impl TryFrom<i32> for MapType {
     }
 }
 
+/// How a robot stands towards Cogmind, from `LuigiEntity::relation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Relation {
+    /// Cogmind itself
+    Player,
+    Ally,
+    Neutral,
+    Hostile,
+    Unknown(i32),
+}
+
+impl From<i32> for Relation {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => Self::Player,
+            1 => Self::Ally,
+            2 => Self::Neutral,
+            3 => Self::Hostile,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<Relation> for i32 {
+    fn from(relation: Relation) -> Self {
+        match relation {
+            Relation::Player => 0,
+            Relation::Ally => 1,
+            Relation::Neutral => 2,
+            Relation::Hostile => 3,
+            Relation::Unknown(value) => value,
+        }
+    }
+}
+
+/// Whether a robot is up and about, from `LuigiEntity::active_state`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActiveState {
+    /// Dormant, unpowered or otherwise not acting
+    Inactive,
+    Active,
+    Unknown(i32),
+}
+
+impl From<i32> for ActiveState {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => Self::Inactive,
+            1 => Self::Active,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<ActiveState> for i32 {
+    fn from(state: ActiveState) -> Self {
+        match state {
+            ActiveState::Inactive => 0,
+            ActiveState::Active => 1,
+            ActiveState::Unknown(value) => value,
+        }
+    }
+}
+
+/// Why a block of bytes could not be decoded into a Luigi struct
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    ShortBuffer {
+        type_name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    InvalidBool {
+        type_name: &'static str,
+        offset: usize,
+        value: u8,
+    },
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ShortBuffer {
+                type_name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} needs {} bytes but only {} were read",
+                type_name, expected, actual
+            ),
+            Self::InvalidBool {
+                type_name,
+                offset,
+                value,
+            } => write!(
+                f,
+                "{} has invalid bool 0x{:02X} at offset {}",
+                type_name, value, offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// A `#[repr(C)]` struct that can be read out of game memory
+pub trait Decode: Sized {
+    /// Size of the struct in the game's memory
+    const SIZE: usize;
+
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError>;
+}
+
+/// Little-endian field reader over a buffer already checked to be `T::SIZE` long
+struct Fields<'a> {
+    type_name: &'static str,
+    bytes: &'a [u8],
+}
+
+impl<'a> Fields<'a> {
+    fn new<T: Decode>(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        let type_name = std::any::type_name::<T>()
+            .rsplit("::")
+            .next()
+            .unwrap_or_default();
+        if bytes.len() < T::SIZE {
+            return Err(DecodeError::ShortBuffer {
+                type_name,
+                expected: T::SIZE,
+                actual: bytes.len(),
+            });
+        }
+        Ok(Self { type_name, bytes })
+    }
+
+    fn word(&self, offset: usize) -> [u8; 4] {
+        self.bytes[offset..offset + 4].try_into().unwrap()
+    }
+
+    fn i32(&self, offset: usize) -> i32 {
+        i32::from_le_bytes(self.word(offset))
+    }
+
+    fn u32(&self, offset: usize) -> u32 {
+        u32::from_le_bytes(self.word(offset))
+    }
+
+    fn bool(&self, offset: usize) -> Result<bool, DecodeError> {
+        match self.bytes[offset] {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(DecodeError::InvalidBool {
+                type_name: self.type_name,
+                offset,
+                value,
+            }),
+        }
+    }
+}
+
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct LuigiMachineHacking {
+    /// Kept raw: its values are undocumented
     pub action_ready: i32,
     pub detect_chance: i32,
     pub trace_progress: i32,
+    /// Also `false` before any hack, so `false` alone doesn't mean one failed
     pub last_hack_success: bool,
 }
-impl From<&Vec<u8>> for LuigiMachineHacking {
-    fn from(slice: &Vec<u8>) -> Self {
-        let p: *const [u8; mem::size_of::<Self>()] =
-            slice.as_ptr() as *const [u8; mem::size_of::<Self>()];
-        unsafe { mem::transmute(*p) }
+impl Decode for LuigiMachineHacking {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let f = Fields::new::<Self>(bytes)?;
+        Ok(Self {
+            action_ready: f.i32(0),
+            detect_chance: f.i32(4),
+            trace_progress: f.i32(8),
+            last_hack_success: f.bool(12)?,
+        })
     }
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct LuigiProp {
     pub id: i32,
     pub interactive_piece: bool,
 }
-impl From<&Vec<u8>> for LuigiProp {
-    fn from(slice: &Vec<u8>) -> Self {
-        let p: *const [u8; mem::size_of::<Self>()] =
-            slice.as_ptr() as *const [u8; mem::size_of::<Self>()];
-        unsafe { mem::transmute(*p) }
+impl LuigiProp {
+    pub fn prop_id(&self) -> Option<PropId> {
+        PropId::from_id(self.id)
+    }
+}
+impl Decode for LuigiProp {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let f = Fields::new::<Self>(bytes)?;
+        Ok(Self {
+            id: f.i32(0),
+            interactive_piece: f.bool(4)?,
+        })
     }
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct LuigiItem {
     pub id: i32,
     pub integrity: i32,
     pub equipped: bool,
 }
-impl From<&Vec<u8>> for LuigiItem {
-    fn from(slice: &Vec<u8>) -> Self {
-        let p: *const [u8; mem::size_of::<Self>()] =
-            slice.as_ptr() as *const [u8; mem::size_of::<Self>()];
-        unsafe { mem::transmute(*p) }
+impl LuigiItem {
+    pub fn item_id(&self) -> Option<ItemId> {
+        ItemId::from_id(self.id)
+    }
+}
+impl Decode for LuigiItem {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let f = Fields::new::<Self>(bytes)?;
+        Ok(Self {
+            id: f.i32(0),
+            integrity: f.i32(4),
+            equipped: f.bool(8)?,
+        })
     }
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct LuigiEntity {
     pub id: i32,
     pub integrity: i32,
+    /// Serialized typed by `state::Entity` instead
+    #[serde(skip)]
     pub relation: i32,
+    #[serde(skip)]
     pub active_state: i32,
     pub exposure: i32,
     pub energy: i32,
@@ -169,16 +538,43 @@ pub struct LuigiEntity {
     pub inventory_size: i32,
     pub inventory: u32,
 }
-impl From<&Vec<u8>> for LuigiEntity {
-    fn from(slice: &Vec<u8>) -> Self {
-        let p: *const [u8; mem::size_of::<Self>()] =
-            slice.as_ptr() as *const [u8; mem::size_of::<Self>()];
-        unsafe { mem::transmute(*p) }
+impl LuigiEntity {
+    pub fn entity_id(&self) -> Option<EntityId> {
+        EntityId::from_id(self.id)
+    }
+
+    pub fn relation(&self) -> Relation {
+        self.relation.into()
+    }
+
+    pub fn active_state(&self) -> ActiveState {
+        self.active_state.into()
+    }
+}
+impl Decode for LuigiEntity {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let f = Fields::new::<Self>(bytes)?;
+        Ok(Self {
+            id: f.i32(0),
+            integrity: f.i32(4),
+            relation: f.i32(8),
+            active_state: f.i32(12),
+            exposure: f.i32(16),
+            energy: f.i32(20),
+            matter: f.i32(24),
+            heat: f.i32(28),
+            system_corruption: f.i32(32),
+            speed: f.i32(36),
+            inventory_size: f.i32(40),
+            inventory: f.u32(44),
+        })
     }
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct LuigiTile {
     pub last_action: i32,
     pub last_fov: i32,
@@ -188,16 +584,30 @@ pub struct LuigiTile {
     pub entity: u32,
     pub item: u32,
 }
-impl From<&Vec<u8>> for LuigiTile {
-    fn from(slice: &Vec<u8>) -> Self {
-        let p: *const [u8; mem::size_of::<Self>()] =
-            slice.as_ptr() as *const [u8; mem::size_of::<Self>()];
-        unsafe { mem::transmute(*p) }
+impl LuigiTile {
+    pub fn cell_id(&self) -> Option<CellId> {
+        CellId::from_id(self.cell)
+    }
+}
+impl Decode for LuigiTile {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let f = Fields::new::<Self>(bytes)?;
+        Ok(Self {
+            last_action: f.i32(0),
+            last_fov: f.i32(4),
+            cell: f.i32(8),
+            door_open: f.bool(12)?,
+            prop: f.u32(16),
+            entity: f.u32(20),
+            item: f.u32(24),
+        })
     }
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct LuigiAi {
     pub magic1: i32,
     pub magic2: i32,
@@ -211,10 +621,204 @@ pub struct LuigiAi {
     pub player: u32,
     pub machine_hacking: u32,
 }
-impl From<&Vec<u8>> for LuigiAi {
-    fn from(slice: &Vec<u8>) -> Self {
-        let p: *const [u8; mem::size_of::<Self>()] =
-            slice.as_ptr() as *const [u8; mem::size_of::<Self>()];
-        unsafe { mem::transmute(*p) }
+impl Decode for LuigiAi {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let f = Fields::new::<Self>(bytes)?;
+        Ok(Self {
+            magic1: f.i32(0),
+            magic2: f.i32(4),
+            action_ready: f.i32(8),
+            map_width: f.i32(12),
+            map_height: f.i32(16),
+            location_depth: f.i32(20),
+            location_map: f.i32(24),
+            map_data: f.u32(28),
+            map_cursor_index: f.i32(32),
+            player: f.u32(36),
+            machine_hacking: f.u32(40),
+        })
+    }
+}
+
+impl LuigiAi {
+    /// Sanity-check the header so a stray copy of the magic isn't mistaken for the real block
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.magic1 != LUIGI_MAGIC1 {
+            bail!("bad magic1 0x{:X}", self.magic1);
+        }
+        if self.magic2 != LUIGI_MAGIC2 {
+            bail!("bad magic2 0x{:X}", self.magic2);
+        }
+        if !(0..=MAX_MAP_DIMENSION).contains(&self.map_width)
+            || !(0..=MAX_MAP_DIMENSION).contains(&self.map_height)
+        {
+            bail!(
+                "implausible map size {}x{}",
+                self.map_width,
+                self.map_height
+            );
+        }
+        MapType::try_from(self.location_map)
+            .map_err(|e| anyhow!("unknown location_map {}", e.0))?;
+        if !is_plausible_pointer(self.player) {
+            bail!("bad player pointer 0x{:X}", self.player);
+        }
+        if !is_plausible_pointer(self.map_data) {
+            bail!("bad map_data pointer 0x{:X}", self.map_data);
+        }
+        if self.map_data == 0 && self.map_width * self.map_height != 0 {
+            bail!("null map_data for a non-empty map");
+        }
+        Ok(())
+    }
+}
+
+/// Null, or an aligned address inside the window the scan covers
+fn is_plausible_pointer(ptr: u32) -> bool {
+    let ptr = ptr as usize;
+    ptr == 0
+        || ((SCAN_START..=SCAN_END).contains(&ptr) && ptr.is_multiple_of(mem::align_of::<u32>()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{offset_of, size_of};
+
+    #[test]
+    fn machine_hacking_layout() {
+        assert_eq!(size_of::<LuigiMachineHacking>(), 16);
+        assert_eq!(offset_of!(LuigiMachineHacking, action_ready), 0);
+        assert_eq!(offset_of!(LuigiMachineHacking, detect_chance), 4);
+        assert_eq!(offset_of!(LuigiMachineHacking, trace_progress), 8);
+        assert_eq!(offset_of!(LuigiMachineHacking, last_hack_success), 12);
+    }
+
+    #[test]
+    fn prop_layout() {
+        assert_eq!(size_of::<LuigiProp>(), 8);
+        assert_eq!(offset_of!(LuigiProp, id), 0);
+        assert_eq!(offset_of!(LuigiProp, interactive_piece), 4);
+    }
+
+    #[test]
+    fn item_layout() {
+        assert_eq!(size_of::<LuigiItem>(), 12);
+        assert_eq!(offset_of!(LuigiItem, id), 0);
+        assert_eq!(offset_of!(LuigiItem, integrity), 4);
+        assert_eq!(offset_of!(LuigiItem, equipped), 8);
+    }
+
+    #[test]
+    fn entity_layout() {
+        assert_eq!(size_of::<LuigiEntity>(), 48);
+        assert_eq!(offset_of!(LuigiEntity, id), 0);
+        assert_eq!(offset_of!(LuigiEntity, integrity), 4);
+        assert_eq!(offset_of!(LuigiEntity, relation), 8);
+        assert_eq!(offset_of!(LuigiEntity, active_state), 12);
+        assert_eq!(offset_of!(LuigiEntity, exposure), 16);
+        assert_eq!(offset_of!(LuigiEntity, energy), 20);
+        assert_eq!(offset_of!(LuigiEntity, matter), 24);
+        assert_eq!(offset_of!(LuigiEntity, heat), 28);
+        assert_eq!(offset_of!(LuigiEntity, system_corruption), 32);
+        assert_eq!(offset_of!(LuigiEntity, speed), 36);
+        assert_eq!(offset_of!(LuigiEntity, inventory_size), 40);
+        assert_eq!(offset_of!(LuigiEntity, inventory), 44);
+    }
+
+    #[test]
+    fn tile_layout() {
+        assert_eq!(size_of::<LuigiTile>(), 28);
+        assert_eq!(offset_of!(LuigiTile, last_action), 0);
+        assert_eq!(offset_of!(LuigiTile, last_fov), 4);
+        assert_eq!(offset_of!(LuigiTile, cell), 8);
+        assert_eq!(offset_of!(LuigiTile, door_open), 12);
+        assert_eq!(offset_of!(LuigiTile, prop), 16);
+        assert_eq!(offset_of!(LuigiTile, entity), 20);
+        assert_eq!(offset_of!(LuigiTile, item), 24);
+    }
+
+    #[test]
+    fn luigi_ai_layout() {
+        assert_eq!(size_of::<LuigiAi>(), 44);
+        assert_eq!(offset_of!(LuigiAi, magic1), 0);
+        assert_eq!(offset_of!(LuigiAi, magic2), 4);
+        assert_eq!(offset_of!(LuigiAi, action_ready), 8);
+        assert_eq!(offset_of!(LuigiAi, map_width), 12);
+        assert_eq!(offset_of!(LuigiAi, map_height), 16);
+        assert_eq!(offset_of!(LuigiAi, location_depth), 20);
+        assert_eq!(offset_of!(LuigiAi, location_map), 24);
+        assert_eq!(offset_of!(LuigiAi, map_data), 28);
+        assert_eq!(offset_of!(LuigiAi, map_cursor_index), 32);
+        assert_eq!(offset_of!(LuigiAi, player), 36);
+        assert_eq!(offset_of!(LuigiAi, machine_hacking), 40);
+    }
+
+    #[test]
+    fn relation_round_trips_through_i32() {
+        for value in -1..=4 {
+            let relation = Relation::from(value);
+            assert_eq!(i32::from(relation), value);
+        }
+        assert_eq!(Relation::from(3), Relation::Hostile);
+        assert_eq!(Relation::from(7), Relation::Unknown(7));
+    }
+
+    #[test]
+    fn active_state_round_trips_through_i32() {
+        for value in -1..=2 {
+            assert_eq!(i32::from(ActiveState::from(value)), value);
+        }
+        assert_eq!(ActiveState::from(1), ActiveState::Active);
+        assert_eq!(ActiveState::from(5), ActiveState::Unknown(5));
+    }
+
+    #[test]
+    fn decode_reads_little_endian_fields() {
+        let mut bytes = vec![0u8; LuigiItem::SIZE];
+        bytes[0..4].copy_from_slice(&42i32.to_le_bytes());
+        bytes[4..8].copy_from_slice(&(-3i32).to_le_bytes());
+        bytes[8] = 1;
+
+        let item = LuigiItem::decode(&bytes).unwrap();
+        assert_eq!(
+            item,
+            LuigiItem {
+                id: 42,
+                integrity: -3,
+                equipped: true,
+            }
+        );
+    }
+
+    #[test]
+    fn decode_rejects_short_buffer() {
+        let err = LuigiAi::decode(&[0u8; 40]).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::ShortBuffer {
+                type_name: "LuigiAi",
+                expected: 44,
+                actual: 40,
+            }
+        );
+    }
+
+    #[test]
+    fn decode_rejects_invalid_bool() {
+        let mut bytes = vec![0u8; LuigiTile::SIZE];
+        bytes[12] = 7;
+
+        let err = LuigiTile::decode(&bytes).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::InvalidBool {
+                type_name: "LuigiTile",
+                offset: 12,
+                value: 7,
+            }
+        );
     }
 }
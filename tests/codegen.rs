@@ -0,0 +1,79 @@
+//! Golden tests for the ID enum generator used by `build.rs`
+
+#[allow(dead_code)]
+#[path = "../build/codegen.rs"]
+mod codegen;
+
+use codegen::IdFormat;
+use std::{env, fs, path::PathBuf};
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("codegen")
+        .join(name)
+}
+
+/// Compare against the golden file, or rewrite it when `UPDATE_GOLDEN` is set
+fn assert_golden(generated: &str, golden: &str) {
+    let path = fixture(golden);
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, generated).unwrap();
+    }
+    let expected = fs::read_to_string(&path).unwrap();
+    assert_eq!(generated, expected, "{} is out of date", path.display());
+}
+
+#[test]
+fn simple_list_matches_golden() {
+    let text = fs::read_to_string(fixture("items.txt")).unwrap();
+    let generated = codegen::generate_enum("items.txt", &text, IdFormat::Simple, "ItemId").unwrap();
+    assert_golden(&generated, "items.rs");
+}
+
+#[test]
+fn tabular_list_matches_golden() {
+    let text = fs::read_to_string(fixture("props.txt")).unwrap();
+    let generated =
+        codegen::generate_enum("props.txt", &text, IdFormat::Tabular, "PropId").unwrap();
+    assert_golden(&generated, "props.rs");
+}
+
+#[test]
+fn duplicate_id_names_both_lines() {
+    let err =
+        codegen::parse("items.txt", "   0 Matter\n   0 Scrap\n", IdFormat::Simple).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "items.txt:2: duplicate id 0 (first defined on line 1)"
+    );
+}
+
+#[test]
+fn identifier_collision_names_both_lines() {
+    let err = codegen::parse(
+        "items.txt",
+        "   0 Lgt. Engine\n   1 Lgt_ Engine\n",
+        IdFormat::Simple,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "items.txt:2: identifier Lgt__Engine collides with line 1"
+    );
+}
+
+#[test]
+fn malformed_lines_are_all_reported() {
+    let err = codegen::parse(
+        "items.txt",
+        "   0 Matter\n   x Scrap\n   2\n",
+        IdFormat::Simple,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "items.txt:2: bad id \"x\": \"x Scrap\"\nitems.txt:3: missing name: \"2\""
+    );
+}
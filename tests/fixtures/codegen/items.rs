@@ -0,0 +1,53 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum ItemId {
+    Matter,
+    _1st_Aid_Kit,
+    Lgt__Ion_Engine,
+}
+
+impl ItemId {
+    pub fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(Self::Matter),
+            1 => Some(Self::_1st_Aid_Kit),
+            3 => Some(Self::Lgt__Ion_Engine),
+            _ => None,
+        }
+    }
+
+    pub fn id(&self) -> i32 {
+        match self {
+            Self::Matter => 0,
+            Self::_1st_Aid_Kit => 1,
+            Self::Lgt__Ion_Engine => 3,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Matter => r#"Matter"#,
+            Self::_1st_Aid_Kit => r#"1st Aid Kit"#,
+            Self::Lgt__Ion_Engine => r#"Lgt. Ion Engine"#,
+        }
+    }
+}
+
+impl TryFrom<i32> for ItemId {
+    type Error = &'static str;
+    fn try_from(id: i32) -> Result<Self, Self::Error> {
+        Self::from_id(id).ok_or("unknown id")
+    }
+}
+
+impl From<ItemId> for i32 {
+    fn from(id: ItemId) -> i32 {
+        id.id()
+    }
+}
+
+impl std::fmt::Display for ItemId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,53 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum PropId {
+    GENERIC_MACHINE_2,
+    GENERIC_MACHINE_1,
+    Terminal_vFe_01a,
+}
+
+impl PropId {
+    pub fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(Self::GENERIC_MACHINE_2),
+            1 => Some(Self::GENERIC_MACHINE_1),
+            2 => Some(Self::Terminal_vFe_01a),
+            _ => None,
+        }
+    }
+
+    pub fn id(&self) -> i32 {
+        match self {
+            Self::GENERIC_MACHINE_2 => 0,
+            Self::GENERIC_MACHINE_1 => 1,
+            Self::Terminal_vFe_01a => 2,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GENERIC_MACHINE_2 => r#"Machine"#,
+            Self::GENERIC_MACHINE_1 => r#"Machine"#,
+            Self::Terminal_vFe_01a => r#"Terminal"#,
+        }
+    }
+}
+
+impl TryFrom<i32> for PropId {
+    type Error = &'static str;
+    fn try_from(id: i32) -> Result<Self, Self::Error> {
+        Self::from_id(id).ok_or("unknown id")
+    }
+}
+
+impl From<PropId> for i32 {
+    fn from(id: PropId) -> i32 {
+        id.id()
+    }
+}
+
+impl std::fmt::Display for PropId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,178 @@
+#![cfg(all(unix, feature = "presence"))]
+
+#[path = "support/discord.rs"]
+mod discord;
+
+use discord::{Event, FakeDiscord};
+use serde_json::json;
+use statmind::config::{AssetsConfig, PresenceConfig};
+use statmind::discord::PresenceProvider;
+use statmind::types::{LuigiAi, MapType, LUIGI_MAGIC1, LUIGI_MAGIC2};
+use statmind::GameState;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use std::{env, thread};
+
+/// The IPC client finds Discord through `XDG_RUNTIME_DIR`, which is process-wide
+static ENV: Mutex<()> = Mutex::new(());
+
+fn start_discord() -> (MutexGuard<'static, ()>, FakeDiscord) {
+    let guard = ENV.lock().unwrap_or_else(|e| e.into_inner());
+    let discord = FakeDiscord::start();
+    env::set_var("XDG_RUNTIME_DIR", discord.dir());
+    (guard, discord)
+}
+
+fn state(depth: i32, map: MapType) -> GameState {
+    GameState {
+        ai: LuigiAi {
+            magic1: LUIGI_MAGIC1,
+            magic2: LUIGI_MAGIC2,
+            action_ready: 1,
+            map_width: 0,
+            map_height: 0,
+            location_depth: depth,
+            location_map: map as i32,
+            map_data: 0,
+            map_cursor_index: 0,
+            player: 0,
+            machine_hacking: 0,
+        },
+        tiles: Vec::new(),
+        player: None,
+        machine_hacking: None,
+    }
+}
+
+fn show(presence: &mut PresenceProvider, depth: i32, map: MapType) -> anyhow::Result<bool> {
+    let activity = presence.render(&state(depth, map));
+    presence.set_activity(&activity)
+}
+
+/// Poll until an update goes out, as the run loop would
+fn poll_until_sent(presence: &mut PresenceProvider) {
+    // Long enough for the update interval plus a reconnect
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while !matches!(presence.poll(), Ok(true)) {
+        assert!(Instant::now() < deadline, "no update was sent");
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn sends_the_rendered_activity() {
+    let (_env, discord) = start_discord();
+    let mut config = PresenceConfig::default();
+    config.maps.insert(
+        "fac".into(),
+        AssetsConfig {
+            image: Some("map_factory".into()),
+            text: None,
+        },
+    );
+    let mut presence = PresenceProvider::new(&config).unwrap();
+
+    assert!(show(&mut presence, -7, MapType::MapFac).unwrap());
+    let events = discord.wait_for(|events| events.len() == 2);
+    assert_eq!(
+        events[0],
+        Event::Connected {
+            client_id: config.application_id.clone()
+        }
+    );
+
+    let activity = discord.wait_for_activities(1).remove(0).unwrap();
+    assert_eq!(activity["details"], "Playing b13");
+    assert_eq!(activity["state"], "Current map: -7/Factory");
+    assert_eq!(
+        activity["assets"],
+        json!({ "large_image": "map_factory", "large_text": "Cogmind b13" })
+    );
+    assert_eq!(
+        activity["buttons"],
+        json!([{ "label": "Visit Site", "url": "https://gridsagegames.com/cogmind" }])
+    );
+    assert!(activity["timestamps"]["start"].is_i64());
+}
+
+#[test]
+fn menu_keeps_a_steady_timer() {
+    let (_env, _discord) = start_discord();
+    let mut presence = PresenceProvider::new(&PresenceConfig::default()).unwrap();
+
+    let first = presence.render(&state(0, MapType::MapNone));
+    thread::sleep(Duration::from_millis(1100));
+    assert_eq!(presence.render(&state(0, MapType::MapNone)), first);
+}
+
+#[test]
+fn only_changes_are_sent() {
+    let (_env, discord) = start_discord();
+    let mut presence = PresenceProvider::new(&PresenceConfig::default()).unwrap();
+
+    assert!(show(&mut presence, -10, MapType::MapMat).unwrap());
+    assert!(!show(&mut presence, -10, MapType::MapMat).unwrap());
+    // Too soon after the last update, so it waits for the rate limit
+    assert!(!show(&mut presence, -9, MapType::MapMat).unwrap());
+
+    // Frames arrive in order, so the held back change would show up before the clear
+    presence.close().unwrap();
+    let activities = discord.wait_for_activities(2);
+    assert_eq!(
+        activities[0].as_ref().unwrap()["state"],
+        "Current map: -10/Materials"
+    );
+    assert_eq!(activities[1], None);
+}
+
+#[test]
+fn waits_for_discord_to_start() {
+    let (_env, mut discord) = start_discord();
+    discord.stop();
+    let mut presence = PresenceProvider::new(&PresenceConfig::default()).unwrap();
+    assert!(!show(&mut presence, -10, MapType::MapMat).unwrap());
+    assert!(!presence.is_connected());
+
+    discord.restart();
+    poll_until_sent(&mut presence);
+    let activity = discord.wait_for_activities(1).remove(0).unwrap();
+    assert_eq!(activity["state"], "Current map: -10/Materials");
+}
+
+#[test]
+fn reconnects_and_resends_after_discord_restarts() {
+    let (_env, mut discord) = start_discord();
+    let mut presence = PresenceProvider::new(&PresenceConfig::default()).unwrap();
+    assert!(show(&mut presence, -10, MapType::MapMat).unwrap());
+    discord.wait_for(|events| events.len() == 2);
+
+    discord.restart();
+    // The dead socket only shows when the held back update fails to write
+    assert!(!show(&mut presence, -9, MapType::MapMat).unwrap());
+    poll_until_sent(&mut presence);
+
+    let events = discord.wait_for(|events| events.len() == 5);
+    assert!(matches!(events[2], Event::Disconnected), "{:?}", events);
+    assert!(matches!(events[3], Event::Connected { .. }), "{:?}", events);
+    let activities = discord.wait_for_activities(2);
+    assert_eq!(
+        activities[1].as_ref().unwrap()["state"],
+        "Current map: -9/Materials"
+    );
+}
+
+#[test]
+fn clears_and_closes_when_the_game_exits() {
+    let (_env, discord) = start_discord();
+    let mut presence = PresenceProvider::new(&PresenceConfig::default()).unwrap();
+    assert!(show(&mut presence, -10, MapType::MapMat).unwrap());
+
+    presence.close().unwrap();
+    let events = discord.wait_for(|events| events.ends_with(&[Event::Closed]));
+    assert_eq!(events[events.len() - 2], Event::SetActivity(None));
+    assert!(!presence.is_connected());
+
+    // The next game reconnects on its own
+    assert!(show(&mut presence, -11, MapType::MapScr).unwrap());
+    discord.wait_for(|events| events.len() == 6);
+}
@@ -0,0 +1,212 @@
+#![cfg(target_os = "linux")]
+
+#[path = "support/game.rs"]
+mod game;
+
+use anyhow::{bail, Context};
+use game::{fake_game, ROOT};
+use read_process_memory::Pid;
+use statmind::config::ProcessConfig;
+use statmind::locator::LuigiLocator;
+use statmind::memory::{MemorySource, MockSource, ProcessSource};
+use statmind::process::{find_cogmind, is_running};
+use statmind::types::MapType;
+use statmind::GameState;
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::sync::{Mutex, MutexGuard};
+use std::{env, fs, ptr};
+use sysinfo::{System, SystemExt};
+
+/// Set on the copy of this test binary that plays the game
+const FAKE_COGMIND: &str = "STATMIND_FAKE_COGMIND";
+
+/// Discovery sees every process on the system, so only one fake game runs at a time
+static GAME: Mutex<()> = Mutex::new(());
+
+/// This test binary running only [`fake_cogmind`], copied to `cogmind.exe`
+struct FakeCogmind {
+    child: Child,
+    stdout: BufReader<ChildStdout>,
+    dir: PathBuf,
+}
+
+impl FakeCogmind {
+    fn start(args: &[&str]) -> (MutexGuard<'static, ()>, Self) {
+        let guard = GAME.lock().unwrap_or_else(|e| e.into_inner());
+
+        let dir = env::temp_dir().join(format!("statmind-cogmind-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cogmind.exe");
+        fs::copy(env::current_exe().unwrap(), &path).unwrap();
+
+        // Game arguments go after `--`, where the harness takes them as extra
+        // filters that match no test
+        let mut child = Command::new(&path)
+            .args(["--exact", "--nocapture", "--quiet", "--", "fake_cogmind"])
+            .args(args)
+            .env(FAKE_COGMIND, "1")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let mut game = Self { child, stdout, dir };
+        // Skip the harness announcing the test
+        while game.read_line() != "ready" {}
+        (guard, game)
+    }
+
+    fn pid(&self) -> Pid {
+        self.child.id() as Pid
+    }
+
+    fn send(&mut self, command: &str) {
+        let stdin = self.child.stdin.as_mut().unwrap();
+        writeln!(stdin, "{}", command).unwrap();
+        assert_eq!(self.read_line(), "ok");
+    }
+
+    fn read_line(&mut self) -> String {
+        let mut line = String::new();
+        assert_ne!(self.stdout.read_line(&mut line).unwrap(), 0, "Game exited");
+        line.trim_end().to_string()
+    }
+
+    fn quit(mut self) {
+        drop(self.child.stdin.take());
+        assert!(self.child.wait().unwrap().success());
+    }
+}
+
+impl Drop for FakeCogmind {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Stand-in for a running Cogmind, only active in the copy [`FakeCogmind`] starts
+///
+/// Maps the made-up game from `tests/support/game.rs` at the same addresses in
+/// its own memory, which keeps every pointer below 4 GiB like in the 32-bit
+/// game. Prints `ready` once the memory is laid out, then reads commands from
+/// stdin and answers each with `ok`:
+///
+/// - `map <depth> <map>` moves the player to another location
+/// - `quit` exits
+#[test]
+fn fake_cogmind() -> anyhow::Result<()> {
+    if env::var_os(FAKE_COGMIND).is_none() {
+        return Ok(());
+    }
+
+    let mut memory = Memory::default();
+    memory.write(&fake_game(-10, MapType::MapMat as i32))?;
+    println!("ready");
+
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            ["map", depth, map] => {
+                let depth = depth.parse().context("Bad depth")?;
+                let map = map.parse().context("Bad map")?;
+                memory.write(&fake_game(depth, map))?;
+            }
+            ["quit"] => break,
+            _ => bail!("Unknown command: {}", line),
+        }
+        println!("ok");
+    }
+    Ok(())
+}
+
+/// Pages mapped at fixed addresses so far
+#[derive(Default)]
+struct Memory {
+    pages: BTreeSet<usize>,
+}
+
+impl Memory {
+    /// Copy every segment of `game` to its own address, mapping pages as needed
+    fn write(&mut self, game: &MockSource) -> anyhow::Result<()> {
+        // SAFETY: sysconf has no preconditions
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        for (addr, bytes) in game.segments() {
+            let first = addr & !(page_size - 1);
+            for page in (first..addr + bytes.len()).step_by(page_size) {
+                if self.pages.insert(page) {
+                    map_page(page, page_size)?;
+                }
+            }
+            // SAFETY: every page under the segment was mapped writable above
+            unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), addr as *mut u8, bytes.len()) };
+        }
+        Ok(())
+    }
+}
+
+/// Map one anonymous page exactly at `addr`, failing rather than replacing anything there
+fn map_page(addr: usize, len: usize) -> anyhow::Result<()> {
+    // SAFETY: MAP_FIXED_NOREPLACE never touches existing mappings
+    let mapped = unsafe {
+        libc::mmap(
+            addr as *mut libc::c_void,
+            len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_FIXED_NOREPLACE,
+            -1,
+            0,
+        )
+    };
+    if mapped != addr as *mut libc::c_void {
+        bail!("Failed to map 0x{:X}: {}", addr, io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+fn read_state(source: &dyn MemorySource, locator: &mut LuigiLocator) -> GameState {
+    let (_, ai) = locator.read(source).unwrap();
+    GameState::read(source, ai).unwrap()
+}
+
+fn expected_state(depth: i32, map: MapType) -> GameState {
+    let memory = fake_game(depth, map as i32);
+    read_state(&memory, &mut LuigiLocator::with_base(ROOT))
+}
+
+#[test]
+fn finds_attaches_and_follows_the_game() {
+    let (_guard, mut game) = FakeCogmind::start(&["-luigiAi"]);
+    let mut sys = System::new();
+    let pid = find_cogmind(&mut sys, &ProcessConfig::default()).unwrap();
+    assert_eq!(pid, game.pid());
+    assert!(is_running(&mut sys, pid));
+
+    let source = ProcessSource::attach(pid).unwrap();
+    let mut locator = LuigiLocator::default();
+    let state = read_state(&source, &mut locator);
+    assert_eq!(locator.base(), Some(ROOT));
+    assert_eq!(state, expected_state(-10, MapType::MapMat));
+
+    game.send(&format!("map -9 {}", MapType::MapFac as i32));
+    let state = read_state(&source, &mut locator);
+    assert_eq!(state, expected_state(-9, MapType::MapFac));
+
+    game.quit();
+    assert!(!is_running(&mut sys, pid));
+}
+
+#[test]
+fn ignores_the_game_without_the_luigi_ai_argument() {
+    let (_guard, game) = FakeCogmind::start(&[]);
+    assert_eq!(
+        find_cogmind(&mut System::new(), &ProcessConfig::default()),
+        None
+    );
+    game.quit();
+}
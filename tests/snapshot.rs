@@ -0,0 +1,83 @@
+#[path = "support/game.rs"]
+mod game;
+
+use game::{fake_game, ROOT, UNRELATED};
+use statmind::locator::LuigiLocator;
+use statmind::memory::{MemorySource, MockSource, SnapshotSource};
+use statmind::types::MapType;
+use statmind::{snapshot, GameState};
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("statmind-{}-{}", std::process::id(), name))
+}
+
+fn read_state(source: &dyn MemorySource, mut locator: LuigiLocator) -> GameState {
+    let (_, ai) = locator.read(source).unwrap();
+    GameState::read(source, ai).unwrap()
+}
+
+#[test]
+fn capture_round_trips() {
+    let memory = fake_game(-7, MapType::MapFac as i32);
+    let expected = read_state(&memory, LuigiLocator::default());
+
+    let path = temp_path("round-trip.snapshot");
+    snapshot::capture(&memory, ROOT)
+        .unwrap()
+        .save(&path)
+        .unwrap();
+    let snapshot = SnapshotSource::open(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(snapshot.root(), Some(ROOT));
+    let labels: Vec<&str> = snapshot.segments().map(|(_, label, _)| label).collect();
+    assert_eq!(
+        labels,
+        [
+            "LuigiAi",
+            "tiles",
+            "player",
+            "inventory",
+            "entity",
+            "item",
+            "prop",
+            "machine_hacking"
+        ]
+    );
+    assert!(snapshot.segments().all(|(addr, _, _)| addr != UNRELATED));
+
+    let root = snapshot.root().unwrap();
+    assert_eq!(
+        read_state(&snapshot, LuigiLocator::with_base(root)),
+        expected
+    );
+}
+
+#[test]
+fn reads_version_1() {
+    let memory = fake_game(-10, MapType::MapMat as i32);
+    let expected = read_state(&memory, LuigiLocator::default());
+
+    let path = temp_path("v1.snapshot");
+    fs::write(&path, version_1(&memory)).unwrap();
+    let snapshot = SnapshotSource::open(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(snapshot.root(), None);
+    assert!(snapshot.segments().all(|(_, label, _)| label.is_empty()));
+    assert_eq!(read_state(&snapshot, LuigiLocator::default()), expected);
+}
+
+fn version_1(memory: &MockSource) -> Vec<u8> {
+    let mut data = b"STATMIND".to_vec();
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&(memory.segments().count() as u32).to_le_bytes());
+    for (addr, bytes) in memory.segments() {
+        data.extend_from_slice(&(addr as u64).to_le_bytes());
+        data.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        data.extend_from_slice(bytes);
+    }
+    data
+}
@@ -0,0 +1,219 @@
+#[path = "support/game.rs"]
+mod game;
+
+use game::{fake_game, HACKING, HOSTILE, INVENTORY, ITEM, PLAYER, PROP, ROOT, TILES, UNRELATED};
+use statmind::locator::{read_luigi_ai, LuigiLocator};
+use statmind::memory::{MemorySource, MockSource};
+use statmind::regions::Region;
+use statmind::snapshot;
+use statmind::state::{read_settled, ReadStats};
+use statmind::types::{
+    ActiveState, Decode, LuigiAi, LuigiEntity, LuigiItem, LuigiTile, MapType, Relation,
+};
+use statmind::GameState;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+/// Records every call the decoders make
+struct CountingSource {
+    memory: MockSource,
+    reads: RefCell<Vec<(usize, usize)>>,
+    batches: RefCell<Vec<Vec<(usize, usize)>>>,
+}
+
+impl CountingSource {
+    fn new(memory: MockSource) -> Self {
+        Self {
+            memory,
+            reads: RefCell::default(),
+            batches: RefCell::default(),
+        }
+    }
+}
+
+impl MemorySource for CountingSource {
+    fn read(&self, addr: usize, len: usize) -> anyhow::Result<Vec<u8>> {
+        self.reads.borrow_mut().push((addr, len));
+        self.memory.read(addr, len)
+    }
+
+    fn read_many(&self, requests: &[(usize, usize)]) -> Vec<anyhow::Result<Vec<u8>>> {
+        self.batches.borrow_mut().push(requests.to_vec());
+        self.memory.read_many(requests)
+    }
+
+    fn regions(&self) -> anyhow::Result<Vec<Region>> {
+        self.memory.regions()
+    }
+}
+
+/// A game whose `action_ready` moves on as it is read, one value per read of `LuigiAi`
+struct TurningSource {
+    memory: MockSource,
+    action_ready: RefCell<VecDeque<i32>>,
+}
+
+impl TurningSource {
+    fn new(action_ready: &[i32]) -> Self {
+        Self {
+            memory: fake_game(-10, MapType::MapMat as i32),
+            action_ready: RefCell::new(action_ready.iter().copied().collect()),
+        }
+    }
+}
+
+impl MemorySource for TurningSource {
+    fn read(&self, addr: usize, len: usize) -> anyhow::Result<Vec<u8>> {
+        let mut bytes = self.memory.read(addr, len)?;
+        if addr == ROOT {
+            let mut queue = self.action_ready.borrow_mut();
+            let value = match queue.len() {
+                1 => queue[0],
+                _ => queue.pop_front().unwrap(),
+            };
+            bytes[8..12].copy_from_slice(&value.to_le_bytes());
+        }
+        Ok(bytes)
+    }
+
+    fn regions(&self) -> anyhow::Result<Vec<Region>> {
+        self.memory.regions()
+    }
+
+    fn is_live(&self) -> bool {
+        true
+    }
+}
+
+fn read_consistent(source: &dyn MemorySource) -> (anyhow::Result<GameState>, ReadStats) {
+    let mut stats = ReadStats::default();
+    let state = GameState::read_consistent(source, &mut LuigiLocator::with_base(ROOT), &mut stats);
+    (state, stats)
+}
+
+#[test]
+fn keeps_a_read_made_between_turns() {
+    let (state, stats) = read_consistent(&TurningSource::new(&[4]));
+    assert_eq!(state.unwrap().ai.action_ready, 4);
+    assert_eq!(
+        stats,
+        ReadStats {
+            reads: 1,
+            retries: 0
+        }
+    );
+}
+
+#[test]
+fn retries_when_a_turn_passes_during_the_read() {
+    // Turn 4 ends mid-read, turn 5 holds still
+    let (state, stats) = read_consistent(&TurningSource::new(&[4, 5, 5]));
+    assert_eq!(state.unwrap().ai.action_ready, 5);
+    assert_eq!(stats.retries, 1);
+}
+
+#[test]
+fn retries_while_the_game_is_mid_turn() {
+    let (state, stats) = read_consistent(&TurningSource::new(&[0, 0, 0, 0, 1]));
+    assert_eq!(state.unwrap().ai.action_ready, 1);
+    assert_eq!(stats.retries, 2);
+}
+
+#[test]
+fn gives_up_when_the_game_never_settles() {
+    let (state, stats) = read_consistent(&TurningSource::new(&[0]));
+    assert!(state.unwrap_err().to_string().contains("mid-turn"));
+    assert_eq!(stats.reads, 1);
+    assert!(stats.retries > 0);
+}
+
+#[test]
+fn reads_a_recording_once_even_mid_turn() {
+    let mut memory = fake_game(-10, MapType::MapMat as i32);
+    let mut ai = memory.read(ROOT, LuigiAi::SIZE).unwrap();
+    ai[8..12].copy_from_slice(&0i32.to_le_bytes());
+    memory.insert(ROOT, ai);
+
+    let (state, stats) = read_consistent(&memory);
+    assert_eq!(state.unwrap().ai.action_ready, 0);
+    assert_eq!(
+        stats,
+        ReadStats {
+            reads: 1,
+            retries: 0
+        }
+    );
+}
+
+#[test]
+fn captures_between_turns() {
+    let source = TurningSource::new(&[0, 0, 0, 7]);
+    let mut stats = ReadStats::default();
+    let snapshot = read_settled(
+        &source,
+        &mut LuigiLocator::with_base(ROOT),
+        &mut stats,
+        |root, _| snapshot::capture(&source, root),
+    )
+    .unwrap();
+    assert_eq!(read_luigi_ai(&snapshot, ROOT).unwrap().action_ready, 7);
+    assert_eq!(stats.retries, 1);
+}
+
+#[test]
+fn reads_the_map_in_three_calls() {
+    let memory = fake_game(-10, MapType::MapMat as i32);
+    let ai = read_luigi_ai(&memory, ROOT).unwrap();
+    let source = CountingSource::new(memory);
+    let state = GameState::read(&source, ai).unwrap();
+
+    assert_eq!(
+        source.reads.into_inner(),
+        [(TILES, state.tiles.len() * LuigiTile::SIZE)]
+    );
+    let batches = source.batches.into_inner();
+    assert_eq!(batches.len(), 2);
+    // The player is both on a tile and behind `LuigiAi::player`, but read once
+    let records: Vec<usize> = batches[0].iter().map(|&(addr, _)| addr).collect();
+    assert_eq!(records, [PLAYER, HOSTILE, ITEM, PROP, HACKING]);
+    assert_eq!(batches[1], [(INVENTORY, 3 * LuigiItem::SIZE)]);
+
+    let player = state.player.as_ref().unwrap();
+    assert_eq!(state.tile(1, 0).unwrap().entity.as_ref(), Some(player));
+    assert_eq!(player.inventory.len(), 3);
+    assert_eq!(player.relation, Relation::Player);
+    let hostile = state.tile(2, 1).unwrap().entity.as_ref().unwrap();
+    assert_eq!(hostile.relation, Relation::Hostile);
+    assert_eq!(hostile.active_state, ActiveState::Active);
+}
+
+#[test]
+fn names_the_pointer_it_could_not_follow() {
+    let mut memory = fake_game(-10, MapType::MapMat as i32);
+    // Cut the inventory short of its three slots
+    memory.insert(INVENTORY, vec![0; LuigiItem::SIZE]);
+    let ai = read_luigi_ai(&memory, ROOT).unwrap();
+
+    let error = GameState::read(&memory, ai).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains(&format!("0x{:X}", INVENTORY as u32)),
+        "{:#}",
+        error
+    );
+}
+
+#[test]
+fn empty_inventory_needs_no_read() {
+    let mut memory = fake_game(-10, MapType::MapMat as i32);
+    // The hostile's inventory points somewhere unshared but holds nothing
+    let mut hostile = memory.read(HOSTILE, LuigiEntity::SIZE).unwrap();
+    hostile[44..48].copy_from_slice(&(UNRELATED as u32).to_le_bytes());
+    memory.insert(HOSTILE, hostile);
+    let ai = read_luigi_ai(&memory, ROOT).unwrap();
+
+    let state = GameState::read(&memory, ai).unwrap();
+    let hostile = state.tile(2, 1).unwrap().entity.as_ref().unwrap();
+    assert!(hostile.inventory.is_empty());
+}
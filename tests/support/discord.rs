@@ -0,0 +1,227 @@
+//! Stand-in for the Discord client's IPC socket
+//!
+//! Listens on `discord-ipc-0` in its own directory, answers the handshake and
+//! records what clients send. Point `XDG_RUNTIME_DIR` at [`FakeDiscord::dir`]
+//! before connecting.
+
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const OP_HANDSHAKE: u32 = 0;
+const OP_FRAME: u32 = 1;
+const OP_CLOSE: u32 = 2;
+const OP_PING: u32 = 3;
+const OP_PONG: u32 = 4;
+
+/// How long `wait_for` waits before giving up
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Something a client did, in order
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Connected {
+        client_id: String,
+    },
+    /// `SET_ACTIVITY` with the activity, `None` when clearing
+    SetActivity(Option<Value>),
+    /// The client sent the close opcode
+    Closed,
+    /// The socket was shut down without a close
+    Disconnected,
+}
+
+#[derive(Default)]
+struct Shared {
+    events: Mutex<Vec<Event>>,
+    changed: Condvar,
+    streams: Mutex<Vec<UnixStream>>,
+}
+
+impl Shared {
+    fn push(&self, event: Event) {
+        self.events.lock().unwrap().push(event);
+        self.changed.notify_all();
+    }
+}
+
+pub struct FakeDiscord {
+    dir: PathBuf,
+    shared: Arc<Shared>,
+    /// Tells the running accept loop to quit
+    listening: Option<Arc<AtomicBool>>,
+}
+
+impl FakeDiscord {
+    /// Listen in a fresh temporary directory
+    pub fn start() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "statmind-discord-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let mut discord = Self {
+            dir,
+            shared: Arc::default(),
+            listening: None,
+        };
+        discord.listen();
+        discord
+    }
+
+    /// Directory holding the socket, for `XDG_RUNTIME_DIR`
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Block until `count` activities were sent and return them, `None` for clears
+    pub fn wait_for_activities(&self, count: usize) -> Vec<Option<Value>> {
+        let activities = |events: &[Event]| -> Vec<Option<Value>> {
+            events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::SetActivity(activity) => Some(activity.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        activities(&self.wait_for(|events| activities(events).len() >= count))
+    }
+
+    /// Block until `done` holds for the events so far
+    pub fn wait_for(&self, mut done: impl FnMut(&[Event]) -> bool) -> Vec<Event> {
+        let events = self.shared.events.lock().unwrap();
+        let (events, timeout) = self
+            .shared
+            .changed
+            .wait_timeout_while(events, TIMEOUT, |events| !done(events))
+            .unwrap();
+        assert!(!timeout.timed_out(), "timed out, got {:#?}", *events);
+        events.clone()
+    }
+
+    /// Act like Discord quitting: drop every connection and stop listening
+    pub fn stop(&mut self) {
+        if let Some(stopped) = self.listening.take() {
+            stopped.store(true, Ordering::SeqCst);
+            // Wake the accept loop so it sees the flag
+            let _ = UnixStream::connect(self.socket_path());
+        }
+        let _ = fs::remove_file(self.socket_path());
+        for stream in self.shared.streams.lock().unwrap().drain(..) {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+
+    /// Act like Discord starting again
+    pub fn restart(&mut self) {
+        self.stop();
+        self.listen();
+    }
+
+    fn socket_path(&self) -> PathBuf {
+        self.dir.join("discord-ipc-0")
+    }
+
+    fn listen(&mut self) {
+        let listener = UnixListener::bind(self.socket_path()).unwrap();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let shared = self.shared.clone();
+        let flag = stopped.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                let Ok(stream) = stream else { break };
+                shared
+                    .streams
+                    .lock()
+                    .unwrap()
+                    .push(stream.try_clone().unwrap());
+                let shared = shared.clone();
+                thread::spawn(move || serve(stream, &shared));
+            }
+        });
+        self.listening = Some(stopped);
+    }
+}
+
+impl Drop for FakeDiscord {
+    fn drop(&mut self) {
+        self.stop();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn serve(mut stream: UnixStream, shared: &Shared) {
+    loop {
+        let (op, payload) = match read_frame(&mut stream) {
+            Ok(frame) => frame,
+            Err(_) => {
+                shared.push(Event::Disconnected);
+                return;
+            }
+        };
+        match op {
+            OP_HANDSHAKE => {
+                let client_id = payload["client_id"].as_str().unwrap_or_default().to_owned();
+                shared.push(Event::Connected { client_id });
+                let ready = json!({
+                    "cmd": "DISPATCH",
+                    "evt": "READY",
+                    "data": { "v": 1, "user": { "id": "0", "username": "test" } },
+                });
+                let _ = write_frame(&mut stream, OP_FRAME, &ready);
+            }
+            OP_FRAME if payload["cmd"] == "SET_ACTIVITY" => {
+                let activity = &payload["args"]["activity"];
+                shared.push(Event::SetActivity(
+                    (!activity.is_null()).then(|| activity.clone()),
+                ));
+                let reply = json!({
+                    "cmd": "SET_ACTIVITY",
+                    "nonce": payload["nonce"],
+                    "evt": null,
+                    "data": activity,
+                });
+                let _ = write_frame(&mut stream, OP_FRAME, &reply);
+            }
+            OP_PING => {
+                let _ = write_frame(&mut stream, OP_PONG, &payload);
+            }
+            OP_CLOSE => {
+                shared.push(Event::Closed);
+                return;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn read_frame(stream: &mut UnixStream) -> io::Result<(u32, Value)> {
+    let mut header = [0; 8];
+    stream.read_exact(&mut header)?;
+    let op = u32::from_le_bytes(header[..4].try_into().unwrap());
+    let len = u32::from_le_bytes(header[4..].try_into().unwrap());
+    let mut body = vec![0; len as usize];
+    stream.read_exact(&mut body)?;
+    let payload = serde_json::from_slice(&body)?;
+    Ok((op, payload))
+}
+
+fn write_frame(stream: &mut UnixStream, op: u32, payload: &Value) -> io::Result<()> {
+    let body = payload.to_string();
+    stream.write_all(&op.to_le_bytes())?;
+    stream.write_all(&(body.len() as u32).to_le_bytes())?;
+    stream.write_all(body.as_bytes())
+}
@@ -0,0 +1,117 @@
+//! A small made-up game laid out in a `MockSource` the way Cogmind lays it out in memory
+
+use statmind::memory::MockSource;
+use statmind::types::{LUIGI_MAGIC1, LUIGI_MAGIC2};
+use statmind::ItemId;
+
+pub const ROOT: usize = 0x0100_0000;
+pub const TILES: usize = 0x0200_0000;
+pub const PLAYER: usize = 0x0300_0000;
+pub const INVENTORY: usize = 0x0300_1000;
+pub const HOSTILE: usize = 0x0310_0000;
+pub const ITEM: usize = 0x0400_0000;
+pub const PROP: usize = 0x0500_0000;
+pub const HACKING: usize = 0x0600_0000;
+/// Mapped but not reachable from the root
+pub const UNRELATED: usize = 0x0700_0000;
+
+pub const WIDTH: i32 = 3;
+pub const HEIGHT: i32 = 2;
+
+/// Little-endian struct builder; bools are written as a whole word
+#[derive(Default)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn i32(mut self, value: i32) -> Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn ptr(self, addr: usize) -> Self {
+        self.i32(addr as u32 as i32)
+    }
+
+    fn bool(self, value: bool) -> Self {
+        self.i32(value as i32)
+    }
+}
+
+/// Player at (1, 0), a hostile at (2, 1), an item at (0, 0) and a prop at (1, 1)
+pub fn fake_game(depth: i32, map: i32) -> MockSource {
+    let mut memory = MockSource::new();
+
+    let ai = Writer::default()
+        .i32(LUIGI_MAGIC1)
+        .i32(LUIGI_MAGIC2)
+        .i32(1)
+        .i32(WIDTH)
+        .i32(HEIGHT)
+        .i32(depth)
+        .i32(map)
+        .ptr(TILES)
+        .i32(-1)
+        .ptr(PLAYER)
+        .ptr(HACKING);
+    memory.insert(ROOT, ai.0);
+
+    let mut tiles = Vec::new();
+    for i in 0..WIDTH * HEIGHT {
+        let (prop, entity, item) = match (i % WIDTH, i / WIDTH) {
+            (0, 0) => (0, 0, ITEM),
+            (1, 0) => (0, PLAYER, 0),
+            (1, 1) => (PROP, 0, 0),
+            (2, 1) => (0, HOSTILE, 0),
+            _ => (0, 0, 0),
+        };
+        let tile = Writer::default()
+            .i32(100 + i)
+            .i32(7)
+            .i32(i)
+            .bool(false)
+            .ptr(prop)
+            .ptr(entity)
+            .ptr(item);
+        tiles.extend(tile.0);
+    }
+    memory.insert(TILES, tiles);
+
+    memory.insert(PLAYER, entity(1, 0, 3, INVENTORY).0);
+    let inventory = [
+        item(ItemId::Lgt__Treads, true),
+        item(ItemId::Lgt__Treads, true),
+        item(ItemId::Hover_Unit, false),
+    ];
+    memory.insert(INVENTORY, inventory.into_iter().flat_map(|w| w.0).collect());
+    memory.insert(HOSTILE, entity(200, 3, 0, 0).0);
+
+    memory.insert(ITEM, item(ItemId::Matter, false).0);
+    memory.insert(PROP, Writer::default().i32(5).bool(true).0);
+    memory.insert(
+        HACKING,
+        Writer::default().i32(0).i32(25).i32(10).bool(true).0,
+    );
+    memory.insert(UNRELATED, vec![0xAB; 64]);
+
+    memory
+}
+
+fn entity(id: i32, relation: i32, inventory_size: i32, inventory: usize) -> Writer {
+    Writer::default()
+        .i32(id)
+        .i32(800)
+        .i32(relation)
+        .i32(1)
+        .i32(0)
+        .i32(90)
+        .i32(150)
+        .i32(30)
+        .i32(2)
+        .i32(100)
+        .i32(inventory_size)
+        .ptr(inventory)
+}
+
+fn item(id: ItemId, equipped: bool) -> Writer {
+    Writer::default().i32(id.id()).i32(50).bool(equipped)
+}